@@ -2,7 +2,7 @@ from django.conf import settings
 from django.template.backends.base import BaseEngine
 from django.template.backends.django import get_installed_libraries
 
-from .django_rusty_templates import Engine, Template
+from .django_rusty_templates import Engine
 
 __all__ = ["RustyTemplates", "Template"]
 
@@ -22,10 +22,10 @@ class RustyTemplates(BaseEngine):
         self.engine = Engine(self.dirs, self.app_dirs, **options)
 
     def from_string(self, template_code):
-        return self.engine.from_string(template_code)
+        return Template(self.engine.from_string(template_code), self)
 
     def get_template(self, template_name):
-        return self.engine.get_template(template_name)
+        return Template(self.engine.get_template(template_name), self)
 
     def get_templatetag_libraries(self, custom_libraries):
         """
@@ -35,3 +35,34 @@ class RustyTemplates(BaseEngine):
         libraries = get_installed_libraries()
         libraries.update(custom_libraries)
         return libraries
+
+
+class Template:
+    """
+    Wrap an engine template like Django's backend ``Template``, which only
+    accepts a dict context. The engine template is available as ``template``
+    and also accepts Django ``Context`` and ``RequestContext`` objects.
+    """
+
+    def __init__(self, template, backend):
+        self.template = template
+        self.backend = backend
+
+    @property
+    def origin(self):
+        return self.template.origin
+
+    @property
+    def name(self):
+        return self.template.name
+
+    @property
+    def source(self):
+        return self.template.source
+
+    def render(self, context=None, request=None):
+        if context is not None and not isinstance(context, dict):
+            raise TypeError(
+                "context must be a dict rather than %s." % context.__class__.__name__
+            )
+        return self.template.render(context, request)
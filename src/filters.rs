@@ -8,15 +8,68 @@ use crate::types::Argument;
 pub enum FilterType {
     Add(AddFilter),
     AddSlashes(AddSlashesFilter),
+    ApNumber(ApNumberFilter),
     Capfirst(CapfirstFilter),
     Center(CenterFilter),
+    Cut(CutFilter),
+    Date(DateFilter),
     Default(DefaultFilter),
+    DefaultIfNone(DefaultIfNoneFilter),
+    DictSort(DictSortFilter),
+    DictSortReversed(DictSortReversedFilter),
+    DivisibleBy(DivisibleByFilter),
     Escape(EscapeFilter),
+    EscapeJs(EscapeJsFilter),
+    EscapeSeq(EscapeSeqFilter),
     External(ExternalFilter),
+    FileSizeFormat(FileSizeFormatFilter),
+    First(FirstFilter),
+    FloatFormat(FloatFormatFilter),
+    ForceEscape(ForceEscapeFilter),
+    GetDigit(GetDigitFilter),
+    IntComma(IntCommaFilter),
+    IntWord(IntWordFilter),
+    IriEncode(IriEncodeFilter),
+    Join(JoinFilter),
+    JsonScript(JsonScriptFilter),
+    Last(LastFilter),
+    Length(LengthFilter),
+    LineBreaks(LineBreaksFilter),
+    LineBreaksBr(LineBreaksBrFilter),
+    LineNumbers(LineNumbersFilter),
+    Ljust(LjustFilter),
     Lower(LowerFilter),
+    MakeList(MakeListFilter),
+    NaturalDay(NaturalDayFilter),
+    NaturalTime(NaturalTimeFilter),
+    Ordinal(OrdinalFilter),
+    Phone2Numeric(Phone2NumericFilter),
+    Pluralize(PluralizeFilter),
+    Pprint(PprintFilter),
+    Random(RandomFilter),
+    Rjust(RjustFilter),
     Safe(SafeFilter),
+    SafeSeq(SafeSeqFilter),
+    Slice(SliceFilter),
     Slugify(SlugifyFilter),
+    StringFormat(StringFormatFilter),
+    StripTags(StripTagsFilter),
+    Time(TimeFilter),
+    TimeSince(TimeSinceFilter),
+    TimeUntil(TimeUntilFilter),
+    Title(TitleFilter),
+    TruncateChars(TruncateCharsFilter),
+    TruncateCharsHtml(TruncateCharsHtmlFilter),
+    TruncateWords(TruncateWordsFilter),
+    TruncateWordsHtml(TruncateWordsHtmlFilter),
+    UnorderedList(UnorderedListFilter),
     Upper(UpperFilter),
+    UrlEncode(UrlEncodeFilter),
+    Urlize(UrlizeFilter),
+    UrlizeTrunc(UrlizeTruncFilter),
+    WordCount(WordCountFilter),
+    WordWrap(WordWrapFilter),
+    YesNo(YesNoFilter),
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -33,6 +86,9 @@ impl AddFilter {
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApNumberFilter;
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct CapfirstFilter;
 
@@ -47,6 +103,28 @@ impl CenterFilter {
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct CutFilter {
+    pub argument: Argument,
+}
+
+impl CutFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DateFilter {
+    pub argument: Option<Argument>,
+}
+
+impl DateFilter {
+    pub fn new(argument: Option<Argument>) -> Self {
+        Self { argument }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct DefaultFilter {
     pub argument: Argument,
@@ -58,19 +136,81 @@ impl DefaultFilter {
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct DefaultIfNoneFilter {
+    pub argument: Argument,
+}
+
+impl DefaultIfNoneFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DictSortFilter {
+    pub argument: Argument,
+}
+
+impl DictSortFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DictSortReversedFilter {
+    pub argument: Argument,
+}
+
+impl DictSortReversedFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DivisibleByFilter {
+    pub argument: Argument,
+}
+
+impl DivisibleByFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct EscapeFilter;
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct EscapeJsFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EscapeSeqFilter;
+
 #[derive(Clone, Debug)]
 pub struct ExternalFilter {
     pub filter: Arc<Py<PyAny>>,
     pub argument: Option<Argument>,
+    pub is_safe: bool,
+    pub needs_autoescape: bool,
+    pub expects_localtime: bool,
 }
 
 impl ExternalFilter {
-    pub fn new(filter: Py<PyAny>, argument: Option<Argument>) -> Self {
+    pub fn new(filter: &Bound<'_, PyAny>, argument: Option<Argument>) -> Self {
+        // Django sets these flags on the filter function when registering it.
+        let flag = |name| {
+            filter
+                .getattr(name)
+                .is_ok_and(|flag| flag.is_truthy().unwrap_or(false))
+        };
         Self {
-            filter: Arc::new(filter),
+            is_safe: flag("is_safe"),
+            needs_autoescape: flag("needs_autoescape"),
+            expects_localtime: flag("expects_localtime"),
+            filter: Arc::new(filter.clone().unbind()),
             argument,
         }
     }
@@ -86,14 +226,322 @@ impl PartialEq for ExternalFilter {
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileSizeFormatFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FirstFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FloatFormatFilter {
+    pub argument: Option<Argument>,
+}
+
+impl FloatFormatFilter {
+    pub fn new(argument: Option<Argument>) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForceEscapeFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetDigitFilter {
+    pub argument: Argument,
+}
+
+impl GetDigitFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IntCommaFilter {
+    pub argument: Option<Argument>,
+}
+
+impl IntCommaFilter {
+    pub fn new(argument: Option<Argument>) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IntWordFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IriEncodeFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct JoinFilter {
+    pub argument: Argument,
+}
+
+impl JoinFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonScriptFilter {
+    pub argument: Option<Argument>,
+}
+
+impl JsonScriptFilter {
+    pub fn new(argument: Option<Argument>) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LastFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LengthFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineBreaksFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineBreaksBrFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineNumbersFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LjustFilter {
+    pub argument: Argument,
+}
+
+impl LjustFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct LowerFilter;
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct MakeListFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NaturalDayFilter {
+    pub argument: Option<Argument>,
+}
+
+impl NaturalDayFilter {
+    pub fn new(argument: Option<Argument>) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NaturalTimeFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrdinalFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Phone2NumericFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PluralizeFilter {
+    pub argument: Option<Argument>,
+}
+
+impl PluralizeFilter {
+    pub fn new(argument: Option<Argument>) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PprintFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RandomFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RjustFilter {
+    pub argument: Argument,
+}
+
+impl RjustFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct SafeFilter;
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct SafeSeqFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliceFilter {
+    pub argument: Argument,
+}
+
+impl SliceFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct SlugifyFilter;
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct StringFormatFilter {
+    pub argument: Argument,
+}
+
+impl StringFormatFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StripTagsFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeFilter {
+    pub argument: Option<Argument>,
+}
+
+impl TimeFilter {
+    pub fn new(argument: Option<Argument>) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeSinceFilter {
+    pub argument: Option<Argument>,
+}
+
+impl TimeSinceFilter {
+    pub fn new(argument: Option<Argument>) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeUntilFilter {
+    pub argument: Option<Argument>,
+}
+
+impl TimeUntilFilter {
+    pub fn new(argument: Option<Argument>) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TitleFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TruncateCharsFilter {
+    pub argument: Argument,
+}
+
+impl TruncateCharsFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TruncateCharsHtmlFilter {
+    pub argument: Argument,
+}
+
+impl TruncateCharsHtmlFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TruncateWordsFilter {
+    pub argument: Argument,
+}
+
+impl TruncateWordsFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TruncateWordsHtmlFilter {
+    pub argument: Argument,
+}
+
+impl TruncateWordsHtmlFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnorderedListFilter;
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct UpperFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UrlEncodeFilter {
+    pub argument: Option<Argument>,
+}
+
+impl UrlEncodeFilter {
+    pub fn new(argument: Option<Argument>) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UrlizeFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UrlizeTruncFilter {
+    pub argument: Argument,
+}
+
+impl UrlizeTruncFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WordCountFilter;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WordWrapFilter {
+    pub argument: Argument,
+}
+
+impl WordWrapFilter {
+    pub fn new(argument: Argument) -> Self {
+        Self { argument }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct YesNoFilter {
+    pub argument: Option<Argument>,
+}
+
+impl YesNoFilter {
+    pub fn new(argument: Option<Argument>) -> Self {
+        Self { argument }
+    }
+}
@@ -4,6 +4,7 @@ pub mod core;
 pub mod custom_tag;
 pub mod forloop;
 pub mod ifcondition;
+pub mod l10n;
 pub mod load;
 pub mod tag;
 pub mod variable;
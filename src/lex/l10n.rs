@@ -0,0 +1,116 @@
+use miette::{Diagnostic, SourceSpan};
+use thiserror::Error;
+
+use crate::lex::tag::TagParts;
+use crate::types::TemplateString;
+
+#[derive(Debug, PartialEq)]
+pub struct L10nToken {
+    pub at: (usize, usize),
+    pub enabled: bool,
+}
+
+#[derive(Error, Debug, Diagnostic, PartialEq, Eq)]
+pub enum L10nError {
+    #[error("'{tag}' argument should be 'on' or 'off'.")]
+    InvalidArgument {
+        tag: &'static str,
+        #[label("here")]
+        at: SourceSpan,
+    },
+    #[error("'{tag}' tag takes at most one argument.")]
+    UnexpectedArgument {
+        tag: &'static str,
+        #[label("here")]
+        at: SourceSpan,
+    },
+}
+
+/// Lex the optional `on` or `off` argument of the `localize` and `localtime` tags.
+///
+/// A missing argument is the same as `on`.
+pub fn lex_l10n_argument(
+    template: TemplateString<'_>,
+    parts: TagParts,
+    tag: &'static str,
+) -> Result<L10nToken, L10nError> {
+    let content = template.content(parts.at);
+    let at = parts.at;
+    match content {
+        "" | "on" => Ok(L10nToken { at, enabled: true }),
+        "off" => Ok(L10nToken { at, enabled: false }),
+        _ => match content.find(char::is_whitespace) {
+            None => Err(L10nError::InvalidArgument { tag, at: at.into() }),
+            Some(_) => Err(L10nError::UnexpectedArgument { tag, at: at.into() }),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lex_localize_default() {
+        let template = "{% localize %}";
+        let parts = TagParts { at: (11, 0) };
+        let token = lex_l10n_argument(template.into(), parts, "localize").unwrap();
+        let on = L10nToken {
+            at: (11, 0),
+            enabled: true,
+        };
+        assert_eq!(token, on);
+    }
+
+    #[test]
+    fn test_lex_localize_off() {
+        let template = "{% localize off %}";
+        let parts = TagParts { at: (12, 3) };
+        let token = lex_l10n_argument(template.into(), parts, "localize").unwrap();
+        let off = L10nToken {
+            at: (12, 3),
+            enabled: false,
+        };
+        assert_eq!(token, off);
+    }
+
+    #[test]
+    fn test_lex_localtime_on() {
+        let template = "{% localtime on %}";
+        let parts = TagParts { at: (13, 2) };
+        let token = lex_l10n_argument(template.into(), parts, "localtime").unwrap();
+        let on = L10nToken {
+            at: (13, 2),
+            enabled: true,
+        };
+        assert_eq!(token, on);
+    }
+
+    #[test]
+    fn test_lex_localize_invalid() {
+        let template = "{% localize other %}";
+        let parts = TagParts { at: (12, 5) };
+        let error = lex_l10n_argument(template.into(), parts, "localize").unwrap_err();
+        assert_eq!(
+            error,
+            L10nError::InvalidArgument {
+                tag: "localize",
+                at: (12, 5).into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_lex_localtime_unexpected_argument() {
+        let template = "{% localtime off on %}";
+        let parts = TagParts { at: (13, 6) };
+        let error = lex_l10n_argument(template.into(), parts, "localtime").unwrap_err();
+        assert_eq!(
+            error,
+            L10nError::UnexpectedArgument {
+                tag: "localtime",
+                at: (13, 6).into()
+            }
+        );
+    }
+}
@@ -4,10 +4,14 @@ use std::path::{Path, PathBuf};
 use cached::proc_macro::cached;
 use encoding_rs::Encoding;
 use pyo3::exceptions::PyUnicodeError;
+use pyo3::intern;
 use pyo3::prelude::*;
 use sugar_path::SugarPath;
 
-use crate::template::django_rusty_templates::{EngineData, Template};
+use crate::template::django_rusty_templates::{
+    EngineData, OriginLoader, Template, TemplateDoesNotExist,
+};
+use crate::utils::PyResultMethods;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct LoaderError {
@@ -67,15 +71,26 @@ fn get_app_template_dirs(py: Python<'_>, dirname: &str) -> Result<Vec<PathBuf>,
 pub struct FileSystemLoader {
     dirs: Vec<PathBuf>,
     encoding: &'static Encoding,
+    origin_loader: Option<OriginLoader>,
 }
 
 impl FileSystemLoader {
     pub fn new(dirs: Vec<PathBuf>, encoding: &'static Encoding) -> Self {
-        Self { dirs, encoding }
+        Self {
+            dirs,
+            encoding,
+            origin_loader: None,
+        }
     }
 
     pub fn from_pathbuf(dirs: Vec<PathBuf>, encoding: &'static Encoding) -> Self {
-        Self { dirs, encoding }
+        Self::new(dirs, encoding)
+    }
+
+    /// Set the Django loader reported as `Origin.loader`.
+    pub fn with_origin_loader(mut self, loader: OriginLoader) -> Self {
+        self.origin_loader = Some(loader);
+        self
     }
 
     fn get_template(
@@ -107,7 +122,12 @@ impl FileSystemLoader {
                     encoding.name()
                 ))));
             }
-            return Ok(Template::new(py, &contents, path, engine));
+            return Ok(
+                Template::new(py, &contents, path, template_name, engine).map(|mut template| {
+                    template.loader = self.origin_loader.clone();
+                    template
+                }),
+            );
         }
         Err(LoaderError { tried })
     }
@@ -115,11 +135,21 @@ impl FileSystemLoader {
 
 pub struct AppDirsLoader {
     encoding: &'static Encoding,
+    origin_loader: Option<OriginLoader>,
 }
 
 impl AppDirsLoader {
     pub fn new(encoding: &'static Encoding) -> Self {
-        Self { encoding }
+        Self {
+            encoding,
+            origin_loader: None,
+        }
+    }
+
+    /// Set the Django loader reported as `Origin.loader`.
+    pub fn with_origin_loader(mut self, loader: OriginLoader) -> Self {
+        self.origin_loader = Some(loader);
+        self
     }
 
     fn get_template(
@@ -132,7 +162,10 @@ impl AppDirsLoader {
             Ok(dirs) => dirs,
             Err(e) => return Ok(Err(e)),
         };
-        let filesystem_loader = FileSystemLoader::from_pathbuf(dirs, self.encoding);
+        let filesystem_loader = FileSystemLoader {
+            origin_loader: self.origin_loader.clone(),
+            ..FileSystemLoader::from_pathbuf(dirs, self.encoding)
+        };
         filesystem_loader.get_template(py, template_name, engine)
     }
 }
@@ -183,12 +216,21 @@ impl CachedLoader {
 
 pub struct LocMemLoader {
     templates: HashMap<String, String>,
+    origin_loader: Option<OriginLoader>,
 }
 
 impl LocMemLoader {
-    #[allow(dead_code)]
     pub fn new(templates: HashMap<String, String>) -> Self {
-        Self { templates }
+        Self {
+            templates,
+            origin_loader: None,
+        }
+    }
+
+    /// Set the Django loader reported as `Origin.loader`.
+    pub fn with_origin_loader(mut self, loader: OriginLoader) -> Self {
+        self.origin_loader = Some(loader);
+        self
     }
 
     fn get_template(
@@ -202,8 +244,13 @@ impl LocMemLoader {
                 py,
                 contents,
                 PathBuf::from(template_name),
+                template_name,
                 engine,
-            ))
+            )
+            .map(|mut template| {
+                template.loader = self.origin_loader.clone();
+                template
+            }))
         } else {
             Err(LoaderError {
                 tried: vec![(
@@ -215,16 +262,70 @@ impl LocMemLoader {
     }
 }
 
-pub struct ExternalLoader {}
+pub struct ExternalLoader {
+    loader: Py<PyAny>,
+}
 
 impl ExternalLoader {
+    pub fn new(loader: Py<PyAny>) -> Self {
+        Self { loader }
+    }
+
+    /// Load the source of `origin`, or `None` if the Python loader raises
+    /// `TemplateDoesNotExist`.
+    fn get_contents(&self, origin: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+        let py = origin.py();
+        match self
+            .loader
+            .call_method1(py, intern!(py, "get_contents"), (origin,))
+            .ok_or_isinstance_of::<TemplateDoesNotExist>(py)?
+        {
+            Ok(contents) => Ok(Some(contents.extract(py)?)),
+            Err(_) => Ok(None),
+        }
+    }
+
     fn get_template(
         &self,
-        _py: Python<'_>,
-        _template_name: &str,
-        _engine: &EngineData,
+        py: Python<'_>,
+        template_name: &str,
+        engine: &EngineData,
     ) -> Result<PyResult<Template>, LoaderError> {
-        todo!()
+        let origins = match self
+            .loader
+            .bind(py)
+            .call_method1(intern!(py, "get_template_sources"), (template_name,))
+            .and_then(|origins| origins.try_iter())
+        {
+            Ok(origins) => origins,
+            Err(e) => return Ok(Err(e)),
+        };
+        let mut tried = Vec::new();
+        for origin in origins {
+            let source = origin.and_then(|origin| {
+                let name: String = origin.getattr(intern!(py, "name"))?.str()?.extract()?;
+                let contents = self.get_contents(&origin)?;
+                Ok((name, contents, origin))
+            });
+            match source {
+                Ok((name, Some(contents), origin)) => {
+                    let template =
+                        Template::new(py, &contents, PathBuf::from(name), template_name, engine)
+                            .and_then(|mut template| {
+                                let loader = match origin.getattr_opt(intern!(py, "loader"))? {
+                                    Some(loader) if !loader.is_none() => loader.unbind(),
+                                    _ => self.loader.clone_ref(py),
+                                };
+                                template.loader = Some(OriginLoader::python(loader));
+                                Ok(template)
+                            });
+                    return Ok(template);
+                }
+                Ok((name, None, _)) => tried.push((name, "Source does not exist".to_string())),
+                Err(e) => return Ok(Err(e)),
+            }
+        }
+        Err(LoaderError { tried })
     }
 }
 
@@ -232,9 +333,7 @@ pub enum Loader {
     FileSystem(FileSystemLoader),
     AppDirs(AppDirsLoader),
     Cached(CachedLoader),
-    #[allow(dead_code)]
     LocMem(LocMemLoader),
-    #[allow(dead_code)]
     External(ExternalLoader),
 }
 
@@ -258,26 +357,12 @@ impl Loader {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pyo3::types::PyDict;
     use pyo3::{BoundObject, IntoPyObjectExt};
 
     use quickcheck::quickcheck;
 
-    fn setup_django(py: Python<'_>) {
-        // Import the os module and set the DJANGO_SETTINGS_MODULE environment variable
-        let os_module = PyModule::import(py, "os").unwrap();
-        let environ = os_module.getattr("environ").unwrap();
-        environ
-            .call_method(
-                "setdefault",
-                ("DJANGO_SETTINGS_MODULE", "tests.settings"),
-                None,
-            )
-            .unwrap();
-
-        // Import the django module and call django.setup()
-        let django_module = PyModule::import(py, "django").unwrap();
-        django_module.call_method0("setup").unwrap();
-    }
+    use crate::utils::setup_django;
 
     #[test]
     fn test_filesystem_loader() {
@@ -499,6 +584,73 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_external_loader() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let globals = PyDict::new(py);
+            py.run(
+                c"
+from types import SimpleNamespace
+
+class Loader:
+    def get_template_sources(self, template_name):
+        yield SimpleNamespace(name=f'db:{template_name}')
+
+    def get_contents(self, origin):
+        return 'external'
+
+loader = Loader()
+",
+                Some(&globals),
+                None,
+            )
+            .unwrap();
+            let loader = globals.get_item("loader").unwrap().unwrap();
+
+            let external_loader = ExternalLoader::new(loader.clone().unbind());
+
+            let template = external_loader
+                .get_template(py, "index.html", &engine)
+                .unwrap()
+                .unwrap();
+            assert_eq!(template.template, "external".to_string());
+            assert_eq!(template.filename.unwrap(), PathBuf::from("db:index.html"));
+            assert_eq!(template.template_name.unwrap(), "index.html");
+            assert!(template.loader.unwrap().bind(py).unwrap().is(&loader));
+        });
+    }
+
+    #[test]
+    fn test_locmem_loader_origin_loader() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let mut templates: HashMap<String, String> = HashMap::new();
+            templates.insert("index.html".to_string(), "index".to_string());
+            let origin_loader = PyDict::new(py).into_any();
+
+            let loader = LocMemLoader::new(templates)
+                .with_origin_loader(OriginLoader::python(origin_loader.clone().unbind()));
+
+            let template = loader
+                .get_template(py, "index.html", &engine)
+                .unwrap()
+                .unwrap();
+            assert!(
+                template
+                    .loader
+                    .unwrap()
+                    .bind(py)
+                    .unwrap()
+                    .is(&origin_loader)
+            );
+        });
+    }
+
     #[test]
     fn test_locmem_loader_missing_template() {
         Python::initialize();
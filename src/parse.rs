@@ -11,16 +11,69 @@ use thiserror::Error;
 
 use crate::filters::AddFilter;
 use crate::filters::AddSlashesFilter;
+use crate::filters::ApNumberFilter;
 use crate::filters::CapfirstFilter;
 use crate::filters::CenterFilter;
+use crate::filters::CutFilter;
+use crate::filters::DateFilter;
 use crate::filters::DefaultFilter;
+use crate::filters::DefaultIfNoneFilter;
+use crate::filters::DictSortFilter;
+use crate::filters::DictSortReversedFilter;
+use crate::filters::DivisibleByFilter;
 use crate::filters::EscapeFilter;
+use crate::filters::EscapeJsFilter;
+use crate::filters::EscapeSeqFilter;
 use crate::filters::ExternalFilter;
+use crate::filters::FileSizeFormatFilter;
 use crate::filters::FilterType;
+use crate::filters::FirstFilter;
+use crate::filters::FloatFormatFilter;
+use crate::filters::ForceEscapeFilter;
+use crate::filters::GetDigitFilter;
+use crate::filters::IntCommaFilter;
+use crate::filters::IntWordFilter;
+use crate::filters::IriEncodeFilter;
+use crate::filters::JoinFilter;
+use crate::filters::JsonScriptFilter;
+use crate::filters::LastFilter;
+use crate::filters::LengthFilter;
+use crate::filters::LineBreaksBrFilter;
+use crate::filters::LineBreaksFilter;
+use crate::filters::LineNumbersFilter;
+use crate::filters::LjustFilter;
 use crate::filters::LowerFilter;
+use crate::filters::MakeListFilter;
+use crate::filters::NaturalDayFilter;
+use crate::filters::NaturalTimeFilter;
+use crate::filters::OrdinalFilter;
+use crate::filters::Phone2NumericFilter;
+use crate::filters::PluralizeFilter;
+use crate::filters::PprintFilter;
+use crate::filters::RandomFilter;
+use crate::filters::RjustFilter;
 use crate::filters::SafeFilter;
+use crate::filters::SafeSeqFilter;
+use crate::filters::SliceFilter;
 use crate::filters::SlugifyFilter;
+use crate::filters::StringFormatFilter;
+use crate::filters::StripTagsFilter;
+use crate::filters::TimeFilter;
+use crate::filters::TimeSinceFilter;
+use crate::filters::TimeUntilFilter;
+use crate::filters::TitleFilter;
+use crate::filters::TruncateCharsFilter;
+use crate::filters::TruncateCharsHtmlFilter;
+use crate::filters::TruncateWordsFilter;
+use crate::filters::TruncateWordsHtmlFilter;
+use crate::filters::UnorderedListFilter;
 use crate::filters::UpperFilter;
+use crate::filters::UrlEncodeFilter;
+use crate::filters::UrlizeFilter;
+use crate::filters::UrlizeTruncFilter;
+use crate::filters::WordCountFilter;
+use crate::filters::WordWrapFilter;
+use crate::filters::YesNoFilter;
 use crate::lex::START_TAG_LEN;
 use crate::lex::autoescape::{AutoescapeEnabled, AutoescapeError, lex_autoescape_argument};
 use crate::lex::common::{LexerError, text_content_at, translated_text_content_at};
@@ -32,6 +85,7 @@ use crate::lex::forloop::{ForLexer, ForLexerError, ForLexerInError, ForTokenType
 use crate::lex::ifcondition::{
     IfConditionAtom, IfConditionLexer, IfConditionOperator, IfConditionTokenType,
 };
+use crate::lex::l10n::{L10nError, lex_l10n_argument};
 use crate::lex::load::{LoadLexer, LoadToken};
 use crate::lex::tag::{TagLexerError, TagParts, lex_tag};
 use crate::lex::variable::{
@@ -120,33 +174,194 @@ impl Filter {
                 Some(right) => FilterType::Center(CenterFilter::new(right)),
                 None => return Err(ParseError::MissingArgument { at: at.into() }),
             },
+            "cut" => match right {
+                Some(right) => FilterType::Cut(CutFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
+            "date" => FilterType::Date(DateFilter::new(right)),
             "default" => match right {
                 Some(right) => FilterType::Default(DefaultFilter::new(right)),
                 None => return Err(ParseError::MissingArgument { at: at.into() }),
             },
+            "default_if_none" => match right {
+                Some(right) => FilterType::DefaultIfNone(DefaultIfNoneFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
+            "dictsort" => match right {
+                Some(right) => FilterType::DictSort(DictSortFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
+            "dictsortreversed" => match right {
+                Some(right) => FilterType::DictSortReversed(DictSortReversedFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
+            "divisibleby" => match right {
+                Some(right) => FilterType::DivisibleBy(DivisibleByFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
             "escape" => match right {
                 Some(right) => return Err(unexpected_argument("escape", right)),
                 None => FilterType::Escape(EscapeFilter),
             },
+            "escapejs" => match right {
+                Some(right) => return Err(unexpected_argument("escapejs", right)),
+                None => FilterType::EscapeJs(EscapeJsFilter),
+            },
+            "escapeseq" => match right {
+                Some(right) => return Err(unexpected_argument("escapeseq", right)),
+                None => FilterType::EscapeSeq(EscapeSeqFilter),
+            },
+            "filesizeformat" => match right {
+                Some(right) => return Err(unexpected_argument("filesizeformat", right)),
+                None => FilterType::FileSizeFormat(FileSizeFormatFilter),
+            },
+            "first" => match right {
+                Some(right) => return Err(unexpected_argument("first", right)),
+                None => FilterType::First(FirstFilter),
+            },
+            "floatformat" => FilterType::FloatFormat(FloatFormatFilter::new(right)),
+            "force_escape" => match right {
+                Some(right) => return Err(unexpected_argument("force_escape", right)),
+                None => FilterType::ForceEscape(ForceEscapeFilter),
+            },
+            "get_digit" => match right {
+                Some(right) => FilterType::GetDigit(GetDigitFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
+            "iriencode" => match right {
+                Some(right) => return Err(unexpected_argument("iriencode", right)),
+                None => FilterType::IriEncode(IriEncodeFilter),
+            },
+            "join" => match right {
+                Some(right) => FilterType::Join(JoinFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
+            "json_script" => FilterType::JsonScript(JsonScriptFilter::new(right)),
+            "last" => match right {
+                Some(right) => return Err(unexpected_argument("last", right)),
+                None => FilterType::Last(LastFilter),
+            },
+            "length" => match right {
+                Some(right) => return Err(unexpected_argument("length", right)),
+                None => FilterType::Length(LengthFilter),
+            },
+            "linebreaks" => match right {
+                Some(right) => return Err(unexpected_argument("linebreaks", right)),
+                None => FilterType::LineBreaks(LineBreaksFilter),
+            },
+            "linebreaksbr" => match right {
+                Some(right) => return Err(unexpected_argument("linebreaksbr", right)),
+                None => FilterType::LineBreaksBr(LineBreaksBrFilter),
+            },
+            "linenumbers" => match right {
+                Some(right) => return Err(unexpected_argument("linenumbers", right)),
+                None => FilterType::LineNumbers(LineNumbersFilter),
+            },
+            "ljust" => match right {
+                Some(right) => FilterType::Ljust(LjustFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
             "lower" => match right {
                 Some(right) => return Err(unexpected_argument("lower", right)),
                 None => FilterType::Lower(LowerFilter),
             },
+            "make_list" => match right {
+                Some(right) => return Err(unexpected_argument("make_list", right)),
+                None => FilterType::MakeList(MakeListFilter),
+            },
+            "phone2numeric" => match right {
+                Some(right) => return Err(unexpected_argument("phone2numeric", right)),
+                None => FilterType::Phone2Numeric(Phone2NumericFilter),
+            },
+            "pluralize" => FilterType::Pluralize(PluralizeFilter::new(right)),
+            "pprint" => match right {
+                Some(right) => return Err(unexpected_argument("pprint", right)),
+                None => FilterType::Pprint(PprintFilter),
+            },
+            "random" => match right {
+                Some(right) => return Err(unexpected_argument("random", right)),
+                None => FilterType::Random(RandomFilter),
+            },
+            "rjust" => match right {
+                Some(right) => FilterType::Rjust(RjustFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
             "safe" => match right {
                 Some(right) => return Err(unexpected_argument("safe", right)),
                 None => FilterType::Safe(SafeFilter),
             },
+            "safeseq" => match right {
+                Some(right) => return Err(unexpected_argument("safeseq", right)),
+                None => FilterType::SafeSeq(SafeSeqFilter),
+            },
+            "slice" => match right {
+                Some(right) => FilterType::Slice(SliceFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
             "slugify" => match right {
                 Some(right) => return Err(unexpected_argument("slugify", right)),
                 None => FilterType::Slugify(SlugifyFilter),
             },
+            "stringformat" => match right {
+                Some(right) => FilterType::StringFormat(StringFormatFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
+            "striptags" => match right {
+                Some(right) => return Err(unexpected_argument("striptags", right)),
+                None => FilterType::StripTags(StripTagsFilter),
+            },
+            "time" => FilterType::Time(TimeFilter::new(right)),
+            "timesince" => FilterType::TimeSince(TimeSinceFilter::new(right)),
+            "timeuntil" => FilterType::TimeUntil(TimeUntilFilter::new(right)),
+            "title" => match right {
+                Some(right) => return Err(unexpected_argument("title", right)),
+                None => FilterType::Title(TitleFilter),
+            },
+            "truncatechars" => match right {
+                Some(right) => FilterType::TruncateChars(TruncateCharsFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
+            "truncatechars_html" => match right {
+                Some(right) => FilterType::TruncateCharsHtml(TruncateCharsHtmlFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
+            "truncatewords" => match right {
+                Some(right) => FilterType::TruncateWords(TruncateWordsFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
+            "truncatewords_html" => match right {
+                Some(right) => FilterType::TruncateWordsHtml(TruncateWordsHtmlFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
+            "unordered_list" => match right {
+                Some(right) => return Err(unexpected_argument("unordered_list", right)),
+                None => FilterType::UnorderedList(UnorderedListFilter),
+            },
             "upper" => match right {
                 Some(right) => return Err(unexpected_argument("upper", right)),
                 None => FilterType::Upper(UpperFilter),
             },
+            "urlencode" => FilterType::UrlEncode(UrlEncodeFilter::new(right)),
+            "urlize" => match right {
+                Some(right) => return Err(unexpected_argument("urlize", right)),
+                None => FilterType::Urlize(UrlizeFilter),
+            },
+            "urlizetrunc" => match right {
+                Some(right) => FilterType::UrlizeTrunc(UrlizeTruncFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
+            "wordcount" => match right {
+                Some(right) => return Err(unexpected_argument("wordcount", right)),
+                None => FilterType::WordCount(WordCountFilter),
+            },
+            "wordwrap" => match right {
+                Some(right) => FilterType::WordWrap(WordWrapFilter::new(right)),
+                None => return Err(ParseError::MissingArgument { at: at.into() }),
+            },
+            "yesno" => FilterType::YesNo(YesNoFilter::new(right)),
             external => {
                 let external = match parser.external_filters.get(external) {
-                    Some(external) => external.clone().unbind(),
+                    Some(external) => external,
                     None => {
                         return Err(ParseError::InvalidFilter {
                             at: at.into(),
@@ -154,13 +369,58 @@ impl Filter {
                         });
                     }
                 };
-                FilterType::External(ExternalFilter::new(external, right))
+                match builtin_filter(external) {
+                    Some(name) => humanize_filter(name, right)?,
+                    None => FilterType::External(ExternalFilter::new(external, right)),
+                }
             }
         };
         Ok(Self { at, left, filter })
     }
 }
 
+/// Recognise the filters of Django's own libraries that we implement natively.
+fn builtin_filter(filter: &Bound<'_, PyAny>) -> Option<&'static str> {
+    let py = filter.py();
+    let module: String = filter
+        .getattr(intern!(py, "__module__"))
+        .ok()?
+        .extract()
+        .ok()?;
+    if module != "django.contrib.humanize.templatetags.humanize" {
+        return None;
+    }
+    let name: String = filter
+        .getattr(intern!(py, "__name__"))
+        .ok()?
+        .extract()
+        .ok()?;
+    [
+        "apnumber",
+        "intcomma",
+        "intword",
+        "naturalday",
+        "naturaltime",
+        "ordinal",
+    ]
+    .into_iter()
+    .find(|builtin| *builtin == name)
+}
+
+/// Build a native filter for one of the filters `builtin_filter` recognises.
+fn humanize_filter(name: &'static str, right: Option<Argument>) -> Result<FilterType, ParseError> {
+    Ok(match (name, right) {
+        ("apnumber", None) => FilterType::ApNumber(ApNumberFilter),
+        ("intcomma", right) => FilterType::IntComma(IntCommaFilter::new(right)),
+        ("intword", None) => FilterType::IntWord(IntWordFilter),
+        ("naturalday", right) => FilterType::NaturalDay(NaturalDayFilter::new(right)),
+        ("naturaltime", None) => FilterType::NaturalTime(NaturalTimeFilter),
+        ("ordinal", None) => FilterType::Ordinal(OrdinalFilter),
+        (name, Some(right)) => return Err(unexpected_argument(name, right)),
+        (name, None) => unreachable!("{name} is not a humanize filter"),
+    })
+}
+
 fn parse_numeric(content: &str, at: (usize, usize)) -> Result<TagElement, ParseError> {
     match content.parse::<BigInt>() {
         Ok(n) => Ok(TagElement::Int(n)),
@@ -455,31 +715,61 @@ impl PartialEq for SimpleTag {
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cache {
+    pub expire_time: TagElement,
+    pub expire_time_at: (usize, usize),
+    pub fragment_name: String,
+    pub vary_on: Vec<TagElement>,
+    pub cache_name: Option<(TagElement, (usize, usize))>,
+    pub nodes: Vec<TokenTree>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum Tag {
     Autoescape {
         enabled: AutoescapeEnabled,
         nodes: Vec<TokenTree>,
     },
+    Cache(Cache),
     If {
         condition: IfCondition,
         truthy: Vec<TokenTree>,
         falsey: Option<Vec<TokenTree>>,
     },
     For(For),
+    GetCurrentTimezone {
+        variable: String,
+    },
     Load,
+    Localize {
+        enabled: bool,
+        nodes: Vec<TokenTree>,
+    },
+    LocalTime {
+        enabled: bool,
+        nodes: Vec<TokenTree>,
+    },
     SimpleTag(SimpleTag),
+    Timezone {
+        timezone: TagElement,
+        nodes: Vec<TokenTree>,
+    },
     Url(Url),
 }
 
 #[derive(PartialEq, Eq)]
 enum EndTagType {
     Autoescape,
+    EndCache,
     Elif,
     Else,
     EndIf,
     Empty,
     EndFor,
+    EndLocalize,
+    EndLocalTime,
+    EndTimezone,
     Verbatim,
 }
 
@@ -487,11 +777,15 @@ impl EndTagType {
     fn as_str(&self) -> &'static str {
         match self {
             Self::Autoescape => "endautoescape",
+            Self::EndCache => "endcache",
             Self::Elif => "elif",
             Self::Else => "else",
             Self::EndIf => "endif",
             Self::Empty => "empty",
             Self::EndFor => "endfor",
+            Self::EndLocalize => "endlocalize",
+            Self::EndLocalTime => "endlocaltime",
+            Self::EndTimezone => "endtimezone",
             Self::Verbatim => "endverbatim",
         }
     }
@@ -597,6 +891,11 @@ pub enum ParseError {
     #[error(transparent)]
     #[diagnostic(transparent)]
     BlockError(#[from] TagLexerError),
+    #[error("'cache' tag requires at least 2 arguments.")]
+    CacheArguments {
+        #[label("here")]
+        at: SourceSpan,
+    },
     #[error(transparent)]
     #[diagnostic(transparent)]
     LexerError(#[from] LexerError),
@@ -606,6 +905,9 @@ pub enum ParseError {
     #[error(transparent)]
     #[diagnostic(transparent)]
     ForLexerInError(#[from] ForLexerInError),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    L10nError(#[from] L10nError),
     #[allow(clippy::enum_variant_names)]
     #[error(transparent)]
     #[diagnostic(transparent)]
@@ -622,6 +924,11 @@ pub enum ParseError {
     #[error(transparent)]
     #[diagnostic(transparent)]
     VariableError(#[from] VariableLexerError),
+    #[error("'get_current_timezone' requires 'as variable'")]
+    GetCurrentTimezoneAs {
+        #[label("here")]
+        at: SourceSpan,
+    },
     #[error("Invalid filter: '{filter}'")]
     InvalidFilter {
         filter: String,
@@ -709,6 +1016,11 @@ pub enum ParseError {
         #[label("after this keyword argument")]
         after: SourceSpan,
     },
+    #[error("'timezone' takes one argument (timezone)")]
+    TimezoneArgument {
+        #[label("here")]
+        at: SourceSpan,
+    },
     #[error("Unexpected positional argument")]
     TooManyPositionalArguments {
         #[label("here")]
@@ -822,7 +1134,33 @@ struct SimpleTagContext<'py> {
 
 #[derive(Clone)]
 enum TagContext<'py> {
+    Cache,
+    GetCurrentTimezone,
+    Localize,
+    LocalTime,
     SimpleTag(SimpleTagContext<'py>),
+    Timezone,
+}
+
+impl<'py> TagContext<'py> {
+    /// Recognise the tags of Django's own libraries that we implement natively.
+    fn builtin(tag: &Bound<'py, PyAny>) -> Option<Self> {
+        let py = tag.py();
+        let module: String = tag
+            .getattr(intern!(py, "__module__"))
+            .ok()?
+            .extract()
+            .ok()?;
+        let name: String = tag.getattr(intern!(py, "__name__")).ok()?.extract().ok()?;
+        Some(match (module.as_str(), name.as_str()) {
+            ("django.templatetags.cache", "do_cache") => Self::Cache,
+            ("django.templatetags.l10n", "localize_tag") => Self::Localize,
+            ("django.templatetags.tz", "localtime_tag") => Self::LocalTime,
+            ("django.templatetags.tz", "timezone_tag") => Self::Timezone,
+            ("django.templatetags.tz", "get_current_timezone_tag") => Self::GetCurrentTimezone,
+            _ => return None,
+        })
+    }
 }
 
 pub struct Parser<'t, 'l, 'py> {
@@ -1053,6 +1391,11 @@ impl<'t, 'l, 'py> Parser<'t, 'l, 'py> {
                 at,
                 parts,
             }),
+            "endcache" => Either::Right(EndTag {
+                end: EndTagType::EndCache,
+                at,
+                parts,
+            }),
             "endverbatim" => Either::Right(EndTag {
                 end: EndTagType::Verbatim,
                 at,
@@ -1085,10 +1428,32 @@ impl<'t, 'l, 'py> Parser<'t, 'l, 'py> {
                 at,
                 parts,
             }),
+            "endlocalize" => Either::Right(EndTag {
+                end: EndTagType::EndLocalize,
+                at,
+                parts,
+            }),
+            "endlocaltime" => Either::Right(EndTag {
+                end: EndTagType::EndLocalTime,
+                at,
+                parts,
+            }),
+            "endtimezone" => Either::Right(EndTag {
+                end: EndTagType::EndTimezone,
+                at,
+                parts,
+            }),
             tag_name => match self.external_tags.get(tag_name) {
                 Some(TagContext::SimpleTag(context)) => {
                     Either::Left(self.parse_simple_tag(context, at, parts)?)
                 }
+                Some(TagContext::Cache) => Either::Left(self.parse_cache(at, parts)?),
+                Some(TagContext::Localize) => Either::Left(self.parse_localize(at, parts)?),
+                Some(TagContext::LocalTime) => Either::Left(self.parse_localtime(at, parts)?),
+                Some(TagContext::Timezone) => Either::Left(self.parse_timezone(at, parts)?),
+                Some(TagContext::GetCurrentTimezone) => {
+                    Either::Left(self.parse_get_current_timezone(at, parts)?)
+                }
                 None => todo!("{tag_name}"),
             },
         })
@@ -1266,6 +1631,10 @@ impl<'t, 'l, 'py> Parser<'t, 'l, 'py> {
         name: &str,
         tag: &Bound<'py, PyAny>,
     ) -> Result<(), PyParseError> {
+        if let Some(builtin) = TagContext::builtin(tag) {
+            self.external_tags.insert(name.to_string(), builtin);
+            return Ok(());
+        }
         let closure = tag.getattr("__closure__")?;
         let tag = if closure.is_none() {
             todo!("Fully custom tag")
@@ -1423,6 +1792,115 @@ impl<'t, 'l, 'py> Parser<'t, 'l, 'py> {
         }))
     }
 
+    fn parse_cache(
+        &mut self,
+        at: (usize, usize),
+        parts: TagParts,
+    ) -> Result<TokenTree, PyParseError> {
+        let mut tokens = SimpleTagLexer::new(self.template, parts)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ParseError::from)?;
+        if tokens.len() < 2 {
+            return Err(ParseError::CacheArguments { at: at.into() }.into());
+        }
+        let cache_name = match tokens.last() {
+            Some(SimpleTagToken {
+                kwarg: Some(kwarg), ..
+            }) if tokens.len() > 2 && self.template.content(*kwarg) == "using" => {
+                let token = tokens.pop().expect("tokens is not empty");
+                Some((token.parse(self)?, token.at))
+            }
+            _ => None,
+        };
+        let mut tokens = tokens.into_iter();
+        let expire_time = tokens.next().expect("tokens has at least two elements");
+        let fragment_name = tokens.next().expect("tokens has at least two elements");
+        let fragment_name = self.template.content(fragment_name.at).to_string();
+        let expire_time_at = expire_time.at;
+        let expire_time = self.parse_cache_argument(expire_time)?;
+        let vary_on = tokens
+            .map(|token| self.parse_cache_argument(token))
+            .collect::<Result<_, _>>()?;
+        let (nodes, _) = self.parse_until(vec![EndTagType::EndCache], "cache", at)?;
+        Ok(TokenTree::Tag(Tag::Cache(Cache {
+            expire_time,
+            expire_time_at,
+            fragment_name,
+            vary_on,
+            cache_name,
+            nodes,
+        })))
+    }
+
+    fn parse_cache_argument(&self, token: SimpleTagToken) -> Result<TagElement, ParseError> {
+        match token.kwarg {
+            Some(kwarg) => {
+                let at = (kwarg.0, kwarg.1 + 1 + token.at.1);
+                Err(ParseError::UnexpectedKeywordArgument { at: at.into() })
+            }
+            None => token.parse(self),
+        }
+    }
+
+    fn parse_localize(
+        &mut self,
+        at: (usize, usize),
+        parts: TagParts,
+    ) -> Result<TokenTree, PyParseError> {
+        let token =
+            lex_l10n_argument(self.template, parts, "localize").map_err(ParseError::from)?;
+        let (nodes, _) = self.parse_until(vec![EndTagType::EndLocalize], "localize", at)?;
+        Ok(TokenTree::Tag(Tag::Localize {
+            enabled: token.enabled,
+            nodes,
+        }))
+    }
+
+    fn parse_localtime(
+        &mut self,
+        at: (usize, usize),
+        parts: TagParts,
+    ) -> Result<TokenTree, PyParseError> {
+        let token =
+            lex_l10n_argument(self.template, parts, "localtime").map_err(ParseError::from)?;
+        let (nodes, _) = self.parse_until(vec![EndTagType::EndLocalTime], "localtime", at)?;
+        Ok(TokenTree::Tag(Tag::LocalTime {
+            enabled: token.enabled,
+            nodes,
+        }))
+    }
+
+    fn parse_timezone(
+        &mut self,
+        at: (usize, usize),
+        parts: TagParts,
+    ) -> Result<TokenTree, PyParseError> {
+        let mut lexer = SimpleTagLexer::new(self.template, parts);
+        let timezone = match lexer.next() {
+            Some(token) => token.map_err(ParseError::from)?.parse(self)?,
+            None => return Err(ParseError::TimezoneArgument { at: at.into() }.into()),
+        };
+        if lexer.next().is_some() {
+            return Err(ParseError::TimezoneArgument { at: at.into() }.into());
+        }
+        let (nodes, _) = self.parse_until(vec![EndTagType::EndTimezone], "timezone", at)?;
+        Ok(TokenTree::Tag(Tag::Timezone { timezone, nodes }))
+    }
+
+    fn parse_get_current_timezone(
+        &self,
+        at: (usize, usize),
+        parts: TagParts,
+    ) -> Result<TokenTree, ParseError> {
+        let content = self.template.content(parts.at);
+        match content.split_whitespace().collect::<Vec<_>>()[..] {
+            ["as", variable] => Ok(TokenTree::Tag(Tag::GetCurrentTimezone {
+                variable: variable.to_string(),
+            })),
+            _ => Err(ParseError::GetCurrentTimezoneAs { at: at.into() }),
+        }
+    }
+
     fn parse_if(
         &mut self,
         at: (usize, usize),
@@ -1646,6 +2124,9 @@ mod tests {
                 filter: FilterType::External(ExternalFilter {
                     filter: external,
                     argument: None,
+                    is_safe: false,
+                    needs_autoescape: false,
+                    expects_localtime: false,
                 }),
             }));
             assert_eq!(nodes, vec![bar]);
@@ -1699,6 +2180,9 @@ mod tests {
                 filter: FilterType::External(ExternalFilter {
                     filter: external,
                     argument: None,
+                    is_safe: false,
+                    needs_autoescape: false,
+                    expects_localtime: false,
                 }),
             }));
             let external = get_external_filter(&nodes[0]);
@@ -1709,6 +2193,9 @@ mod tests {
                 filter: FilterType::External(ExternalFilter {
                     filter: external,
                     argument: None,
+                    is_safe: false,
+                    needs_autoescape: false,
+                    expects_localtime: false,
                 }),
             }));
             assert_eq!(nodes, vec![baz]);
@@ -1740,6 +2227,9 @@ mod tests {
                         at: (11, 3),
                         argument_type: ArgumentType::Variable(baz),
                     }),
+                    is_safe: false,
+                    needs_autoescape: false,
+                    expects_localtime: false,
                 }),
             }));
             assert_eq!(nodes, vec![bar]);
@@ -1774,6 +2264,9 @@ mod tests {
                         at: (11, 5),
                         argument_type: ArgumentType::Text(baz),
                     }),
+                    is_safe: false,
+                    needs_autoescape: false,
+                    expects_localtime: false,
                 }),
             }));
             assert_eq!(nodes, vec![bar]);
@@ -1805,6 +2298,9 @@ mod tests {
                         at: (11, 8),
                         argument_type: ArgumentType::TranslatedText(baz),
                     }),
+                    is_safe: false,
+                    needs_autoescape: false,
+                    expects_localtime: false,
                 }),
             }));
             assert_eq!(nodes, vec![bar]);
@@ -1836,6 +2332,9 @@ mod tests {
                 filter: FilterType::External(ExternalFilter {
                     filter: external,
                     argument: Some(num),
+                    is_safe: false,
+                    needs_autoescape: false,
+                    expects_localtime: false,
                 }),
             }));
             assert_eq!(nodes, vec![bar]);
@@ -1866,6 +2365,9 @@ mod tests {
                 filter: FilterType::External(ExternalFilter {
                     filter: external,
                     argument: Some(num),
+                    is_safe: false,
+                    needs_autoescape: false,
+                    expects_localtime: false,
                 }),
             }));
             assert_eq!(nodes, vec![bar]);
@@ -1896,6 +2398,9 @@ mod tests {
                 filter: FilterType::External(ExternalFilter {
                     filter: external,
                     argument: Some(num),
+                    is_safe: false,
+                    needs_autoescape: false,
+                    expects_localtime: false,
                 }),
             }));
             assert_eq!(nodes, vec![bar]);
@@ -1925,7 +2430,7 @@ mod tests {
             let context = PyDict::new(py);
             context.set_item("bar", "").unwrap();
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "");
 
@@ -2340,8 +2845,8 @@ mod tests {
                 FilterType::Lower(LowerFilter)
             );
             assert_ne!(
-                FilterType::External(ExternalFilter::new(py.None(), None)),
-                FilterType::External(ExternalFilter::new(py.None(), None))
+                FilterType::External(ExternalFilter::new(py.None().bind(py), None)),
+                FilterType::External(ExternalFilter::new(py.None().bind(py), None))
             );
             assert_ne!(
                 FilterType::Lower(LowerFilter),
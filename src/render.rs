@@ -1,7 +1,18 @@
 pub mod common;
+pub mod dateformat;
 pub mod filters;
+mod html;
+mod humanize;
+pub mod numberformat;
+mod stringformat;
 pub mod tags;
+pub mod timesince;
+mod translation;
+pub mod truncate;
 pub mod types;
+mod url;
+mod urlize;
+mod wrap;
 
 use std::borrow::Cow;
 
@@ -82,7 +93,7 @@ where
         context: &mut Context,
     ) -> RenderResult<'t> {
         match self.resolve(py, template, context, ResolveFailures::Raise)? {
-            Some(content) => Ok(content.render(context)?),
+            Some(content) => Ok(content.localize(py, context)?.render(context)?),
             None => Ok(Cow::Borrowed("")),
         }
     }
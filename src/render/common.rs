@@ -6,7 +6,7 @@ use pyo3::types::PyString;
 
 use super::types::{AsBorrowedContent, Content, ContentString, Context};
 use super::{Evaluate, Render, RenderResult, Resolve, ResolveFailures, ResolveResult};
-use crate::error::RenderError;
+use crate::error::{PyRenderError, RenderError};
 use crate::parse::{TagElement, TokenTree};
 use crate::types::Argument;
 use crate::types::ArgumentType;
@@ -97,6 +97,28 @@ impl Resolve for Variable {
     }
 }
 
+impl Variable {
+    /// Resolve like Django's `FilterExpression.resolve`, treating a failed
+    /// attribute or key lookup as missing when the engine has a
+    /// `string_if_invalid` to render instead.
+    pub fn resolve_lookup<'t, 'py>(
+        &self,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+        failures: ResolveFailures,
+    ) -> ResolveResult<'t, 'py> {
+        match self.resolve(py, template, context, failures) {
+            Err(PyRenderError::RenderError(RenderError::VariableDoesNotExist { .. }))
+                if !context.string_if_invalid.is_empty() =>
+            {
+                Ok(None)
+            }
+            resolved => resolved,
+        }
+    }
+}
+
 impl Resolve for ForVariable {
     fn resolve<'t, 'py>(
         &self,
@@ -221,6 +243,94 @@ impl Resolve for TagElement {
     }
 }
 
+impl TagElement {
+    /// Resolve this element, using `Variable::resolve_lookup` for variables.
+    pub fn resolve_lookup<'t, 'py>(
+        &self,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+        failures: ResolveFailures,
+    ) -> ResolveResult<'t, 'py> {
+        match self {
+            Self::Variable(variable) => variable.resolve_lookup(py, template, context, failures),
+            element => element.resolve(py, template, context, failures),
+        }
+    }
+
+    /// The variable this element looks up, before applying any filters.
+    fn variable(&self) -> Option<&Variable> {
+        match self {
+            Self::Variable(variable) => Some(variable),
+            Self::Filter(filter) => filter.left.variable(),
+            _ => None,
+        }
+    }
+
+    /// Resolve like Django's `FilterExpression.resolve`, which uses the
+    /// engine's `string_if_invalid` when the variable lookup fails.
+    ///
+    /// Without a `string_if_invalid`, failed lookups are handled by `failures`.
+    pub fn resolve_or_invalid<'t, 'py>(
+        &self,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+        failures: ResolveFailures,
+    ) -> ResolveResult<'t, 'py> {
+        if context.string_if_invalid.is_empty() {
+            return self.resolve(py, template, context, failures);
+        }
+        match self.resolve_lookup(py, template, context, ResolveFailures::Raise)? {
+            Some(content) => Ok(Some(content)),
+            None => string_if_invalid(self.variable(), py, template, context),
+        }
+    }
+}
+
+/// The engine's `string_if_invalid` for a failed lookup of `variable`, with
+/// `%s` replaced by the variable's name, or `None` if it is empty.
+fn string_if_invalid<'t, 'py>(
+    variable: Option<&Variable>,
+    py: Python<'py>,
+    template: TemplateString<'t>,
+    context: &Context,
+) -> ResolveResult<'t, 'py> {
+    let string_if_invalid = &context.string_if_invalid;
+    let Some(variable) = variable.filter(|_| !string_if_invalid.is_empty()) else {
+        return Ok(None);
+    };
+    let invalid = match string_if_invalid.contains("%s") {
+        true => PyString::new(py, string_if_invalid)
+            .rem(template.content(variable.at))?
+            .extract()?,
+        false => string_if_invalid.clone(),
+    };
+    Ok(Some(Content::String(match context.autoescape {
+        false => ContentString::String(Cow::Owned(invalid)),
+        true => ContentString::HtmlUnsafe(Cow::Owned(invalid)),
+    })))
+}
+
+/// Render the resolved content of a `{{ }}` node, using `string_if_invalid`
+/// if looking up `variable` failed.
+fn render_variable_node<'t>(
+    content: Option<Content<'t, '_>>,
+    variable: Option<&Variable>,
+    py: Python<'_>,
+    template: TemplateString<'t>,
+    context: &mut Context,
+) -> RenderResult<'t> {
+    let content = match content {
+        Some(content) => content.localize(py, context)?,
+        None => match string_if_invalid(variable, py, template, context)? {
+            Some(content) => content,
+            None => return Ok(Cow::Borrowed("")),
+        },
+    };
+    Ok(content.render(context)?)
+}
+
 impl Evaluate for TagElement {
     fn evaluate(
         &self,
@@ -250,12 +360,21 @@ impl Render for TokenTree {
         match self {
             Self::Text(text) => text.render(py, template, context),
             Self::TranslatedText(_text) => todo!(),
-            Self::Int(n) => Ok(n.to_string().into()),
-            Self::Float(f) => Ok(f.to_string().into()),
+            Self::Int(n) => Ok(Content::Int(n.clone())
+                .localize(py, context)?
+                .render(context)?),
+            Self::Float(f) => Ok(Content::Float(*f).localize(py, context)?.render(context)?),
             Self::Tag(tag) => tag.render(py, template, context),
-            Self::Variable(variable) => variable.render(py, template, context),
+            Self::Variable(variable) => {
+                let content =
+                    variable.resolve_lookup(py, template, context, ResolveFailures::Raise)?;
+                render_variable_node(content, Some(variable), py, template, context)
+            }
             Self::ForVariable(variable) => variable.render(py, template, context),
-            Self::Filter(filter) => filter.render(py, template, context),
+            Self::Filter(filter) => {
+                let content = filter.resolve(py, template, context, ResolveFailures::Raise)?;
+                render_variable_node(content, filter.left.variable(), py, template, context)
+            }
         }
     }
 }
@@ -348,6 +467,43 @@ user = User('Lily')
         })
     }
 
+    #[test]
+    fn test_render_string_if_invalid() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let mut context = Context::new(HashMap::new(), None, true);
+            context.string_if_invalid = "<%s>".to_string();
+            let template = TemplateString("{{ missing }}");
+            let variable = Variable::new((3, 7));
+
+            let rendered = TokenTree::Variable(variable)
+                .render(py, template, &mut context)
+                .unwrap();
+            assert_eq!(rendered, "&lt;missing&gt;");
+        })
+    }
+
+    #[test]
+    fn test_render_string_if_invalid_attribute() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let user = PyDict::new(py);
+            user.set_item("name", "Lily").unwrap();
+            let context = HashMap::from([("user".to_string(), user.into_any().unbind())]);
+            let mut context = Context::new(context, None, false);
+            context.string_if_invalid = "INVALID %s".to_string();
+            let template = TemplateString("{{ user.nmae }}");
+            let variable = Variable::new((3, 9));
+
+            let rendered = TokenTree::Variable(variable)
+                .render(py, template, &mut context)
+                .unwrap();
+            assert_eq!(rendered, "INVALID user.nmae");
+        })
+    }
+
     #[test]
     fn test_render_html_autoescape() {
         Python::initialize();
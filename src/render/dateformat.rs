@@ -0,0 +1,408 @@
+//! A port of `django.utils.dateformat`.
+//!
+//! Values are extracted from Python `datetime`, `date` and `time` objects and
+//! formatted in Rust. Translated names still come from Django so they follow the
+//! active language, and the timezone-dependent format characters defer to Django's
+//! own `DateFormat` so that they match it exactly.
+
+use pyo3::exceptions::{PyAttributeError, PyTypeError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::type_object::PyTypeInfo;
+use pyo3::types::{PyDate, PyDateAccess, PyDateTime, PyTime, PyTimeAccess};
+
+use crate::render::translation::gettext;
+use crate::utils::PyResultMethods;
+
+const TIME_CHARS: &str = "aAefgGhHiOPsTuZ";
+const DATE_CHARS: &str = "bcdDEFIjlLmMnNorStUwWyYz";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Time {
+    hour: u8,
+    minute: u8,
+    second: u8,
+    microsecond: u32,
+}
+
+fn is_leap(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl Date {
+    fn day_of_year(&self) -> u32 {
+        (1..self.month)
+            .map(|month| days_in_month(self.year, month) as u32)
+            .sum::<u32>()
+            + self.day as u32
+    }
+
+    /// The proleptic Gregorian ordinal, where 0001-01-01 is day 1.
+    pub fn ordinal(&self) -> i64 {
+        let year = self.year as i64 - 1;
+        year * 365 + year / 4 - year / 100 + year / 400 + self.day_of_year() as i64
+    }
+
+    /// Monday is 0 and Sunday is 6, as in Python's `date.weekday`.
+    fn weekday(&self) -> u8 {
+        ((self.ordinal() + 6) % 7) as u8
+    }
+
+    /// The ISO 8601 year and week number, as in Python's `date.isocalendar`.
+    fn iso_year_week(&self) -> (i32, i64) {
+        fn week1_monday(year: i32) -> i64 {
+            let first_day = Date {
+                year,
+                month: 1,
+                day: 1,
+            };
+            let first_weekday = first_day.weekday() as i64;
+            let monday = first_day.ordinal() - first_weekday;
+            // Week 1 is the first week containing a Thursday.
+            match first_weekday > 3 {
+                true => monday + 7,
+                false => monday,
+            }
+        }
+
+        let today = self.ordinal();
+        let mut year = self.year;
+        let mut week = (today - week1_monday(year)).div_euclid(7);
+        if week < 0 {
+            year -= 1;
+            week = (today - week1_monday(year)).div_euclid(7);
+        } else if week >= 52 && today >= week1_monday(year + 1) {
+            year += 1;
+            week = 0;
+        }
+        (year, week + 1)
+    }
+
+    /// English ordinal suffix for the day of the month.
+    fn suffix(&self) -> &'static str {
+        match self.day {
+            11..=13 => "th",
+            day => match day % 10 {
+                1 => "st",
+                2 => "nd",
+                3 => "rd",
+                _ => "th",
+            },
+        }
+    }
+}
+
+impl Time {
+    fn hour_12(&self) -> u8 {
+        match self.hour % 12 {
+            0 => 12,
+            hour => hour,
+        }
+    }
+
+    /// Hours and minutes in 12-hour format, with the minutes left off if zero.
+    fn f(&self) -> String {
+        match self.minute {
+            0 => self.hour_12().to_string(),
+            minute => format!("{}:{minute:02}", self.hour_12()),
+        }
+    }
+}
+
+/// Look up a translated name in one of the mappings in `django.utils.dates`.
+fn date_name<'py>(py: Python<'py>, mapping: &str, key: u8) -> PyResult<Bound<'py, PyAny>> {
+    py.import(intern!(py, "django.utils.dates"))?
+        .getattr(mapping)?
+        .get_item(key)?
+        .str()
+        .map(Bound::into_any)
+}
+
+/// Remove the backslash from escaped characters in literal text.
+fn unescape(literal: &str, output: &mut String) {
+    let mut chars = literal.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('\\', Some(&next)) if next != '\n' => {
+                output.push(next);
+                chars.next();
+            }
+            _ => output.push(c),
+        }
+    }
+}
+
+pub struct DateFormat<'py> {
+    value: Bound<'py, PyAny>,
+    date: Option<Date>,
+    time: Option<Time>,
+    /// Plain `datetime.date` objects reject time-related format characters.
+    is_date: bool,
+    django: Option<Bound<'py, PyAny>>,
+}
+
+impl<'py> DateFormat<'py> {
+    /// Extract the fields of a `datetime`, `date` or `time` object.
+    ///
+    /// Returns `None` for any other type of object.
+    pub fn new(value: Bound<'py, PyAny>) -> Option<Self> {
+        let py = value.py();
+        let (date, time) = if let Ok(datetime) = value.cast::<PyDateTime>() {
+            (
+                Some(Date {
+                    year: datetime.get_year(),
+                    month: datetime.get_month(),
+                    day: datetime.get_day(),
+                }),
+                Some(Time {
+                    hour: datetime.get_hour(),
+                    minute: datetime.get_minute(),
+                    second: datetime.get_second(),
+                    microsecond: datetime.get_microsecond(),
+                }),
+            )
+        } else if let Ok(date) = value.cast::<PyDate>() {
+            (
+                Some(Date {
+                    year: date.get_year(),
+                    month: date.get_month(),
+                    day: date.get_day(),
+                }),
+                None,
+            )
+        } else if let Ok(time) = value.cast::<PyTime>() {
+            (
+                None,
+                Some(Time {
+                    hour: time.get_hour(),
+                    minute: time.get_minute(),
+                    second: time.get_second(),
+                    microsecond: time.get_microsecond(),
+                }),
+            )
+        } else {
+            return None;
+        };
+        let is_date = value.get_type().is(PyDate::type_object(py));
+        Some(Self {
+            value,
+            date,
+            time,
+            is_date,
+            django: None,
+        })
+    }
+
+    /// Format the value with a Django date format string.
+    ///
+    /// When `time_only` is set only the time-related format characters are
+    /// supported, as for Django's `time_format`. Returns `None` when the format
+    /// needs a field the value does not have.
+    pub fn format(&mut self, format: &str, time_only: bool) -> PyResult<Option<String>> {
+        let mut output = String::with_capacity(format.len());
+        let mut literal = String::new();
+        let mut previous = None;
+        for c in format.chars() {
+            let is_format_char =
+                (TIME_CHARS.contains(c) || DATE_CHARS.contains(c)) && previous != Some('\\');
+            previous = Some(c);
+            if !is_format_char {
+                literal.push(c);
+                continue;
+            }
+            unescape(&literal, &mut output);
+            literal.clear();
+            match self.format_char(c, time_only)? {
+                Some(piece) => output.push_str(&piece),
+                None => return Ok(None),
+            }
+        }
+        unescape(&literal, &mut output);
+        Ok(Some(output))
+    }
+
+    fn format_char(&mut self, c: char, time_only: bool) -> PyResult<Option<String>> {
+        let is_time_char = TIME_CHARS.contains(c);
+        if self.is_date && is_time_char {
+            return Err(PyTypeError::new_err(format!(
+                "The format for date objects may not contain time-related format specifiers (found '{c}')."
+            )));
+        }
+        if time_only && !is_time_char {
+            return Ok(None);
+        }
+        let py = self.value.py();
+        if let Some(time) = self.time {
+            let piece = match c {
+                'a' if time.hour > 11 => Some(gettext(py, "p.m.")?),
+                'a' => Some(gettext(py, "a.m.")?),
+                'A' if time.hour > 11 => Some(gettext(py, "PM")?),
+                'A' => Some(gettext(py, "AM")?),
+                'f' => Some(time.f()),
+                'g' => Some(time.hour_12().to_string()),
+                'G' => Some(time.hour.to_string()),
+                'h' => Some(format!("{:02}", time.hour_12())),
+                'H' => Some(format!("{:02}", time.hour)),
+                'i' => Some(format!("{:02}", time.minute)),
+                'P' => Some(match (time.hour, time.minute) {
+                    (0, 0) => gettext(py, "midnight")?,
+                    (12, 0) => gettext(py, "noon")?,
+                    _ => {
+                        let meridiem = match time.hour > 11 {
+                            true => gettext(py, "p.m.")?,
+                            false => gettext(py, "a.m.")?,
+                        };
+                        format!("{} {meridiem}", time.f())
+                    }
+                }),
+                's' => Some(format!("{:02}", time.second)),
+                'u' => Some(format!("{:06}", time.microsecond)),
+                _ => None,
+            };
+            if piece.is_some() {
+                return Ok(piece);
+            }
+        }
+        if let Some(date) = self.date {
+            let piece = match c {
+                'b' => Some(date_name(py, "MONTHS_3", date.month)?.extract()?),
+                'd' => Some(format!("{:02}", date.day)),
+                'D' => Some(date_name(py, "WEEKDAYS_ABBR", date.weekday())?.extract()?),
+                'E' => Some(date_name(py, "MONTHS_ALT", date.month)?.extract()?),
+                'F' => Some(date_name(py, "MONTHS", date.month)?.extract()?),
+                'j' => Some(date.day.to_string()),
+                'l' => Some(date_name(py, "WEEKDAYS", date.weekday())?.extract()?),
+                'L' => Some(match is_leap(date.year) {
+                    true => "True".to_string(),
+                    false => "False".to_string(),
+                }),
+                'm' => Some(format!("{:02}", date.month)),
+                'M' => Some(
+                    date_name(py, "MONTHS_3", date.month)?
+                        .call_method0(intern!(py, "title"))?
+                        .extract()?,
+                ),
+                'n' => Some(date.month.to_string()),
+                'N' => Some(date_name(py, "MONTHS_AP", date.month)?.extract()?),
+                'o' => Some(date.iso_year_week().0.to_string()),
+                'S' => Some(date.suffix().to_string()),
+                't' => Some(days_in_month(date.year, date.month).to_string()),
+                'w' => Some(((date.weekday() + 1) % 7).to_string()),
+                'W' => Some(date.iso_year_week().1.to_string()),
+                'y' => Some(format!("{:02}", date.year % 100)),
+                'Y' => Some(format!("{:04}", date.year)),
+                'z' => Some(date.day_of_year().to_string()),
+                _ => None,
+            };
+            if piece.is_some() {
+                return Ok(piece);
+            }
+        }
+        self.format_with_django(c)
+    }
+
+    /// Use Django's `DateFormat` for the timezone-dependent format characters.
+    fn format_with_django(&mut self, c: char) -> PyResult<Option<String>> {
+        let py = self.value.py();
+        let django = match &self.django {
+            Some(django) => django,
+            None => {
+                let date_format = py
+                    .import(intern!(py, "django.utils.dateformat"))?
+                    .getattr(intern!(py, "DateFormat"))?
+                    .call1((&self.value,))?;
+                self.django.insert(date_format)
+            }
+        };
+        let mut buf = [0; 4];
+        let method = c.encode_utf8(&mut buf);
+        match django
+            .call_method0(&*method)
+            .ok_or_isinstance_of::<PyAttributeError>(py)?
+        {
+            Ok(piece) => Ok(Some(piece.str()?.extract()?)),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date { year, month, day }
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(date(1, 1, 1).weekday(), 0);
+        assert_eq!(date(2000, 1, 1).weekday(), 5);
+        assert_eq!(date(2024, 2, 29).weekday(), 3);
+    }
+
+    #[test]
+    fn test_day_of_year() {
+        assert_eq!(date(2023, 12, 31).day_of_year(), 365);
+        assert_eq!(date(2024, 12, 31).day_of_year(), 366);
+        assert_eq!(date(2024, 3, 1).day_of_year(), 61);
+    }
+
+    #[test]
+    fn test_iso_year_week() {
+        assert_eq!(date(2005, 1, 1).iso_year_week(), (2004, 53));
+        assert_eq!(date(2007, 12, 31).iso_year_week(), (2008, 1));
+        assert_eq!(date(2008, 12, 28).iso_year_week(), (2008, 52));
+        assert_eq!(date(2010, 1, 4).iso_year_week(), (2010, 1));
+    }
+
+    #[test]
+    fn test_suffix() {
+        let suffixes: Vec<_> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 31]
+            .into_iter()
+            .map(|day| date(2000, 1, day).suffix())
+            .collect();
+        assert_eq!(
+            suffixes,
+            [
+                "st", "nd", "rd", "th", "th", "th", "th", "st", "nd", "rd", "st"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hours_and_minutes() {
+        let time = |hour, minute| Time {
+            hour,
+            minute,
+            second: 0,
+            microsecond: 0,
+        };
+        assert_eq!(time(0, 0).f(), "12");
+        assert_eq!(time(13, 5).f(), "1:05");
+        assert_eq!(time(12, 30).f(), "12:30");
+    }
+
+    #[test]
+    fn test_unescape() {
+        let mut output = String::new();
+        unescape(r"\\a\b c\", &mut output);
+        assert_eq!(output, r"\ab c\");
+    }
+}
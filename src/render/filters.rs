@@ -1,22 +1,57 @@
 use std::borrow::Cow;
 use std::sync::LazyLock;
 
-use html_escape::encode_quoted_attribute_to_string;
-use num_bigint::{BigInt, ToBigInt};
-use num_traits::ToPrimitive;
+use html_escape::{encode_quoted_attribute, encode_quoted_attribute_to_string};
+use num_bigint::{BigInt, BigUint, ToBigInt};
+use num_traits::{Signed, ToPrimitive, Zero};
+use pyo3::exceptions::{
+    PyAttributeError, PyException, PyIndexError, PyKeyError, PyRecursionError, PyTypeError,
+    PyValueError,
+};
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::sync::PyOnceLock;
-use pyo3::types::PyType;
+use pyo3::types::{
+    IntoPyDict, PyBool, PyDate, PyDateAccess, PyDateTime, PyDict, PyFloat, PyInt, PyList, PyRange,
+    PySlice, PyString, PyTuple, PyType, PyTzInfoAccess,
+};
 
-use crate::error::RenderError;
+use crate::error::{PyRenderError, RenderError};
 use crate::filters::{
-    AddFilter, AddSlashesFilter, CapfirstFilter, CenterFilter, DefaultFilter, EscapeFilter,
-    ExternalFilter, FilterType, LowerFilter, SafeFilter, SlugifyFilter, UpperFilter,
+    AddFilter, AddSlashesFilter, ApNumberFilter, CapfirstFilter, CenterFilter, CutFilter,
+    DateFilter, DefaultFilter, DefaultIfNoneFilter, DictSortFilter, DictSortReversedFilter,
+    DivisibleByFilter, EscapeFilter, EscapeJsFilter, EscapeSeqFilter, ExternalFilter,
+    FileSizeFormatFilter, FilterType, FirstFilter, FloatFormatFilter, ForceEscapeFilter,
+    GetDigitFilter, IntCommaFilter, IntWordFilter, IriEncodeFilter, JoinFilter, JsonScriptFilter,
+    LastFilter, LengthFilter, LineBreaksBrFilter, LineBreaksFilter, LineNumbersFilter, LjustFilter,
+    LowerFilter, MakeListFilter, NaturalDayFilter, NaturalTimeFilter, OrdinalFilter,
+    Phone2NumericFilter, PluralizeFilter, PprintFilter, RandomFilter, RjustFilter, SafeFilter,
+    SafeSeqFilter, SliceFilter, SlugifyFilter, StringFormatFilter, StripTagsFilter, TimeFilter,
+    TimeSinceFilter, TimeUntilFilter, TitleFilter, TruncateCharsFilter, TruncateCharsHtmlFilter,
+    TruncateWordsFilter, TruncateWordsHtmlFilter, UnorderedListFilter, UpperFilter,
+    UrlEncodeFilter, UrlizeFilter, UrlizeTruncFilter, WordCountFilter, WordWrapFilter, YesNoFilter,
 };
 use crate::parse::Filter;
+use crate::render::dateformat::{Date, DateFormat};
+use crate::render::html::escapejs;
+use crate::render::humanize::{
+    AP_NUMBERS, INTWORD_CONVERTERS, float_repr, intcomma, interpolate, naturaltime, ordinal_format,
+    round_away_from_one,
+};
+use crate::render::numberformat::{Decimal, ParsedDecimal};
+use crate::render::stringformat::{FormatSpec, Value};
+use crate::render::timesince::timesince;
+use crate::render::translation::{gettext, ngettext, pgettext};
+use crate::render::truncate::{
+    truncate_chars, truncate_html_chars, truncate_html_words, truncate_words,
+};
 use crate::render::types::{AsBorrowedContent, Content, ContentString, Context, IntoOwnedContent};
+use crate::render::url::{iri_to_uri, quote};
+use crate::render::urlize::urlize;
+use crate::render::wrap::wrap;
 use crate::render::{Resolve, ResolveFailures, ResolveResult};
-use crate::types::TemplateString;
+use crate::types::{Argument, TemplateString};
+use crate::utils::{PyResultMethods, is_python_whitespace};
 use regex::Regex;
 use unicode_normalization::UnicodeNormalization;
 
@@ -28,7 +63,30 @@ static NON_WORD_RE: LazyLock<Regex> =
 static WHITESPACE_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"[-\s]+").expect("Static string will never panic"));
 
+// Used by `normalize_newlines` to convert all newlines to `\n`
+static NEWLINES_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\r\n|\r").expect("Static string will never panic"));
+
+// Two or more newlines separate paragraphs in the `linebreaks` filter
+static PARAGRAPHS_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\n{2,}").expect("Static string will never panic"));
+
+// The `title` filter doesn't capitalise after an apostrophe or a digit
+static TITLE_APOSTROPHE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[a-z]'[A-Z]").expect("Static string will never panic"));
+static TITLE_DIGIT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\d[A-Z]").expect("Static string will never panic"));
+
 static SAFEDATA: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+static MARK_SAFE: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+static RANDOM_CHOICE: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+static CONDITIONAL_ESCAPE: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+static JSON_DUMPS: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+static DJANGO_JSON_ENCODER: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+static GET_FORMAT: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+static TEMPLATE_LOCALTIME: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+static GENERATOR_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+static STRIP_TAGS: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
 
 impl Resolve for Filter {
     fn resolve<'t, 'py>(
@@ -38,20 +96,143 @@ impl Resolve for Filter {
         context: &mut Context,
         failures: ResolveFailures,
     ) -> ResolveResult<'t, 'py> {
-        let left = self.left.resolve(py, template, context, failures)?;
+        let left = self.left.resolve_lookup(py, template, context, failures)?;
+        // Django skips the filters when using `string_if_invalid`.
+        if left.is_none()
+            && failures == ResolveFailures::Raise
+            && !context.string_if_invalid.is_empty()
+        {
+            return Ok(None);
+        }
         match &self.filter {
             FilterType::Add(filter) => filter.resolve(left, py, template, context),
             FilterType::AddSlashes(filter) => filter.resolve(left, py, template, context),
+            FilterType::ApNumber(filter) => filter.resolve(left, py, template, context),
             FilterType::Capfirst(filter) => filter.resolve(left, py, template, context),
             FilterType::Center(filter) => filter.resolve(left, py, template, context),
+            FilterType::Cut(filter) => filter.resolve(left, py, template, context),
+            FilterType::Date(filter) => filter.resolve(left, py, template, context),
             FilterType::Default(filter) => filter.resolve(left, py, template, context),
+            FilterType::DefaultIfNone(filter) => filter.resolve(left, py, template, context),
+            FilterType::DictSort(filter) => filter.resolve(left, py, template, context),
+            FilterType::DictSortReversed(filter) => filter.resolve(left, py, template, context),
+            FilterType::DivisibleBy(filter) => filter.resolve(left, py, template, context),
             FilterType::Escape(filter) => filter.resolve(left, py, template, context),
+            FilterType::EscapeJs(filter) => filter.resolve(left, py, template, context),
+            FilterType::EscapeSeq(filter) => filter.resolve(left, py, template, context),
             FilterType::External(filter) => filter.resolve(left, py, template, context),
+            FilterType::FileSizeFormat(filter) => filter.resolve(left, py, template, context),
+            FilterType::First(filter) => filter.resolve(left, py, template, context),
+            FilterType::FloatFormat(filter) => filter.resolve(left, py, template, context),
+            FilterType::ForceEscape(filter) => filter.resolve(left, py, template, context),
+            FilterType::GetDigit(filter) => filter.resolve(left, py, template, context),
+            FilterType::IntComma(filter) => filter.resolve(left, py, template, context),
+            FilterType::IntWord(filter) => filter.resolve(left, py, template, context),
+            FilterType::IriEncode(filter) => filter.resolve(left, py, template, context),
+            FilterType::Join(filter) => filter.resolve(left, py, template, context),
+            FilterType::JsonScript(filter) => filter.resolve(left, py, template, context),
+            FilterType::Last(filter) => filter.resolve(left, py, template, context),
+            FilterType::Length(filter) => filter.resolve(left, py, template, context),
+            FilterType::LineBreaks(filter) => filter.resolve(left, py, template, context),
+            FilterType::LineBreaksBr(filter) => filter.resolve(left, py, template, context),
+            FilterType::LineNumbers(filter) => filter.resolve(left, py, template, context),
+            FilterType::Ljust(filter) => filter.resolve(left, py, template, context),
             FilterType::Lower(filter) => filter.resolve(left, py, template, context),
+            FilterType::MakeList(filter) => filter.resolve(left, py, template, context),
+            FilterType::NaturalDay(filter) => filter.resolve(left, py, template, context),
+            FilterType::NaturalTime(filter) => filter.resolve(left, py, template, context),
+            FilterType::Ordinal(filter) => filter.resolve(left, py, template, context),
+            FilterType::Phone2Numeric(filter) => filter.resolve(left, py, template, context),
+            FilterType::Pluralize(filter) => filter.resolve(left, py, template, context),
+            FilterType::Pprint(filter) => filter.resolve(left, py, template, context),
+            FilterType::Random(filter) => filter.resolve(left, py, template, context),
+            FilterType::Rjust(filter) => filter.resolve(left, py, template, context),
             FilterType::Safe(filter) => filter.resolve(left, py, template, context),
+            FilterType::SafeSeq(filter) => filter.resolve(left, py, template, context),
+            FilterType::Slice(filter) => filter.resolve(left, py, template, context),
             FilterType::Slugify(filter) => filter.resolve(left, py, template, context),
+            FilterType::StringFormat(filter) => filter.resolve(left, py, template, context),
+            FilterType::StripTags(filter) => filter.resolve(left, py, template, context),
+            FilterType::Time(filter) => filter.resolve(left, py, template, context),
+            FilterType::TimeSince(filter) => filter.resolve(left, py, template, context),
+            FilterType::TimeUntil(filter) => filter.resolve(left, py, template, context),
+            FilterType::Title(filter) => filter.resolve(left, py, template, context),
+            FilterType::TruncateChars(filter) => filter.resolve(left, py, template, context),
+            FilterType::TruncateCharsHtml(filter) => filter.resolve(left, py, template, context),
+            FilterType::TruncateWords(filter) => filter.resolve(left, py, template, context),
+            FilterType::TruncateWordsHtml(filter) => filter.resolve(left, py, template, context),
+            FilterType::UnorderedList(filter) => filter.resolve(left, py, template, context),
             FilterType::Upper(filter) => filter.resolve(left, py, template, context),
+            FilterType::UrlEncode(filter) => filter.resolve(left, py, template, context),
+            FilterType::Urlize(filter) => filter.resolve(left, py, template, context),
+            FilterType::UrlizeTrunc(filter) => filter.resolve(left, py, template, context),
+            FilterType::WordCount(filter) => filter.resolve(left, py, template, context),
+            FilterType::WordWrap(filter) => filter.resolve(left, py, template, context),
+            FilterType::YesNo(filter) => filter.resolve(left, py, template, context),
+        }
+    }
+}
+
+/// Wrap a newly created string that Django would not mark safe.
+fn unsafe_string<'t, 'py>(content: Cow<'t, str>, context: &Context) -> Content<'t, 'py> {
+    Content::String(match context.autoescape {
+        true => ContentString::HtmlUnsafe(content),
+        false => ContentString::String(content),
+    })
+}
+
+/// Mark `output` safe if `input` is, as Django does for `is_safe` filters.
+fn preserve_safety<'py>(
+    input: &Bound<'py, PyAny>,
+    output: Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = input.py();
+    #[allow(non_snake_case)]
+    let SafeData = SAFEDATA.import(py, "django.utils.safestring", "SafeData")?;
+    match input.is_instance(SafeData)? {
+        true => MARK_SAFE
+            .import(py, "django.utils.safestring", "mark_safe")?
+            .call1((output,)),
+        false => Ok(output),
+    }
+}
+
+/// Wrap the `output` of a filter Django registers with `is_safe=True`, which
+/// is only safe if the string `input` was.
+fn is_safe_output<'t, 'py>(
+    input: Content<'t, 'py>,
+    output: String,
+    context: &Context,
+) -> PyResult<Content<'t, 'py>> {
+    Ok(match input {
+        Content::String(_) => input.resolve_string(context)?,
+        Content::Py(ref value) if value.is_instance_of::<PyString>() => {
+            input.resolve_string(context)?
+        }
+        _ => return Ok(unsafe_string(Cow::Owned(output), context)),
+    }
+    .map_content(|_| Cow::Owned(output)))
+}
+
+/// Look up a filter of `django.contrib.humanize`, for values we don't handle
+/// natively.
+fn humanize_filter<'py>(py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyAny>> {
+    py.import(intern!(py, "django.contrib.humanize.templatetags.humanize"))?
+        .getattr(name)
+}
+
+/// Convert `content` to an integer like the `humanize` filters, which return
+/// their input unchanged if `int()` raises a `ValueError` or `TypeError`.
+fn humanize_int(content: &Content<'_, '_>, py: Python<'_>) -> PyResult<Option<BigInt>> {
+    match python_int(content, py) {
+        Ok(int) => Ok(Some(int)),
+        Err(error)
+            if error.is_instance_of::<PyValueError>(py)
+                || error.is_instance_of::<PyTypeError>(py) =>
+        {
+            Ok(None)
         }
+        Err(error) => Err(error),
     }
 }
 
@@ -94,10 +275,7 @@ impl ResolveFilter for AddFilter {
         template: TemplateString<'t>,
         context: &mut Context,
     ) -> ResolveResult<'t, 'py> {
-        let variable = match variable {
-            Some(left) => left,
-            None => return Ok(None),
-        };
+        let variable = variable.unwrap_or_else(|| "".as_content());
         let right = self
             .argument
             .resolve(py, template, context, ResolveFailures::Raise)?
@@ -107,15 +285,37 @@ impl ResolveFilter for AddFilter {
             _ => {
                 let variable = variable.to_py(py)?;
                 let right = right.to_py(py)?;
+                // Sequences are concatenated, and anything else that can't be
+                // added renders as an empty string.
                 match variable.add(right) {
                     Ok(sum) => Ok(Some(Content::Py(sum))),
-                    Err(_) => Ok(None),
+                    Err(_) => Ok(Some("".as_content())),
                 }
             }
         }
     }
 }
 
+impl ResolveFilter for ApNumberFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let value = variable.unwrap_or_else(|| "".as_content());
+        let Some(number) = humanize_int(&value, py)? else {
+            return Ok(Some(value));
+        };
+        let name = match number.to_usize() {
+            Some(number @ 1..=9) => AP_NUMBERS[number - 1],
+            _ => return Ok(Some(Content::Int(number))),
+        };
+        Ok(Some(is_safe_output(value, gettext(py, name)?, context)?))
+    }
+}
+
 impl ResolveFilter for CapfirstFilter {
     fn resolve<'t, 'py>(
         &self,
@@ -141,9 +341,9 @@ impl ResolveFilter for CapfirstFilter {
     }
 }
 
-fn resolve_bigint(bigint: BigInt, at: (usize, usize)) -> Result<usize, RenderError> {
+fn resolve_bigint(bigint: BigInt, at: (usize, usize)) -> Result<isize, RenderError> {
     match bigint.to_isize() {
-        Some(n) => Ok(n.max(0) as usize),
+        Some(n) => Ok(n),
         None => Err(RenderError::OverflowError {
             argument: bigint.to_string(),
             argument_at: at.into(),
@@ -151,6 +351,59 @@ fn resolve_bigint(bigint: BigInt, at: (usize, usize)) -> Result<usize, RenderErr
     }
 }
 
+/// Convert an integer filter argument like Python's `int()`, clamping
+/// negative values to zero.
+fn resolve_size(argument: Content<'_, '_>, at: (usize, usize)) -> Result<usize, PyRenderError> {
+    Ok(resolve_int(argument, at)?.max(0) as usize)
+}
+
+/// Convert an integer filter argument like Python's `int()`.
+fn resolve_int(argument: Content<'_, '_>, at: (usize, usize)) -> Result<isize, PyRenderError> {
+    let size = match argument {
+        Content::Int(left) => resolve_bigint(left, at)?,
+        Content::String(left) => match left.as_raw().parse::<BigInt>() {
+            Ok(n) => resolve_bigint(n, at)?,
+            Err(_) => {
+                return Err(RenderError::InvalidArgumentInteger {
+                    argument: format!("'{}'", left.as_raw()),
+                    argument_at: at.into(),
+                }
+                .into());
+            }
+        },
+        Content::Float(left) => match left.trunc().to_bigint() {
+            Some(n) => resolve_bigint(n, at)?,
+            None => {
+                return Err(RenderError::InvalidArgumentFloat {
+                    argument: left.to_string(),
+                    argument_at: at.into(),
+                }
+                .into());
+            }
+        },
+        Content::Py(left) => match left.extract::<BigInt>() {
+            Ok(left) => resolve_bigint(left, at)?,
+            Err(_) => {
+                let argument = left.to_string();
+                let argument_at = at.into();
+                let err = match left.extract::<f64>() {
+                    Ok(_) => RenderError::InvalidArgumentFloat {
+                        argument,
+                        argument_at,
+                    },
+                    Err(_) => RenderError::InvalidArgumentInteger {
+                        argument,
+                        argument_at,
+                    },
+                };
+                return Err(err.into());
+            }
+        },
+        Content::Bool(b) => b as isize,
+    };
+    Ok(size)
+}
+
 impl ResolveFilter for CenterFilter {
     fn resolve<'t, 'py>(
         &self,
@@ -171,47 +424,9 @@ impl ResolveFilter for CenterFilter {
             .expect("missing argument in context should already have raised");
 
         let size = match arg {
-            Content::Int(left) => resolve_bigint(left, self.argument.at)?,
-            Content::String(left) => match left.as_raw().parse::<BigInt>() {
-                Ok(n) => resolve_bigint(n, self.argument.at)?,
-                Err(_) => {
-                    return Err(RenderError::InvalidArgumentInteger {
-                        argument: format!("'{}'", left.as_raw()),
-                        argument_at: self.argument.at.into(),
-                    }
-                    .into());
-                }
-            },
-            Content::Float(left) => match left.trunc().to_bigint() {
-                Some(n) => resolve_bigint(n, self.argument.at)?,
-                None => {
-                    return Err(RenderError::InvalidArgumentFloat {
-                        argument: left.to_string(),
-                        argument_at: self.argument.at.into(),
-                    }
-                    .into());
-                }
-            },
-            Content::Py(left) => match left.extract::<BigInt>() {
-                Ok(left) => resolve_bigint(left, self.argument.at)?,
-                Err(_) => {
-                    let argument = left.to_string();
-                    let argument_at = self.argument.at.into();
-                    let err = match left.extract::<f64>() {
-                        Ok(_) => RenderError::InvalidArgumentFloat {
-                            argument,
-                            argument_at,
-                        },
-                        Err(_) => RenderError::InvalidArgumentInteger {
-                            argument,
-                            argument_at,
-                        },
-                    };
-                    return Err(err.into());
-                }
-            },
             Content::Bool(true) if content.is_empty() => return Ok(Some(" ".as_content())),
             Content::Bool(_) => return Ok(Some(content.into_content())),
+            arg => resolve_size(arg, self.argument.at)?,
         };
 
         if size <= content.len() {
@@ -235,6 +450,149 @@ impl ResolveFilter for CenterFilter {
     }
 }
 
+/// Shared implementation of the `date` and `time` filters.
+///
+/// `default_format` names the format setting used when no argument is given.
+/// The `time` filter only supports time-related format characters and never
+/// raises for values it cannot format.
+fn format_date<'t, 'py>(
+    variable: Option<Content<'t, 'py>>,
+    argument: &Option<Argument>,
+    default_format: &str,
+    time_only: bool,
+    py: Python<'py>,
+    template: TemplateString<'t>,
+    context: &mut Context,
+) -> ResolveResult<'t, 'py> {
+    let value = match variable {
+        None => return Ok(Some("".as_content())),
+        Some(Content::String(value)) if value.as_raw().is_empty() => {
+            return Ok(Some("".as_content()));
+        }
+        Some(value) => value.to_py(py)?,
+    };
+    let argument = match argument {
+        Some(argument) => Some(
+            argument
+                .resolve(py, template, context, ResolveFailures::Raise)?
+                .expect("missing argument in context should already have raised")
+                .resolve_string(context)?
+                .into_raw(),
+        ),
+        None => None,
+    };
+    let format_name = match argument.as_deref() {
+        None | Some("") => default_format,
+        Some(argument) => argument,
+    };
+    let get_format = GET_FORMAT.import(py, "django.utils.formats", "get_format")?;
+    let format = get_format
+        .call1((format_name,))?
+        .str()?
+        .extract::<String>()?;
+
+    let value = match value.cast::<PyDateTime>() {
+        Ok(datetime) if datetime.get_tzinfo().is_some() => {
+            let template_localtime =
+                TEMPLATE_LOCALTIME.import(py, "django.utils.timezone", "template_localtime")?;
+            template_localtime.call1((value, context.use_tz))?
+        }
+        _ => value,
+    };
+    let formatted = match DateFormat::new(value.clone()) {
+        Some(mut date_format) => {
+            // Like Django, retry with the raw argument if the named format fails.
+            let mut formatted = date_format.format(&format, time_only);
+            if let (Ok(None), Some(argument)) = (&formatted, &argument)
+                && *argument != format
+            {
+                formatted = date_format.format(argument, time_only);
+            }
+            match time_only {
+                true => formatted
+                    .ok_or_isinstance_of::<PyTypeError>(py)?
+                    .ok()
+                    .flatten(),
+                false => formatted?,
+            }
+        }
+        None => {
+            let filter = match time_only {
+                true => "time",
+                false => "date",
+            };
+            let filter = py
+                .import(intern!(py, "django.template.defaultfilters"))?
+                .getattr(filter)?;
+            Some(filter.call1((value, argument))?.str()?.extract()?)
+        }
+    };
+    let formatted = Cow::Owned(formatted.unwrap_or_default());
+    Ok(Some(Content::String(match context.autoescape {
+        true => ContentString::HtmlUnsafe(formatted),
+        false => ContentString::String(formatted),
+    })))
+}
+
+impl ResolveFilter for CutFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let content = match variable {
+            Some(content) => content.resolve_string(context)?,
+            None => ContentString::String(Cow::Borrowed("")),
+        };
+        let arg = self
+            .argument
+            .resolve(py, template, context, ResolveFailures::Raise)?
+            .expect("missing argument in context should already have raised");
+        let arg = match arg {
+            Content::String(arg) => arg.into_raw(),
+            Content::Py(arg) if arg.is_instance_of::<PyString>() => Cow::Owned(arg.extract()?),
+            // Raise the same `TypeError` as Python's `str.replace`.
+            arg => {
+                let name = arg.to_py(py)?.get_type().name()?;
+                return Err(PyTypeError::new_err(format!(
+                    "replace() argument 1 must be str, not {name}"
+                ))
+                .into());
+            }
+        };
+        let cut = content.as_raw().replace(arg.as_ref(), "");
+        // Removing `;` can break HTML entities, so the result is no longer safe.
+        Ok(Some(match content {
+            ContentString::HtmlSafe(_) if arg != ";" => {
+                Content::String(ContentString::HtmlSafe(Cow::Owned(cut)))
+            }
+            _ => unsafe_string(Cow::Owned(cut), context),
+        }))
+    }
+}
+
+impl ResolveFilter for DateFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        format_date(
+            variable,
+            &self.argument,
+            "DATE_FORMAT",
+            false,
+            py,
+            template,
+            context,
+        )
+    }
+}
+
 impl ResolveFilter for DefaultFilter {
     fn resolve<'t, 'py>(
         &self,
@@ -253,6 +611,213 @@ impl ResolveFilter for DefaultFilter {
     }
 }
 
+impl ResolveFilter for DefaultIfNoneFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let default = self
+            .argument
+            .resolve(py, template, context, ResolveFailures::Raise)?
+            .expect("missing argument in context should already have raised");
+        // Unlike `default`, only `None` is replaced, not every falsy value.
+        Ok(Some(match variable {
+            Some(Content::Py(value)) if value.is_none() => default,
+            Some(content) => content,
+            None => "".as_content(),
+        }))
+    }
+}
+
+/// How `dictsort` finds the sort key of each item, following Django's
+/// `_property_resolver`.
+enum SortKey<'py> {
+    /// A numeric argument indexes each item directly.
+    Index(Bound<'py, PyAny>),
+    /// Otherwise the argument is a dotted path, where each part is looked up
+    /// as an item and then as an attribute.
+    Path(Vec<String>),
+}
+
+impl<'py> SortKey<'py> {
+    fn new(argument: Bound<'py, PyAny>) -> PyResult<Self> {
+        let py = argument.py();
+        if PyType::new::<PyFloat>(py)
+            .call1((&argument,))
+            .ok_or_isinstance_of::<PyValueError>(py)?
+            .is_ok()
+        {
+            return Ok(Self::Index(argument));
+        }
+        let argument: String = argument.extract()?;
+        if argument.is_empty() {
+            return Err(PyIndexError::new_err("string index out of range"));
+        }
+        if argument.contains("._") || argument.starts_with('_') {
+            return Err(PyAttributeError::new_err(
+                "Access to private variables is forbidden.",
+            ));
+        }
+        Ok(Self::Path(argument.split('.').map(String::from).collect()))
+    }
+
+    fn resolve(&self, item: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let parts = match self {
+            Self::Index(index) => return item.get_item(index),
+            Self::Path(parts) => parts,
+        };
+        let py = item.py();
+        let mut value = item;
+        for part in parts {
+            value = match value.get_item(part) {
+                Ok(value) => value,
+                Err(error)
+                    if error.is_instance_of::<PyAttributeError>(py)
+                        || error.is_instance_of::<PyIndexError>(py)
+                        || error.is_instance_of::<PyKeyError>(py)
+                        || error.is_instance_of::<PyTypeError>(py)
+                        || error.is_instance_of::<PyValueError>(py) =>
+                {
+                    value.getattr(part.as_str())?
+                }
+                Err(error) => return Err(error),
+            };
+        }
+        Ok(value)
+    }
+}
+
+/// Sort `value` by the keys found by `argument`, returning `None` where
+/// Django's `dictsort` fails silently.
+fn sort_by_key<'py>(
+    value: &Bound<'py, PyAny>,
+    argument: Bound<'py, PyAny>,
+    reverse: bool,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let py = value.py();
+    let sort_key = SortKey::new(argument)?;
+    let mut items = Vec::new();
+    let mut keys = Vec::new();
+    for item in value.try_iter()? {
+        let item = item?;
+        keys.push(sort_key.resolve(item.clone())?);
+        items.push(item);
+    }
+    // Sort the indices in Python so the ordering and any `TypeError` from
+    // incomparable keys match Django exactly.
+    let keys = PyList::new(py, keys)?;
+    let kwargs = PyDict::new(py);
+    kwargs.set_item(
+        intern!(py, "key"),
+        keys.getattr(intern!(py, "__getitem__"))?,
+    )?;
+    kwargs.set_item(intern!(py, "reverse"), reverse)?;
+    let order = py
+        .import(intern!(py, "builtins"))?
+        .getattr(intern!(py, "sorted"))?
+        .call((PyRange::new(py, 0, items.len() as isize)?,), Some(&kwargs))?;
+    let mut sorted = Vec::with_capacity(items.len());
+    for index in order.try_iter()? {
+        sorted.push(items[index?.extract::<usize>()?].clone());
+    }
+    Ok(Some(PyList::new(py, sorted)?.into_any()))
+}
+
+/// Shared implementation of the `dictsort` and `dictsortreversed` filters.
+fn dictsort<'t, 'py>(
+    variable: Option<Content<'t, 'py>>,
+    argument: &Argument,
+    reverse: bool,
+    py: Python<'py>,
+    template: TemplateString<'t>,
+    context: &mut Context,
+) -> ResolveResult<'t, 'py> {
+    let value = variable.unwrap_or_else(|| "".as_content()).to_py(py)?;
+    let argument = argument
+        .resolve(py, template, context, ResolveFailures::Raise)?
+        .expect("missing argument in context should already have raised")
+        .to_py(py)?;
+    let sorted = match sort_by_key(&value, argument, reverse) {
+        Ok(sorted) => sorted,
+        Err(error)
+            if error.is_instance_of::<PyAttributeError>(py)
+                || error.is_instance_of::<PyTypeError>(py) =>
+        {
+            None
+        }
+        Err(error) => return Err(error.into()),
+    };
+    Ok(Some(match sorted {
+        Some(sorted) => Content::Py(sorted),
+        None => "".as_content(),
+    }))
+}
+
+impl ResolveFilter for DictSortFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        dictsort(variable, &self.argument, false, py, template, context)
+    }
+}
+
+impl ResolveFilter for DictSortReversedFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        dictsort(variable, &self.argument, true, py, template, context)
+    }
+}
+
+/// Convert `content` to an integer like Python's `int()`, raising the same
+/// errors.
+fn python_int(content: &Content<'_, '_>, py: Python<'_>) -> PyResult<BigInt> {
+    match content.to_bigint() {
+        Some(int) => Ok(int),
+        None => PyType::new::<PyInt>(py)
+            .call1((content.to_py(py)?,))?
+            .extract(),
+    }
+}
+
+impl ResolveFilter for DivisibleByFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let value = variable.unwrap_or_else(|| "".as_content());
+        let divisor = self
+            .argument
+            .resolve(py, template, context, ResolveFailures::Raise)?
+            .expect("missing argument in context should already have raised");
+        let value = python_int(&value, py)?;
+        let divisor = python_int(&divisor, py)?;
+        if divisor.is_zero() {
+            // Let Python raise its own ZeroDivisionError.
+            let error = Content::Int(value)
+                .to_py(py)?
+                .rem(0)
+                .expect_err("modulo by zero should raise");
+            return Err(error.into());
+        }
+        Ok(Some(Content::Bool((value % divisor).is_zero())))
+    }
+}
+
 impl ResolveFilter for EscapeFilter {
     fn resolve<'t, 'py>(
         &self,
@@ -287,6 +852,64 @@ impl ResolveFilter for EscapeFilter {
     }
 }
 
+/// The input of a `stringfilter`, as `str(value)` without any escaping.
+fn string_input<'t>(
+    variable: Option<Content<'t, '_>>,
+    context: &Context,
+) -> PyResult<Cow<'t, str>> {
+    match variable {
+        Some(Content::Py(object)) => Ok(Cow::Owned(object.str()?.extract()?)),
+        Some(content) => Ok(content.resolve_string(context)?.into_raw()),
+        None => Ok(Cow::Borrowed("")),
+    }
+}
+
+impl ResolveFilter for EscapeJsFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        _py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let content = string_input(variable, context)?;
+        Ok(Some(Content::String(ContentString::HtmlSafe(Cow::Owned(
+            escapejs(&content),
+        )))))
+    }
+}
+
+/// Apply `f` to each item of `variable`, collecting the results in a list.
+fn map_sequence<'t, 'py>(
+    variable: Option<Content<'t, 'py>>,
+    py: Python<'py>,
+    f: impl Fn(Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>>,
+) -> ResolveResult<'t, 'py> {
+    let items = match variable {
+        Some(value) => value
+            .to_py(py)?
+            .try_iter()?
+            .map(|item| f(item?))
+            .collect::<PyResult<Vec<_>>>()?,
+        None => Vec::new(),
+    };
+    Ok(Some(Content::Py(PyList::new(py, items)?.into_any())))
+}
+
+impl ResolveFilter for EscapeSeqFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        _context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let conditional_escape =
+            CONDITIONAL_ESCAPE.import(py, "django.utils.html", "conditional_escape")?;
+        map_sequence(variable, py, |item| conditional_escape.call1((item,)))
+    }
+}
+
 impl ResolveFilter for ExternalFilter {
     fn resolve<'t, 'py>(
         &self,
@@ -299,80 +922,1281 @@ impl ResolveFilter for ExternalFilter {
             Some(arg) => arg.resolve(py, template, context, ResolveFailures::Raise)?,
             None => None,
         };
+        let mut value = match variable {
+            Some(variable) => variable.to_py(py)?,
+            None => py.None().into_bound(py),
+        };
+        if self.expects_localtime {
+            let template_localtime =
+                TEMPLATE_LOCALTIME.import(py, "django.utils.timezone", "template_localtime")?;
+            value = template_localtime.call1((value, context.use_tz))?;
+        }
+        let kwargs = match self.needs_autoescape {
+            true => Some([("autoescape", context.autoescape)].into_py_dict(py)?),
+            false => None,
+        };
         let filter = self.filter.bind(py);
-        let value = match arg {
-            Some(arg) => filter.call1((variable, arg))?,
-            None => filter.call1((variable,))?,
+        let output = match arg {
+            Some(arg) => filter.call((&value, arg), kwargs.as_ref())?,
+            None => filter.call((&value,), kwargs.as_ref())?,
+        };
+        let output = match self.is_safe {
+            true => preserve_safety(&value, output)?,
+            false => output,
         };
-        Ok(Some(Content::Py(value)))
+        Ok(Some(Content::Py(output)))
     }
 }
 
-impl ResolveFilter for LowerFilter {
+/// Shared implementation of the `first` and `last` filters.
+fn index_item<'t, 'py>(
+    variable: Option<Content<'t, 'py>>,
+    index: isize,
+    py: Python<'py>,
+    context: &Context,
+) -> ResolveResult<'t, 'py> {
+    let item = match variable {
+        None => None,
+        Some(Content::String(content)) => {
+            let mut chars = content.as_raw().chars();
+            let item = match index {
+                0 => chars.next(),
+                _ => chars.next_back(),
+            };
+            return Ok(Some(match item {
+                Some(item) => unsafe_string(Cow::Owned(item.to_string()), context),
+                None => "".as_content(),
+            }));
+        }
+        Some(content) => content
+            .to_py(py)?
+            .get_item(index)
+            .ok_or_isinstance_of::<PyIndexError>(py)?
+            .ok(),
+    };
+    Ok(Some(match item {
+        Some(item) => Content::Py(item),
+        None => "".as_content(),
+    }))
+}
+
+/// The translatable messages for kilobytes to petabytes.
+const FILE_SIZE_UNITS: [&str; 5] = ["%s KB", "%s MB", "%s GB", "%s TB", "%s PB"];
+
+/// Format an absolute number of bytes like `filesizeformat`, with plain
+/// spaces.
+fn file_size(bytes: &BigUint, py: Python<'_>, context: &mut Context) -> PyResult<String> {
+    if let Some(bytes) = bytes.to_u64().filter(|bytes| *bytes < 1 << 10) {
+        let message = ngettext(py, "%(size)d byte", "%(size)d bytes", bytes)?;
+        return Ok(message.replace("%(size)d", &bytes.to_string()));
+    }
+    // Each unit is 1024 times larger than the last, up to petabytes.
+    let power = ((bytes.bits() - 1) / 10).min(FILE_SIZE_UNITS.len() as u64);
+    let size = bytes.to_f64().unwrap_or(f64::INFINITY) / 1024f64.powi(power as i32);
+    if !size.is_finite() || size >= 1e200 {
+        // Django formats huge sizes in scientific notation, or overflows.
+        return py
+            .import(intern!(py, "django.template.defaultfilters"))?
+            .getattr(intern!(py, "filesizeformat"))?
+            .call1((bytes,))?
+            .extract();
+    }
+    // Round like Python's `round(size, 1)`, which returns a float.
+    let Some(ParsedDecimal::Finite(exact)) = Decimal::parse(&format!("{size:.52}")) else {
+        unreachable!("finite floats are valid decimals");
+    };
+    let rounded: f64 = exact
+        .round_half_even(1)
+        .parse()
+        .expect("rounded decimals are valid floats");
+    let number = context
+        .number_format(py, true, false)?
+        .format(&rounded.to_string(), Some(1));
+    let message = gettext(py, FILE_SIZE_UNITS[power as usize - 1])?;
+    Ok(message.replace("%s", &number))
+}
+
+impl ResolveFilter for FileSizeFormatFilter {
     fn resolve<'t, 'py>(
         &self,
         variable: Option<Content<'t, 'py>>,
-        _py: Python<'py>,
+        py: Python<'py>,
         _template: TemplateString<'t>,
         context: &mut Context,
     ) -> ResolveResult<'t, 'py> {
-        let content = match variable {
-            Some(content) => content
-                .resolve_string(context)?
-                .map_content(|content| Cow::Owned(content.to_lowercase())),
-            None => "".as_content(),
+        let value = variable.unwrap_or_else(|| "".as_content());
+        let bytes = match python_int(&value, py) {
+            Ok(bytes) => bytes,
+            Err(error)
+                if error.is_instance_of::<PyValueError>(py)
+                    || error.is_instance_of::<PyTypeError>(py) =>
+            {
+                BigInt::zero()
+            }
+            Err(error) => return Err(error.into()),
         };
-        Ok(Some(content))
+        let size = file_size(bytes.magnitude(), py, context)?;
+        let size = match bytes.is_negative() {
+            true => format!("-{size}"),
+            false => size,
+        };
+        // Avoid wrapping between the number and the unit.
+        Ok(Some(unsafe_string(
+            Cow::Owned(size.replace(' ', "\u{a0}")),
+            context,
+        )))
     }
 }
 
-impl ResolveFilter for SafeFilter {
+impl ResolveFilter for FirstFilter {
     fn resolve<'t, 'py>(
         &self,
         variable: Option<Content<'t, 'py>>,
-        _py: Python<'py>,
+        py: Python<'py>,
         _template: TemplateString<'t>,
-        _context: &mut Context,
+        context: &mut Context,
     ) -> ResolveResult<'t, 'py> {
-        Ok(Some(Content::String(ContentString::HtmlSafe(
-            match variable {
-                Some(content) => match content {
-                    Content::String(content) => content.into_raw(),
-                    Content::Int(n) => Cow::Owned(n.to_string()),
-                    Content::Float(n) => Cow::Owned(n.to_string()),
-                    Content::Py(object) => {
-                        let content = object.str()?.extract::<String>()?;
-                        Cow::Owned(content)
-                    }
-                    Content::Bool(true) => Cow::Borrowed("True"),
-                    Content::Bool(false) => Cow::Borrowed("False"),
-                },
-                None => Cow::Borrowed(""),
-            },
-        ))))
+        index_item(variable, 0, py, context)
     }
 }
 
-fn slugify(content: Cow<str>) -> Cow<str> {
-    let content = content
-        .nfkd()
-        // first decomposing characters, then only keeping
-        // the ascii ones, filtering out diacritics for example.
-        .filter(|c| c.is_ascii())
-        .collect::<String>()
-        .to_lowercase();
-    let content = NON_WORD_RE.replace_all(&content, "");
-    let content = content.trim();
-    let content = WHITESPACE_RE.replace_all(content, "-");
-    Cow::Owned(content.to_string())
+/// Parse an integer like Python's `int(str)`, or `None` if it is invalid or
+/// does not fit in an `i64`.
+fn parse_python_int(value: &str) -> Option<i64> {
+    parse_python_bigint(value)?.to_i64()
 }
 
-impl ResolveFilter for SlugifyFilter {
-    fn resolve<'t, 'py>(
+/// Parse an integer like Python's `int(str)`.
+fn parse_python_bigint(value: &str) -> Option<BigInt> {
+    let value = value.trim();
+    let digits = value.trim_start_matches(['+', '-']);
+    if digits.is_empty()
+        || value.len() - digits.len() > 1
+        || digits.starts_with('_')
+        || digits.ends_with('_')
+        || digits.contains("__")
+    {
+        return None;
+    }
+    value.replace('_', "").parse().ok()
+}
+
+impl FloatFormatFilter {
+    /// Resolve the precision and the `g` and `u` suffixes of the argument.
+    ///
+    /// Returns the precision (`None` if it is not an integer), whether to
+    /// force grouping and whether to localize.
+    fn options<'t, 'py>(
         &self,
-        variable: Option<Content<'t, 'py>>,
         py: Python<'py>,
-        _template: TemplateString<'t>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> Result<(Option<i64>, bool, bool), PyRenderError> {
+        let argument = match &self.argument {
+            Some(argument) => argument
+                .resolve(py, template, context, ResolveFailures::Raise)?
+                .expect("missing argument in context should already have raised"),
+            None => return Ok((Some(-1), false, true)),
+        };
+        let argument = match argument {
+            Content::String(argument) => argument.into_raw(),
+            Content::Py(argument) if argument.is_instance_of::<PyString>() => {
+                Cow::Owned(argument.extract::<String>()?)
+            }
+            argument => return Ok((argument.to_bigint().and_then(|p| p.to_i64()), false, true)),
+        };
+        // Django reads the last character of the argument for the suffixes.
+        if argument.is_empty() {
+            return Err(PyIndexError::new_err("string index out of range").into());
+        }
+        let (precision, force_grouping, use_l10n) = if let Some(precision) = argument
+            .strip_suffix("gu")
+            .or_else(|| argument.strip_suffix("ug"))
+        {
+            (precision, true, false)
+        } else if let Some(precision) = argument.strip_suffix('g') {
+            (precision, true, true)
+        } else if let Some(precision) = argument.strip_suffix('u') {
+            (precision, false, false)
+        } else {
+            (argument.as_ref(), false, true)
+        };
+        let precision = match precision {
+            "" => Some(-1),
+            precision => parse_python_int(precision),
+        };
+        Ok((precision, force_grouping, use_l10n))
+    }
+}
+
+impl ResolveFilter for FloatFormatFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let (precision, force_grouping, use_l10n) = self.options(py, template, context)?;
+        let (input, value) = match variable {
+            Some(Content::String(value)) => {
+                let input = value.into_raw();
+                let value = PyString::new(py, &input).into_any();
+                (input, value)
+            }
+            Some(value) => {
+                let value = value.to_py(py)?;
+                (Cow::Owned(value.str()?.extract::<String>()?), value)
+            }
+            None => return Ok(Some("".as_content())),
+        };
+        let decimal = match Decimal::parse(&input) {
+            Some(decimal) => decimal,
+            None => {
+                let float = PyType::new::<PyFloat>(py);
+                let float = match float.call1((value,)) {
+                    Ok(float) => float.str()?.extract::<String>()?,
+                    Err(error)
+                        if error.is_instance_of::<PyValueError>(py)
+                            || error.is_instance_of::<PyTypeError>(py) =>
+                    {
+                        return Ok(Some("".as_content()));
+                    }
+                    Err(error) => return Err(error.into()),
+                };
+                Decimal::parse(&float).expect("Python floats are valid decimals")
+            }
+        };
+        let as_is = || Ok(Some(input.clone().into_content()));
+        let Some(precision) = precision else {
+            return as_is();
+        };
+        let decimal = match decimal {
+            ParsedDecimal::Finite(decimal) => decimal,
+            ParsedDecimal::NonFinite => return as_is(),
+        };
+        // Like Django, numbers with over 200 digits including the exponent are
+        // returned as is to avoid high memory usage.
+        if decimal.digit_count() as u64 + decimal.exponent.unsigned_abs() > 200 {
+            return as_is();
+        }
+        let Ok(places) = u32::try_from(precision.unsigned_abs()) else {
+            return as_is();
+        };
+
+        let number_format = context.number_format(py, use_l10n, force_grouping)?;
+        let formatted = match !decimal.has_fraction() && precision <= 0 {
+            true => number_format.format(&decimal.truncate().to_string(), Some(0)),
+            false => number_format.format(&decimal.round_half_up(places), Some(places as usize)),
+        };
+        Ok(Some(Content::String(ContentString::HtmlSafe(Cow::Owned(
+            formatted,
+        )))))
+    }
+}
+
+impl ResolveFilter for ForceEscapeFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        _py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        // Unlike `escape`, this escapes content already marked safe.
+        let content = string_input(variable, context)?;
+        Ok(Some(Content::String(ContentString::HtmlSafe(Cow::Owned(
+            encode_quoted_attribute(&content).into_owned(),
+        )))))
+    }
+}
+
+impl ResolveFilter for GetDigitFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let value = variable.unwrap_or_else(|| "".as_content());
+        let argument = self
+            .argument
+            .resolve(py, template, context, ResolveFailures::Raise)?
+            .expect("missing argument in context should already have raised");
+        // Invalid input is returned unchanged.
+        let Ok(position) = python_int(&argument, py).ok_or_isinstance_of::<PyValueError>(py)?
+        else {
+            return Ok(Some(value));
+        };
+        let Ok(number) = python_int(&value, py).ok_or_isinstance_of::<PyValueError>(py)? else {
+            return Ok(Some(value));
+        };
+        if position < BigInt::from(1) {
+            return Ok(Some(Content::Int(number)));
+        }
+        let digits = number.to_string();
+        let digit = match position.to_usize() {
+            Some(position) if position <= digits.len() => {
+                digits.as_bytes()[digits.len() - position]
+            }
+            _ => return Ok(Some(Content::Int(BigInt::zero()))),
+        };
+        match digit {
+            // Django tries to parse the sign of negative numbers as a digit.
+            b'-' => {
+                Err(PyValueError::new_err("invalid literal for int() with base 10: '-'").into())
+            }
+            digit => Ok(Some(Content::Int(BigInt::from(digit - b'0')))),
+        }
+    }
+}
+
+impl IntCommaFilter {
+    fn python<'t, 'py>(
+        value: Content<'t, 'py>,
+        use_l10n: bool,
+        py: Python<'py>,
+    ) -> ResolveResult<'t, 'py> {
+        let value = value.to_py(py)?;
+        let formatted = humanize_filter(py, "intcomma")?.call1((&value, use_l10n))?;
+        Ok(Some(Content::Py(preserve_safety(&value, formatted)?)))
+    }
+}
+
+impl ResolveFilter for IntCommaFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let use_l10n = match &self.argument {
+            Some(argument) => argument
+                .resolve(py, template, context, ResolveFailures::Raise)?
+                .expect("missing argument in context should already have raised")
+                .to_py(py)?
+                .is_truthy()?,
+            None => true,
+        };
+        let value = variable.unwrap_or_else(|| "".as_content());
+        // Django formats floats as they are and other numbers as integers.
+        let float = match &value {
+            Content::Float(float) => Some(PyFloat::new(py, *float).into_any()),
+            Content::Py(object) if object.is_exact_instance_of::<PyFloat>() => Some(object.clone()),
+            Content::Py(object)
+                if !(object.is_instance_of::<PyString>()
+                    || object.is_exact_instance_of::<PyInt>()
+                    || object.is_exact_instance_of::<PyBool>()) =>
+            {
+                return Self::python(value, use_l10n, py);
+            }
+            _ => None,
+        };
+        let float = match float {
+            Some(float) => match float_repr(&float)? {
+                Some(float) => Some(float),
+                None => return Self::python(value, use_l10n, py),
+            },
+            None => None,
+        };
+        if use_l10n {
+            let number = match &float {
+                Some(float) => Some(float.clone()),
+                None => humanize_int(&value, py)?.map(|number| number.to_string()),
+            };
+            if let Some(number) = number {
+                let formatted = context.number_format(py, true, true)?.format(&number, None);
+                return Ok(Some(is_safe_output(value, formatted, context)?));
+            }
+        }
+        let text = match (&value, float) {
+            (_, Some(float)) => float,
+            (Content::String(text), None) => text.as_raw().to_string(),
+            (Content::Int(number), None) => number.to_string(),
+            (content, None) => content.to_py(py)?.str()?.extract()?,
+        };
+        match intcomma(&text) {
+            Some(formatted) => Ok(Some(is_safe_output(value, formatted, context)?)),
+            None => Self::python(value, use_l10n, py),
+        }
+    }
+}
+
+impl ResolveFilter for IntWordFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let value = variable.unwrap_or_else(|| "".as_content());
+        let Some(number) = humanize_int(&value, py)? else {
+            return Ok(Some(value));
+        };
+        if number.magnitude() < &BigUint::from(1_000_000u32) {
+            return Ok(Some(Content::Int(number)));
+        }
+        for (exponent, singular, plural) in INTWORD_CONVERTERS {
+            let large_number = BigInt::from(10).pow(exponent);
+            if number.magnitude() >= &(large_number.magnitude() * 1000u32) {
+                continue;
+            }
+            let new_value = match number.to_f64() {
+                // Dividing exact floats rounds correctly, like Python's `/`.
+                Some(float) if float.abs() <= 2f64.powi(53) => float / 10f64.powi(exponent as i32),
+                _ => number.into_pyobject(py)?.div(large_number)?.extract()?,
+            };
+            let count = round_away_from_one(new_value).unsigned_abs();
+            let message = ngettext(py, singular, plural, count)?;
+            // Format the value like `floatformat` with one decimal place.
+            let repr = float_repr(&PyFloat::new(py, new_value))?
+                .expect("intword values are below a thousand");
+            let decimal = match Decimal::parse(&repr) {
+                Some(ParsedDecimal::Finite(decimal)) => decimal,
+                _ => unreachable!("Python floats are valid decimals"),
+            };
+            let new_value = context
+                .number_format(py, true, false)?
+                .format(&decimal.round_half_up(1), Some(1));
+            let formatted = interpolate(py, &message, "value", new_value)?;
+            return Ok(Some(unsafe_string(Cow::Owned(formatted), context)));
+        }
+        Ok(Some(Content::Int(number)))
+    }
+}
+
+impl ResolveFilter for IriEncodeFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        _py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let content = match variable {
+            Some(content) => content.resolve_string(context)?,
+            None => return Ok(Some("".as_content())),
+        };
+        Ok(Some(content.map_content(|content| {
+            match iri_to_uri(&content) {
+                Cow::Borrowed(_) => content,
+                Cow::Owned(uri) => Cow::Owned(uri),
+            }
+        })))
+    }
+}
+
+impl ResolveFilter for JoinFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let value = match variable {
+            Some(value) => value,
+            None => return Ok(Some("".as_content())),
+        };
+        let separator = self
+            .argument
+            .resolve(py, template, context, ResolveFailures::Raise)?
+            .expect("missing argument in context should already have raised");
+        let joined = match context.autoescape {
+            false => {
+                let joined = separator
+                    .to_py(py)?
+                    .call_method1(intern!(py, "join"), (value.to_py(py)?,))
+                    .ok_or_isinstance_of::<PyTypeError>(py)?;
+                match joined {
+                    Ok(joined) => joined.extract::<String>()?,
+                    Err(_) => return Ok(Some(value)),
+                }
+            }
+            true => {
+                let separator = separator.resolve_string(context)?.content();
+                let items: Vec<String> = match &value {
+                    // Characters of a safe string are not themselves safe.
+                    Content::String(content) => content
+                        .as_raw()
+                        .chars()
+                        .map(|c| encode_quoted_attribute(&c.to_string()).into_owned())
+                        .collect(),
+                    Content::Py(object) => {
+                        let items =
+                            match object.try_iter().ok_or_isinstance_of::<PyTypeError>(py)? {
+                                Ok(items) => items,
+                                Err(_) => return Ok(Some(value)),
+                            };
+                        items
+                            .map(|item| {
+                                Ok(Content::Py(item?)
+                                    .resolve_string(context)?
+                                    .content()
+                                    .into_owned())
+                            })
+                            .collect::<PyResult<_>>()?
+                    }
+                    _ => return Ok(Some(value)),
+                };
+                items.join(&separator)
+            }
+        };
+        Ok(Some(Content::String(ContentString::HtmlSafe(Cow::Owned(
+            joined,
+        )))))
+    }
+}
+
+impl ResolveFilter for JsonScriptFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let value = match variable {
+            Some(value) => value.to_py(py)?,
+            None => PyString::new(py, "").into_any(),
+        };
+        let element_id = match &self.argument {
+            Some(argument) => argument
+                .resolve(py, template, context, ResolveFailures::Raise)?
+                .expect("missing argument in context should already have raised")
+                .to_py(py)?,
+            None => py.None().into_bound(py),
+        };
+
+        let dumps = JSON_DUMPS.import(py, "json", "dumps")?;
+        let encoder =
+            DJANGO_JSON_ENCODER.import(py, "django.core.serializers.json", "DjangoJSONEncoder")?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item(intern!(py, "cls"), encoder)?;
+        let json: String = dumps.call((value,), Some(&kwargs))?.extract()?;
+        let json = json
+            .replace('>', "\\u003E")
+            .replace('<', "\\u003C")
+            .replace('&', "\\u0026");
+
+        let script = match element_id.is_truthy()? {
+            true => {
+                let conditional_escape =
+                    CONDITIONAL_ESCAPE.import(py, "django.utils.html", "conditional_escape")?;
+                let element_id: String = conditional_escape.call1((element_id,))?.extract()?;
+                format!(r#"<script id="{element_id}" type="application/json">{json}</script>"#)
+            }
+            false => format!(r#"<script type="application/json">{json}</script>"#),
+        };
+        Ok(Some(Content::String(ContentString::HtmlSafe(Cow::Owned(
+            script,
+        )))))
+    }
+}
+
+impl ResolveFilter for LastFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        index_item(variable, -1, py, context)
+    }
+}
+
+impl ResolveFilter for LengthFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        _context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let length = match variable {
+            Some(Content::String(content)) => content.as_raw().chars().count(),
+            Some(Content::Py(object)) => match object.len() {
+                Ok(length) => length,
+                Err(error)
+                    if error.is_instance_of::<PyTypeError>(py)
+                        || error.is_instance_of::<PyValueError>(py) =>
+                {
+                    0
+                }
+                Err(error) => return Err(error.into()),
+            },
+            _ => 0,
+        };
+        Ok(Some(Content::Int(length.into())))
+    }
+}
+
+/// Resolve the input of a filter that Django marks `needs_autoescape`.
+///
+/// The content is escaped unless it is already safe or autoescaping is off.
+fn autoescaped_input<'t>(
+    variable: Option<Content<'t, '_>>,
+    context: &Context,
+) -> PyResult<Cow<'t, str>> {
+    match variable {
+        Some(content) => Ok(content.resolve_string(context)?.content()),
+        None => Ok(Cow::Borrowed("")),
+    }
+}
+
+fn normalize_newlines(content: &str) -> Cow<'_, str> {
+    NEWLINES_RE.replace_all(content, "\n")
+}
+
+impl ResolveFilter for LineBreaksFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        _py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let content = autoescaped_input(variable, context)?;
+        let content = normalize_newlines(&content);
+        let paragraphs: Vec<String> = PARAGRAPHS_RE
+            .split(&content)
+            .map(|paragraph| format!("<p>{}</p>", paragraph.replace('\n', "<br>")))
+            .collect();
+        Ok(Some(Content::String(ContentString::HtmlSafe(Cow::Owned(
+            paragraphs.join("\n\n"),
+        )))))
+    }
+}
+
+impl ResolveFilter for LineBreaksBrFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        _py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let content = autoescaped_input(variable, context)?;
+        let content = normalize_newlines(&content).replace('\n', "<br>");
+        Ok(Some(Content::String(ContentString::HtmlSafe(Cow::Owned(
+            content,
+        )))))
+    }
+}
+
+impl ResolveFilter for LineNumbersFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        _py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let content = autoescaped_input(variable, context)?;
+        let lines: Vec<&str> = content.split('\n').collect();
+        let width = lines.len().to_string().len();
+        let numbered: Vec<String> = lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| format!("{:0width$}. {line}", index + 1))
+            .collect();
+        Ok(Some(Content::String(ContentString::HtmlSafe(Cow::Owned(
+            numbered.join("\n"),
+        )))))
+    }
+}
+
+const MAX_NATIVE_PADDING: usize = 1 << 20;
+
+/// Pad `variable` with spaces to `argument` characters, on the right like
+/// `str.ljust` or on the left like `str.rjust`.
+fn justify<'t, 'py>(
+    variable: Option<Content<'t, 'py>>,
+    argument: &Argument,
+    pad_left: bool,
+    py: Python<'py>,
+    template: TemplateString<'t>,
+    context: &mut Context,
+) -> ResolveResult<'t, 'py> {
+    let content = match variable {
+        Some(content) => content.resolve_string(context)?,
+        None => ContentString::String(Cow::Borrowed("")),
+    };
+    let size = argument
+        .resolve(py, template, context, ResolveFailures::Raise)?
+        .expect("missing argument in context should already have raised");
+    let size = resolve_size(size, argument.at)?;
+    let len = content.as_raw().chars().count();
+    if size <= len {
+        return Ok(Some(Content::String(content)));
+    }
+    // Leave very wide padding to Python, which raises `MemoryError` where
+    // allocating it here would abort.
+    if size - len > MAX_NATIVE_PADDING {
+        let method = match pad_left {
+            true => intern!(py, "rjust"),
+            false => intern!(py, "ljust"),
+        };
+        let padded: String = PyString::new(py, content.as_raw())
+            .call_method1(method, (size,))?
+            .extract()?;
+        return Ok(Some(content.map_content(|_| Cow::Owned(padded))));
+    }
+    let padding = " ".repeat(size - len);
+    Ok(Some(content.map_content(|content| {
+        Cow::Owned(match pad_left {
+            true => padding + &content,
+            false => content.into_owned() + &padding,
+        })
+    })))
+}
+
+impl ResolveFilter for LjustFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        justify(variable, &self.argument, false, py, template, context)
+    }
+}
+
+impl ResolveFilter for LowerFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        _py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let content = match variable {
+            Some(content) => content
+                .resolve_string(context)?
+                .map_content(|content| Cow::Owned(content.to_lowercase())),
+            None => "".as_content(),
+        };
+        Ok(Some(content))
+    }
+}
+
+impl ResolveFilter for MakeListFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let content = match variable {
+            Some(content) => content.resolve_string(context)?.into_raw(),
+            None => Cow::Borrowed(""),
+        };
+        let chars: Vec<String> = content.chars().map(|c| c.to_string()).collect();
+        Ok(Some(Content::Py(PyList::new(py, chars)?.into_any())))
+    }
+}
+
+impl ResolveFilter for NaturalDayFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let value = match variable {
+            Some(Content::Py(value)) => value,
+            // Django returns values without a `year` unchanged.
+            Some(content) => return Ok(Some(content)),
+            None => return Ok(Some("".as_content())),
+        };
+        let value = match value.cast::<PyDateTime>() {
+            Ok(datetime) if datetime.get_tzinfo().is_some() => {
+                let template_localtime =
+                    TEMPLATE_LOCALTIME.import(py, "django.utils.timezone", "template_localtime")?;
+                template_localtime.call1((value, context.use_tz))?
+            }
+            _ => value,
+        };
+        let Ok(date) = value.cast::<PyDate>() else {
+            let argument = match &self.argument {
+                Some(argument) => Some(
+                    argument
+                        .resolve(py, template, context, ResolveFailures::Raise)?
+                        .expect("missing argument in context should already have raised")
+                        .to_py(py)?,
+                ),
+                None => None,
+            };
+            let natural_day = humanize_filter(py, "naturalday")?.call1((&value, argument))?;
+            return Ok(Some(Content::Py(natural_day)));
+        };
+        let tzinfo = value
+            .cast::<PyDateTime>()
+            .ok()
+            .and_then(|datetime| datetime.get_tzinfo());
+        let today = py
+            .import(intern!(py, "datetime"))?
+            .getattr(intern!(py, "datetime"))?
+            .call_method1(intern!(py, "now"), (tzinfo,))?;
+        let today = today
+            .cast::<PyDate>()
+            .expect("datetime.now always returns a datetime");
+        let (year, month, day) = (date.get_year(), date.get_month(), date.get_day());
+        let days = Date { year, month, day }.ordinal()
+            - Date {
+                year: today.get_year(),
+                month: today.get_month(),
+                day: today.get_day(),
+            }
+            .ordinal();
+        let message = match days {
+            0 => "today",
+            1 => "tomorrow",
+            -1 => "yesterday",
+            _ => {
+                let date = PyDate::new(py, year, month, day)?.into_any();
+                return format_date(
+                    Some(Content::Py(date)),
+                    &self.argument,
+                    "DATE_FORMAT",
+                    false,
+                    py,
+                    template,
+                    context,
+                );
+            }
+        };
+        Ok(Some(unsafe_string(
+            Cow::Owned(gettext(py, message)?),
+            context,
+        )))
+    }
+}
+
+impl ResolveFilter for NaturalTimeFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let value = match variable {
+            Some(Content::Py(value)) => value,
+            // Django returns anything but dates unchanged.
+            Some(content) => return Ok(Some(content)),
+            None => return Ok(Some("".as_content())),
+        };
+        if let Ok(datetime) = value.cast::<PyDateTime>()
+            && let Some(natural_time) = naturaltime(datetime)?
+        {
+            return Ok(Some(unsafe_string(Cow::Owned(natural_time), context)));
+        }
+        let natural_time = humanize_filter(py, "naturaltime")?.call1((value,))?;
+        Ok(Some(Content::Py(natural_time)))
+    }
+}
+
+impl ResolveFilter for OrdinalFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let value = variable.unwrap_or_else(|| "".as_content());
+        let Some(number) = humanize_int(&value, py)? else {
+            return Ok(Some(value));
+        };
+        if number.is_negative() {
+            return Ok(Some(is_safe_output(value, number.to_string(), context)?));
+        }
+        let (translation_context, format) = ordinal_format(&number);
+        let ordinal = PyString::new(py, &pgettext(py, translation_context, format)?)
+            .call_method1(intern!(py, "format"), (number,))?
+            .extract()?;
+        // Django marks ordinals safe so translations can use HTML like `<sup>`.
+        Ok(Some(Content::String(ContentString::HtmlSafe(Cow::Owned(
+            ordinal,
+        )))))
+    }
+}
+
+/// Convert the letters in a phone number to their keypad digits, like
+/// `django.utils.text.phone2numeric`.
+fn phone2numeric(phone: &str) -> String {
+    phone
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'a'..='c' => '2',
+            'd'..='f' => '3',
+            'g'..='i' => '4',
+            'j'..='l' => '5',
+            'm'..='o' => '6',
+            'p'..='s' => '7',
+            't'..='v' => '8',
+            'w'..='z' => '9',
+            c => c,
+        })
+        .collect()
+}
+
+impl ResolveFilter for Phone2NumericFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let content = match variable {
+            Some(content @ Content::String(_)) => content.resolve_string(context)?,
+            Some(Content::Py(value)) if value.is_instance_of::<PyString>() => {
+                Content::Py(value).resolve_string(context)?
+            }
+            // Django doesn't convert other values to strings, so let it raise.
+            Some(content) => {
+                let value = content.to_py(py)?;
+                let numeric = py
+                    .import(intern!(py, "django.utils.text"))?
+                    .getattr(intern!(py, "phone2numeric"))?
+                    .call1((&value,))?;
+                return Ok(Some(Content::Py(preserve_safety(&value, numeric)?)));
+            }
+            None => return Ok(Some("".as_content())),
+        };
+        Ok(Some(content.map_content(|content| {
+            Cow::Owned(phone2numeric(&content))
+        })))
+    }
+}
+
+/// Whether `pluralize` should use the plural suffix for `value`, or `None`
+/// if `value` is neither a number nor sized.
+fn is_plural(value: Option<Content<'_, '_>>, py: Python<'_>) -> PyResult<Option<bool>> {
+    let float = PyType::new::<PyFloat>(py);
+    Ok(Some(match value {
+        Some(Content::Int(n)) => n != BigInt::from(1),
+        Some(Content::Float(f)) => f != 1.0,
+        Some(Content::Bool(b)) => !b,
+        Some(Content::String(content)) => {
+            match float
+                .call1((content.as_raw(),))
+                .ok_or_isinstance_of::<PyValueError>(py)?
+            {
+                Ok(f) => f.extract::<f64>()? != 1.0,
+                Err(_) => return Ok(None),
+            }
+        }
+        Some(Content::Py(object)) => match float.call1((&object,)) {
+            Ok(f) => f.extract::<f64>()? != 1.0,
+            Err(error) if error.is_instance_of::<PyValueError>(py) => return Ok(None),
+            // Not a number, but maybe a sequence.
+            Err(error) if error.is_instance_of::<PyTypeError>(py) => {
+                match object.len().ok_or_isinstance_of::<PyTypeError>(py)? {
+                    Ok(len) => len != 1,
+                    Err(_) => return Ok(None),
+                }
+            }
+            Err(error) => return Err(error),
+        },
+        None => return Ok(None),
+    }))
+}
+
+impl ResolveFilter for PluralizeFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let argument = match &self.argument {
+            Some(argument) => Some(
+                argument
+                    .resolve(py, template, context, ResolveFailures::Raise)?
+                    .expect("missing argument in context should already have raised"),
+            ),
+            None => None,
+        };
+        let suffixes: Vec<String> = match argument {
+            None => vec![String::new(), "s".to_string()],
+            Some(Content::String(argument)) => match argument.as_raw().contains(',') {
+                true => argument.as_raw().split(',').map(String::from).collect(),
+                false => vec![String::new(), argument.into_raw().into_owned()],
+            },
+            // Let Python raise the same errors as Django for other arguments.
+            Some(argument) => {
+                let argument = argument.to_py(py)?;
+                let argument = match argument.contains(",")? {
+                    true => argument,
+                    false => PyString::new(py, ",").add(argument)?,
+                };
+                argument
+                    .call_method1(intern!(py, "split"), (",",))?
+                    .extract()?
+            }
+        };
+        let [singular, plural] = match <[String; 2]>::try_from(suffixes) {
+            Ok(suffixes) => suffixes,
+            Err(_) => return Ok(Some("".as_content())),
+        };
+        Ok(Some(match is_plural(variable, py)? {
+            Some(true) => unsafe_string(Cow::Owned(plural), context),
+            Some(false) => unsafe_string(Cow::Owned(singular), context),
+            None => "".as_content(),
+        }))
+    }
+}
+
+impl ResolveFilter for PprintFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        _context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let value = match variable {
+            Some(content) => content.to_py(py)?,
+            None => PyString::new(py, "").into_any(),
+        };
+        let formatted = match py
+            .import(intern!(py, "pprint"))?
+            .getattr(intern!(py, "pformat"))?
+            .call1((&value,))
+        {
+            Ok(formatted) => formatted,
+            Err(error) if error.is_instance_of::<PyException>(py) => {
+                let message = format!(
+                    "Error in formatting: {}: {}",
+                    error.get_type(py).name()?,
+                    error.value(py).str()?,
+                );
+                PyString::new(py, &message).into_any()
+            }
+            Err(error) => return Err(error.into()),
+        };
+        Ok(Some(Content::Py(preserve_safety(&value, formatted)?)))
+    }
+}
+
+impl ResolveFilter for RandomFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        _context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let value = match variable {
+            Some(value) => value.to_py(py)?,
+            None => return Ok(Some("".as_content())),
+        };
+        // Use Python's `random` module so `random.seed` is respected.
+        let choice = RANDOM_CHOICE.import(py, "random", "choice")?;
+        Ok(Some(
+            match choice
+                .call1((&value,))
+                .ok_or_isinstance_of::<PyIndexError>(py)?
+            {
+                Ok(item) => Content::Py(preserve_safety(&value, item)?),
+                Err(_) => "".as_content(),
+            },
+        ))
+    }
+}
+
+impl ResolveFilter for RjustFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        justify(variable, &self.argument, true, py, template, context)
+    }
+}
+
+impl ResolveFilter for SafeFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        _py: Python<'py>,
+        _template: TemplateString<'t>,
+        _context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        Ok(Some(Content::String(ContentString::HtmlSafe(
+            match variable {
+                Some(content) => match content {
+                    Content::String(content) => content.into_raw(),
+                    Content::Int(n) => Cow::Owned(n.to_string()),
+                    Content::Float(n) => Cow::Owned(n.to_string()),
+                    Content::Py(object) => {
+                        let content = object.str()?.extract::<String>()?;
+                        Cow::Owned(content)
+                    }
+                    Content::Bool(true) => Cow::Borrowed("True"),
+                    Content::Bool(false) => Cow::Borrowed("False"),
+                },
+                None => Cow::Borrowed(""),
+            },
+        ))))
+    }
+}
+
+/// The indices selected by the Python slice `[start:stop:step]` on a
+/// sequence of length `len`.
+fn slice_indices(
+    len: usize,
+    start: Option<i64>,
+    stop: Option<i64>,
+    step: i64,
+) -> impl Iterator<Item = usize> {
+    let len = len as i64;
+    let (lower, upper) = match step > 0 {
+        true => (0, len),
+        false => (-1, len - 1),
+    };
+    let adjust = |index: Option<i64>, default: i64| match index {
+        None => default,
+        Some(index) if index < 0 => (index + len).max(lower),
+        Some(index) => index.min(upper),
+    };
+    let (mut index, stop) = match step > 0 {
+        true => (adjust(start, lower), adjust(stop, upper)),
+        false => (adjust(start, upper), adjust(stop, lower)),
+    };
+    std::iter::from_fn(move || {
+        let in_range = match step > 0 {
+            true => index < stop,
+            false => index > stop,
+        };
+        if !in_range {
+            return None;
+        }
+        let current = index;
+        // Stop once the next index can't be represented: it is out of range.
+        index = index.checked_add(step).unwrap_or(stop);
+        Some(current as usize)
+    })
+}
+
+impl ResolveFilter for SafeSeqFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        _context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let mark_safe = MARK_SAFE.import(py, "django.utils.safestring", "mark_safe")?;
+        map_sequence(variable, py, |item| mark_safe.call1((item,)))
+    }
+}
+
+impl ResolveFilter for SliceFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let value = variable.unwrap_or_else(|| "".as_content());
+        let argument = self
+            .argument
+            .resolve(py, template, context, ResolveFailures::Raise)?
+            .expect("missing argument in context should already have raised");
+        // `str()` of a float or bool is never a valid integer.
+        let argument = match argument {
+            Content::String(argument) => argument.into_raw(),
+            Content::Int(argument) => Cow::Owned(argument.to_string()),
+            Content::Py(argument) => Cow::Owned(argument.str()?.extract::<String>()?),
+            Content::Float(_) | Content::Bool(_) => return Ok(Some(value)),
+        };
+        // Bounds beyond `i64` select the same items as `i64::MIN` or `i64::MAX`.
+        let clamp = |bit: BigInt| {
+            bit.to_i64().unwrap_or(match bit.is_negative() {
+                true => i64::MIN,
+                false => i64::MAX,
+            })
+        };
+        let bits: Option<Vec<Option<i64>>> = argument
+            .split(':')
+            .map(|bit| match bit {
+                "" => Some(None),
+                bit => parse_python_bigint(bit).map(|bit| Some(clamp(bit))),
+            })
+            .collect();
+        let (start, stop, step) = match bits.as_deref() {
+            Some([stop]) => (None, *stop, None),
+            Some([start, stop]) => (*start, *stop, None),
+            Some([start, stop, step]) => (*start, *stop, *step),
+            _ => return Ok(Some(value)),
+        };
+        match value {
+            Content::String(content) => {
+                let step = step.unwrap_or(1);
+                if step == 0 {
+                    return Ok(Some(Content::String(content)));
+                }
+                let chars: Vec<char> = content.as_raw().chars().collect();
+                let sliced: String = slice_indices(chars.len(), start, stop, step)
+                    .map(|index| chars[index])
+                    .collect();
+                Ok(Some(content.map_content(|_| Cow::Owned(sliced))))
+            }
+            value => {
+                let object = value.to_py(py)?;
+                let slice = PyType::new::<PySlice>(py).call1((start, stop, step))?;
+                let sliced = object.get_item(slice);
+                match sliced {
+                    Ok(sliced) => Ok(Some(Content::Py(preserve_safety(&object, sliced)?))),
+                    Err(error)
+                        if error.is_instance_of::<PyTypeError>(py)
+                            || error.is_instance_of::<PyValueError>(py) =>
+                    {
+                        Ok(Some(value))
+                    }
+                    Err(error) => Err(error.into()),
+                }
+            }
+        }
+    }
+}
+
+fn slugify(content: Cow<str>) -> Cow<str> {
+    let content = content
+        .nfkd()
+        // first decomposing characters, then only keeping
+        // the ascii ones, filtering out diacritics for example.
+        .filter(|c| c.is_ascii())
+        .collect::<String>()
+        .to_lowercase();
+    let content = NON_WORD_RE.replace_all(&content, "");
+    let content = content.trim();
+    let content = WHITESPACE_RE.replace_all(content, "-");
+    Cow::Owned(content.to_string())
+}
+
+impl ResolveFilter for SlugifyFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
         _context: &mut Context,
     ) -> ResolveResult<'t, 'py> {
         let content = match variable {
@@ -399,22 +2223,634 @@ impl ResolveFilter for SlugifyFilter {
     }
 }
 
-impl ResolveFilter for UpperFilter {
+impl ResolveFilter for StringFormatFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let value = variable.unwrap_or_else(|| "".as_content());
+        let spec = self
+            .argument
+            .resolve(py, template, context, ResolveFailures::Raise)?
+            .expect("missing argument in context should already have raised");
+        if let Content::String(spec) = &spec
+            && let Some(spec) = FormatSpec::parse(spec.as_raw())
+            && let Some(formatted) = format_native(&spec, &value)?
+        {
+            return Ok(Some(match value {
+                Content::String(ContentString::HtmlSafe(_)) => {
+                    Content::String(ContentString::HtmlSafe(Cow::Owned(formatted)))
+                }
+                _ => unsafe_string(Cow::Owned(formatted), context),
+            }));
+        }
+
+        let value = value.to_py(py)?;
+        // Django formats tuples as a whole, not as the format's arguments.
+        let value = match value.is_instance_of::<PyTuple>() {
+            true => value.str()?.into_any(),
+            false => value,
+        };
+        let format = PyString::new(py, "%").add(spec.to_py(py)?.str()?)?;
+        match format.rem(&value) {
+            Ok(formatted) => Ok(Some(Content::Py(preserve_safety(&value, formatted)?))),
+            Err(error)
+                if error.is_instance_of::<PyValueError>(py)
+                    || error.is_instance_of::<PyTypeError>(py) =>
+            {
+                Ok(Some("".as_content()))
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+/// Format `value` natively, or return `None` to leave it to Python.
+fn format_native(spec: &FormatSpec, value: &Content<'_, '_>) -> PyResult<Option<String>> {
+    let text;
+    let value = match value {
+        Content::String(content) => Value::Str(content.as_raw()),
+        Content::Int(int) => Value::Int(int.clone()),
+        Content::Float(float) => Value::Float(*float),
+        Content::Bool(bool) => Value::Bool(*bool),
+        // Subclasses may change how they are formatted.
+        Content::Py(value) if value.is_exact_instance_of::<PyString>() => {
+            text = value.extract::<String>()?;
+            Value::Str(&text)
+        }
+        Content::Py(value) if value.is_exact_instance_of::<PyInt>() => Value::Int(value.extract()?),
+        Content::Py(value) if value.is_exact_instance_of::<PyFloat>() => {
+            Value::Float(value.extract()?)
+        }
+        Content::Py(value) if value.is_exact_instance_of::<PyBool>() => {
+            Value::Bool(value.extract()?)
+        }
+        Content::Py(_) => return Ok(None),
+    };
+    Ok(spec.format(value))
+}
+
+impl ResolveFilter for StripTagsFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let content = match variable {
+            Some(content) => content.resolve_string(context)?,
+            None => return Ok(Some("".as_content())),
+        };
+        let strip_tags = STRIP_TAGS.import(py, "django.utils.html", "strip_tags")?;
+        let stripped: String = strip_tags.call1((content.as_raw(),))?.extract()?;
+        Ok(Some(content.map_content(|_| Cow::Owned(stripped))))
+    }
+}
+
+impl ResolveFilter for TimeFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        format_date(
+            variable,
+            &self.argument,
+            "TIME_FORMAT",
+            true,
+            py,
+            template,
+            context,
+        )
+    }
+}
+
+/// Shared implementation of the `timesince` and `timeuntil` filters.
+fn format_timesince<'t, 'py>(
+    variable: Option<Content<'t, 'py>>,
+    argument: &Option<Argument>,
+    reversed: bool,
+    py: Python<'py>,
+    template: TemplateString<'t>,
+    context: &mut Context,
+) -> ResolveResult<'t, 'py> {
+    let value = match variable {
+        Some(value) => value.to_py(py)?,
+        None => return Ok(Some("".as_content())),
+    };
+    if !value.is_truthy()? {
+        return Ok(Some("".as_content()));
+    }
+    let argument = match argument {
+        Some(argument) => Some(
+            argument
+                .resolve(py, template, context, ResolveFailures::Raise)?
+                .expect("missing argument in context should already have raised")
+                .to_py(py)?,
+        ),
+        None => None,
+    };
+    let now = match &argument {
+        Some(argument) if argument.is_truthy()? => Some(argument),
+        _ => None,
+    };
+    let formatted = match timesince(&value, now, reversed, None)? {
+        Some(formatted) => formatted,
+        None => {
+            let filter = match reversed {
+                true => intern!(py, "timeuntil_filter"),
+                false => intern!(py, "timesince_filter"),
+            };
+            let filter = py
+                .import(intern!(py, "django.template.defaultfilters"))?
+                .getattr(filter)?;
+            filter.call1((value, argument))?.str()?.extract()?
+        }
+    };
+    let formatted = Cow::Owned(formatted);
+    Ok(Some(Content::String(match context.autoescape {
+        true => ContentString::HtmlUnsafe(formatted),
+        false => ContentString::String(formatted),
+    })))
+}
+
+impl ResolveFilter for TimeSinceFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        format_timesince(variable, &self.argument, false, py, template, context)
+    }
+}
+
+impl ResolveFilter for TimeUntilFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        format_timesince(variable, &self.argument, true, py, template, context)
+    }
+}
+
+/// The replacement for text truncated by characters.
+fn truncation_ellipsis(py: Python<'_>) -> PyResult<String> {
+    py.import(intern!(py, "django.utils.translation"))?
+        .getattr(intern!(py, "pgettext"))?
+        .call1((
+            "String to return when truncating text",
+            "%(truncated_text)s…",
+        ))?
+        .extract()
+}
+
+/// Shared implementation of the truncation filters.
+///
+/// The value is returned unchanged if the length is not an integer.
+fn truncate<'t, 'py>(
+    variable: Option<Content<'t, 'py>>,
+    argument: &Argument,
+    py: Python<'py>,
+    template: TemplateString<'t>,
+    context: &mut Context,
+    truncate: impl FnOnce(&str, usize) -> PyResult<String>,
+) -> ResolveResult<'t, 'py> {
+    let content = match variable {
+        Some(content) => content.resolve_string(context)?,
+        None => return Ok(Some("".as_content())),
+    };
+    let length = argument
+        .resolve(py, template, context, ResolveFailures::Raise)?
+        .expect("missing argument in context should already have raised");
+    let length = match length {
+        Content::String(length) => parse_python_int(length.as_raw()),
+        length => length.to_bigint().and_then(|length| length.to_i64()),
+    };
+    let Some(length) = length else {
+        return Ok(Some(Content::String(content)));
+    };
+    let length = usize::try_from(length).unwrap_or_default();
+    let truncated = truncate(content.as_raw(), length)?;
+    Ok(Some(content.map_content(|_| Cow::Owned(truncated))))
+}
+
+/// Title case `content` like Python's `str.title`, then lowercase letters
+/// following an apostrophe or a digit as Django's `title` filter does.
+fn title<'py>(py: Python<'py>, content: &str) -> PyResult<String> {
+    let titled: String = match content.is_ascii() {
+        true => {
+            let mut previous_is_cased = false;
+            content
+                .chars()
+                .map(|c| {
+                    let c = match previous_is_cased {
+                        true => c.to_ascii_lowercase(),
+                        false => c.to_ascii_uppercase(),
+                    };
+                    previous_is_cased = c.is_ascii_alphabetic();
+                    c
+                })
+                .collect()
+        }
+        // Python's title casing of other scripts relies on its Unicode tables.
+        false => PyString::new(py, content)
+            .call_method0(intern!(py, "title"))?
+            .extract()?,
+    };
+    let lowercase = |captures: &regex::Captures| captures[0].to_lowercase();
+    let titled = TITLE_APOSTROPHE_RE.replace_all(&titled, lowercase);
+    Ok(TITLE_DIGIT_RE.replace_all(&titled, lowercase).into_owned())
+}
+
+impl ResolveFilter for TitleFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let content = match variable {
+            Some(content) => content.resolve_string(context)?,
+            None => return Ok(Some("".as_content())),
+        };
+        let titled = title(py, content.as_raw())?;
+        Ok(Some(content.map_content(|_| Cow::Owned(titled))))
+    }
+}
+
+impl ResolveFilter for TruncateCharsFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        truncate(
+            variable,
+            &self.argument,
+            py,
+            template,
+            context,
+            |text, length| Ok(truncate_chars(text, length, &truncation_ellipsis(py)?)),
+        )
+    }
+}
+
+impl ResolveFilter for TruncateCharsHtmlFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        truncate(
+            variable,
+            &self.argument,
+            py,
+            template,
+            context,
+            |text, length| Ok(truncate_html_chars(text, length, &truncation_ellipsis(py)?)),
+        )
+    }
+}
+
+impl ResolveFilter for TruncateWordsFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        truncate(
+            variable,
+            &self.argument,
+            py,
+            template,
+            context,
+            |text, length| Ok(truncate_words(text, length, " …")),
+        )
+    }
+}
+
+impl ResolveFilter for TruncateWordsHtmlFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        truncate(
+            variable,
+            &self.argument,
+            py,
+            template,
+            context,
+            |text, length| Ok(truncate_html_words(text, length, " …")),
+        )
+    }
+}
+
+/// Lists nested more deeply than Python's default recursion limit raise a
+/// `RecursionError`, as they do in Django.
+const MAX_UNORDERED_LIST_DEPTH: usize = 1000;
+
+/// Convert an `unordered_list` item to a string, escaping it like
+/// `conditional_escape` when autoescaping.
+fn list_item(item: &Bound<'_, PyAny>, autoescape: bool) -> PyResult<String> {
+    let py = item.py();
+    if !autoescape {
+        return item.str()?.extract();
+    }
+    match item
+        .getattr(intern!(py, "__html__"))
+        .ok_or_isinstance_of::<PyAttributeError>(py)?
+    {
+        Ok(html) => html.call0()?.str()?.extract(),
+        Err(_) => Ok(encode_quoted_attribute(&item.str()?.to_cow()?).into_owned()),
+    }
+}
+
+/// Whether `item` holds the children of the previous `unordered_list` item.
+fn is_sublist(item: &Bound<'_, PyAny>) -> PyResult<bool> {
+    let py = item.py();
+    let generator = GENERATOR_TYPE.import(py, "types", "GeneratorType")?;
+    if !(item.is_instance_of::<PyList>()
+        || item.is_instance_of::<PyTuple>()
+        || item.is_instance(generator)?)
+    {
+        return Ok(false);
+    }
+    Ok(item
+        .try_iter()
+        .ok_or_isinstance_of::<PyTypeError>(py)?
+        .is_ok())
+}
+
+/// An `unordered_list` item and the sublist of its children, if any.
+type ListItem<'py> = (Bound<'py, PyAny>, Option<Bound<'py, PyAny>>);
+
+/// Pair each item with the sublist that follows it, if any.
+fn walk_items<'py>(items: &Bound<'py, PyAny>) -> PyResult<Vec<ListItem<'py>>> {
+    let mut iterator = items.try_iter()?;
+    let mut walked = Vec::new();
+    let Some(mut item) = iterator.next().transpose()? else {
+        return Ok(walked);
+    };
+    loop {
+        let Some(next_item) = iterator.next().transpose()? else {
+            walked.push((item, None));
+            return Ok(walked);
+        };
+        if is_sublist(&next_item)? {
+            walked.push((item, Some(next_item)));
+            match iterator.next().transpose()? {
+                Some(following) => item = following,
+                None => return Ok(walked),
+            }
+        } else {
+            walked.push((item, None));
+            item = next_item;
+        }
+    }
+}
+
+fn format_list(items: &Bound<'_, PyAny>, tabs: usize, autoescape: bool) -> PyResult<String> {
+    if tabs > MAX_UNORDERED_LIST_DEPTH {
+        return Err(PyRecursionError::new_err(
+            "maximum recursion depth exceeded",
+        ));
+    }
+    let indent = "\t".repeat(tabs);
+    let mut output = Vec::new();
+    for (item, children) in walk_items(items)? {
+        let sublist = match children {
+            Some(children) if children.is_truthy()? => format!(
+                "\n{indent}<ul>\n{}\n{indent}</ul>\n{indent}",
+                format_list(&children, tabs + 1, autoescape)?
+            ),
+            _ => String::new(),
+        };
+        output.push(format!(
+            "{indent}<li>{}{sublist}</li>",
+            list_item(&item, autoescape)?
+        ));
+    }
+    Ok(output.join("\n"))
+}
+
+impl ResolveFilter for UnorderedListFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let items = match variable {
+            Some(content) => content.to_py(py)?,
+            None => return Ok(Some("".as_content())),
+        };
+        let list = format_list(&items, 1, context.autoescape)?;
+        Ok(Some(Content::String(ContentString::HtmlSafe(Cow::Owned(
+            list,
+        )))))
+    }
+}
+
+impl ResolveFilter for UpperFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        _py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let content = match variable {
+            Some(content) => {
+                let content = content.resolve_string(context)?;
+                content.map_content(|content| Cow::Owned(content.to_uppercase()))
+            }
+            None => "".as_content(),
+        };
+        Ok(Some(content))
+    }
+}
+
+impl ResolveFilter for UrlEncodeFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let content = string_input(variable, context)?;
+        let safe = match &self.argument {
+            Some(argument) => argument
+                .resolve(py, template, context, ResolveFailures::Raise)?
+                .expect("missing argument in context should already have raised"),
+            None => "/".as_content(),
+        };
+        let safe = match safe {
+            Content::String(safe) => safe.into_raw(),
+            Content::Py(safe) if safe.is_instance_of::<PyString>() => Cow::Owned(safe.extract()?),
+            // Django only passes `safe` on when it isn't `None`.
+            Content::Py(safe) if safe.is_none() => Cow::Borrowed("/"),
+            // Let Python handle (or reject) any other kind of `safe` argument.
+            safe => {
+                let quoted = py
+                    .import(intern!(py, "urllib.parse"))?
+                    .getattr(intern!(py, "quote"))?
+                    .call1((content.as_ref(), safe.to_py(py)?))?
+                    .extract::<String>()?;
+                return Ok(Some(unsafe_string(Cow::Owned(quoted), context)));
+            }
+        };
+        let quoted = quote(&content, &safe).into_owned();
+        Ok(Some(unsafe_string(Cow::Owned(quoted), context)))
+    }
+}
+
+/// Shared implementation of the `urlize` and `urlizetrunc` filters.
+fn urlize_content<'t, 'py>(
+    variable: Option<Content<'t, 'py>>,
+    trim_url_limit: Option<usize>,
+    py: Python<'py>,
+    context: &Context,
+) -> ResolveResult<'t, 'py> {
+    let content = match variable {
+        Some(content) => content.resolve_string(context)?,
+        None => return Ok(Some("".as_content())),
+    };
+    let autoescape = matches!(content, ContentString::HtmlUnsafe(_));
+    let urlized = urlize(py, content.as_raw(), trim_url_limit, true, autoescape)?;
+    Ok(Some(Content::String(ContentString::HtmlSafe(Cow::Owned(
+        urlized,
+    )))))
+}
+
+impl ResolveFilter for UrlizeFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        urlize_content(variable, None, py, context)
+    }
+}
+
+impl ResolveFilter for UrlizeTruncFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let limit = self
+            .argument
+            .resolve(py, template, context, ResolveFailures::Raise)?
+            .expect("missing argument in context should already have raised");
+        let limit = resolve_size(limit, self.argument.at)?;
+        urlize_content(variable, Some(limit), py, context)
+    }
+}
+
+impl ResolveFilter for WordCountFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        _py: Python<'py>,
+        _template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let content = string_input(variable, context)?;
+        let count = content
+            .split(is_python_whitespace)
+            .filter(|word| !word.is_empty())
+            .count();
+        Ok(Some(Content::Int(count.into())))
+    }
+}
+
+impl ResolveFilter for WordWrapFilter {
     fn resolve<'t, 'py>(
         &self,
         variable: Option<Content<'t, 'py>>,
-        _py: Python<'py>,
-        _template: TemplateString<'t>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
         context: &mut Context,
     ) -> ResolveResult<'t, 'py> {
         let content = match variable {
-            Some(content) => {
-                let content = content.resolve_string(context)?;
-                content.map_content(|content| Cow::Owned(content.to_uppercase()))
-            }
-            None => "".as_content(),
+            Some(content) => content.resolve_string(context)?,
+            None => ContentString::String(Cow::Borrowed("")),
         };
-        Ok(Some(content))
+        let width = self
+            .argument
+            .resolve(py, template, context, ResolveFailures::Raise)?
+            .expect("missing argument in context should already have raised");
+        let width = resolve_int(width, self.argument.at)?;
+        if width <= 0 {
+            return Err(
+                PyValueError::new_err(format!("invalid width {width} (must be > 0)")).into(),
+            );
+        }
+        let wrapped = wrap(content.as_raw(), width as usize);
+        Ok(Some(content.map_content(|_| Cow::Owned(wrapped))))
+    }
+}
+
+impl ResolveFilter for YesNoFilter {
+    fn resolve<'t, 'py>(
+        &self,
+        variable: Option<Content<'t, 'py>>,
+        py: Python<'py>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> ResolveResult<'t, 'py> {
+        let argument = match &self.argument {
+            Some(argument) => argument
+                .resolve(py, template, context, ResolveFailures::Raise)?
+                .expect("missing argument in context should already have raised"),
+            None => gettext(py, "yes,no,maybe")?.into_content(),
+        };
+        let bits: Vec<String> = match argument {
+            Content::String(argument) => argument.as_raw().split(',').map(String::from).collect(),
+            argument => argument
+                .to_py(py)?
+                .call_method1(intern!(py, "split"), (",",))?
+                .extract()?,
+        };
+        let (yes, no, maybe) = match bits.as_slice() {
+            [yes, no, maybe] => (yes, no, maybe),
+            [yes, no, ..] => (yes, no, no),
+            // An invalid argument leaves the value unchanged.
+            _ => return Ok(Some(variable.unwrap_or_else(|| "".as_content()))),
+        };
+        let choice = match variable {
+            Some(Content::Py(value)) if value.is_none() => maybe,
+            Some(value) if value.to_py(py)?.is_truthy()? => yes,
+            _ => no,
+        };
+        Ok(Some(unsafe_string(Cow::Owned(choice.clone()), context)))
     }
 }
 
@@ -426,6 +2862,7 @@ mod tests {
     use crate::render::Render;
     use crate::template::django_rusty_templates::{EngineData, Template};
     use crate::types::{Argument, ArgumentType, Text, Variable};
+    use crate::utils::setup_django;
 
     use pyo3::types::{PyDict, PyString};
     static MARK_SAFE: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
@@ -480,7 +2917,7 @@ mod tests {
             let context = PyDict::new(py);
             context.set_item("var", "hello world").unwrap();
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "hello-world");
         })
@@ -496,7 +2933,7 @@ mod tests {
             let context = PyDict::new(py);
             context.set_item("var", " hello world").unwrap();
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "hello-world");
         })
@@ -512,7 +2949,7 @@ mod tests {
             let context = PyDict::new(py);
             context.set_item("var", "a&€%").unwrap();
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "a");
         })
@@ -528,7 +2965,7 @@ mod tests {
             let context = PyDict::new(py);
             context.set_item("var", "a & b").unwrap();
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "a-b");
         })
@@ -543,7 +2980,7 @@ mod tests {
             let template_string = "{{ var|default:1|slugify }}".to_string();
             let context = PyDict::new(py);
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "1");
         })
@@ -558,7 +2995,7 @@ mod tests {
             let template_string = "{{ var|default:1.3|slugify }}".to_string();
             let context = PyDict::new(py);
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "1.3");
         })
@@ -573,7 +3010,7 @@ mod tests {
             let template_string = "{{ var|default:'hello world'|slugify }}".to_string();
             let context = PyDict::new(py);
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "hello-world");
         })
@@ -588,7 +3025,7 @@ mod tests {
             let template_string = "{{ var|default:'hello world'|safe|slugify }}".to_string();
             let context = PyDict::new(py);
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "hello-world");
         })
@@ -605,7 +3042,7 @@ mod tests {
             let safe_string = mark_safe(py, "a &amp; b".to_string()).unwrap();
             context.set_item("var", safe_string).unwrap();
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "a-amp-b");
         })
@@ -620,7 +3057,7 @@ mod tests {
             let template_string = "{{ not_there|slugify }}".to_string();
             let context = PyDict::new(py);
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "");
         })
@@ -671,7 +3108,7 @@ mod tests {
             let context = PyDict::new(py);
             context.set_item("var", "hello world").unwrap();
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "Hello world");
 
@@ -679,7 +3116,7 @@ mod tests {
             context.set_item("var", "").unwrap();
             let template_string = "{{ var|capfirst }}".to_string();
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "");
 
@@ -687,7 +3124,7 @@ mod tests {
             context.set_item("bar", "").unwrap();
             let template_string = "{{ var|capfirst }}".to_string();
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "");
 
@@ -709,7 +3146,7 @@ mod tests {
             let context = PyDict::new(py);
             context.set_item("var", "hello").unwrap();
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "   hello   ");
 
@@ -717,7 +3154,7 @@ mod tests {
             context.set_item("var", "django").unwrap();
             let template_string = "{{ var|center:'15' }}".to_string();
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "     django    ");
 
@@ -725,7 +3162,7 @@ mod tests {
             context.set_item("var", "django").unwrap();
             let template_string = "{{ var|center:1 }}".to_string();
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "django");
         })
@@ -757,7 +3194,7 @@ mod tests {
             let template_string = "{{ var|center:'11' }}".to_string();
             let context = PyDict::new(py);
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "");
         })
@@ -773,7 +3210,7 @@ mod tests {
             let context = PyDict::new(py);
             context.set_item("var", "hello").unwrap();
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
-            let result = template.render(py, Some(context), None).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
 
             assert_eq!(result, "hello");
         })
@@ -807,6 +3244,7 @@ mod tests {
         Python::initialize();
 
         Python::attach(|py| {
+            setup_django(py);
             let context = HashMap::new();
             let mut context = Context::new(context, None, false);
             let template = TemplateString("{{ count|default:12}}");
@@ -830,6 +3268,7 @@ mod tests {
         Python::initialize();
 
         Python::attach(|py| {
+            setup_django(py);
             let context = HashMap::new();
             let mut context = Context::new(context, None, false);
             let template = TemplateString("{{ count|default:3.5}}");
@@ -981,4 +3420,380 @@ mod tests {
             assert_eq!(rendered, "");
         })
     }
+
+    #[test]
+    fn test_render_filter_linebreaks() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string = "{{ var|linebreaks }}".to_string();
+            let context = PyDict::new(py);
+            context.set_item("var", "a & b\r\nc\n\n\nd").unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "<p>a & b<br>c</p>\n\n<p>d</p>");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_escapejs() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string = "{{ var|escapejs }}".to_string();
+            let context = PyDict::new(py);
+            context.set_item("var", "<a href=\"x\">\n").unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "\\u003Ca href\\u003D\\u0022x\\u0022\\u003E\\u000A");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_force_escape_safe() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string = "{{ var|safe|force_escape }}".to_string();
+            let context = PyDict::new(py);
+            context.set_item("var", "<b>&amp;</b>").unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "&lt;b&gt;&amp;amp;&lt;/b&gt;");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_striptags() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string = "{{ var|striptags }}".to_string();
+            let context = PyDict::new(py);
+            context
+                .set_item("var", "<p>a <b>b</b></p><script>c</script>")
+                .unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "a bc");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_cut() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string = "{{ var|cut:\" \" }}".to_string();
+            let context = PyDict::new(py);
+            context.set_item("var", "a b c").unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "abc");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_ljust_counts_characters() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string = ".{{ var|ljust:4 }}.".to_string();
+            let context = PyDict::new(py);
+            context.set_item("var", "Åö").unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, ".Åö  .");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_title() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string = "{{ var|title }}".to_string();
+            let context = PyDict::new(py);
+            context.set_item("var", "JOE'S 53RD CRAB-SHACK").unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "Joe's 53rd Crab-Shack");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_pluralize() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string =
+                "{{ one|pluralize:\"y,ies\" }} {{ many|pluralize:\"y,ies\" }}".to_string();
+            let context = PyDict::new(py);
+            context.set_item("one", vec![1]).unwrap();
+            context.set_item("many", 2.5).unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "y ies");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_add_mismatched_sequences() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            setup_django(py);
+            let engine = EngineData::empty();
+            let template_string = "{{ list|add:list|length }}|{{ list|add:tuple }}".to_string();
+            let context = PyDict::new(py);
+            context.set_item("list", vec![1]).unwrap();
+            context.set_item("tuple", (2,)).unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "2|");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_divisibleby() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string =
+                "{{ value|divisibleby:3 }} {{ value|divisibleby:'4' }}".to_string();
+            let context = PyDict::new(py);
+            context.set_item("value", "-12").unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "True True");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_get_digit() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            setup_django(py);
+            let engine = EngineData::empty();
+            let template_string =
+                "{{ value|get_digit:1 }} {{ value|get_digit:5 }} {{ value|get_digit:0 }} {{ text|get_digit:1 }}"
+                    .to_string();
+            let context = PyDict::new(py);
+            context.set_item("value", "0123").unwrap();
+            context.set_item("text", "abc").unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "3 0 123 abc");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_urlencode() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string =
+                "{{ url|urlencode }} {{ url|urlencode:'' }} {{ url|iriencode }}".to_string();
+            let context = PyDict::new(py);
+            context.set_item("url", "/a b?c=ü").unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(
+                result,
+                "/a%20b%3Fc%3D%C3%BC %2Fa%20b%3Fc%3D%C3%BC /a%20b?c=%C3%BC"
+            );
+        })
+    }
+
+    #[test]
+    fn test_render_filter_default_if_none() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string =
+                "{{ none|default_if_none:'x' }}|{{ empty|default_if_none:'x' }}|{{ missing|default_if_none:'x' }}"
+                    .to_string();
+            let context = PyDict::new(py);
+            context.set_item("none", py.None()).unwrap();
+            context.set_item("empty", "").unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "x||");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_stringformat() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string =
+                "{{ int|stringformat:'05d' }} {{ int|stringformat:'#x' }} {{ float|stringformat:'.2f' }} {{ text|stringformat:'-4s' }}| {{ text|stringformat:'d' }}|"
+                    .to_string();
+            let context = PyDict::new(py);
+            context.set_item("int", 42).unwrap();
+            context.set_item("float", 1.23456).unwrap();
+            context.set_item("text", "ab").unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "00042 0x2a 1.23 ab  | |");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_phone2numeric() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string = "{{ phone|phone2numeric }}".to_string();
+            let context = PyDict::new(py);
+            context.set_item("phone", "0800 FLOWERS").unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "0800 3569377");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_unordered_list() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string = "{{ list|unordered_list }}".to_string();
+            let context = PyDict::new(py);
+            let list = py
+                .eval(c"['a<', ['b', ['c']], 'd', [], 'e', ('f',)]", None, None)
+                .unwrap();
+            context.set_item("list", list).unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(
+                result,
+                "\t<li>a<\n\t<ul>\n\t\t<li>b\n\t\t<ul>\n\t\t\t<li>c</li>\n\t\t</ul>\n\t\t</li>\n\t</ul>\n\t</li>\n\t<li>d</li>\n\t<li>e\n\t<ul>\n\t\t<li>f</li>\n\t</ul>\n\t</li>"
+            );
+        })
+    }
+
+    #[test]
+    fn test_render_filter_linebreaksbr() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string = "{{ var|linebreaksbr }}".to_string();
+            let context = PyDict::new(py);
+            context.set_item("var", "<a>\rb\n").unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "<a><br>b<br>");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_linenumbers_padding() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string = "{{ var|linenumbers }}".to_string();
+            let context = PyDict::new(py);
+            let lines: Vec<String> = (1..=10).map(|n| n.to_string()).collect();
+            context.set_item("var", lines.join("\n")).unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            let expected: Vec<String> = (1..=10).map(|n| format!("{n:02}. {n}")).collect();
+            assert_eq!(result, expected.join("\n"));
+        })
+    }
+
+    #[test]
+    fn test_slice_indices() {
+        let slice =
+            |start, stop, step| -> Vec<usize> { slice_indices(7, start, stop, step).collect() };
+        assert_eq!(slice(None, Some(2), 1), vec![0, 1]);
+        assert_eq!(slice(Some(1), Some(-1), 2), vec![1, 3, 5]);
+        assert_eq!(slice(None, None, -1), vec![6, 5, 4, 3, 2, 1, 0]);
+        assert_eq!(slice(Some(-2), None, -3), vec![5, 2]);
+        assert_eq!(slice(Some(10), Some(-10), -1), vec![6, 5, 4, 3, 2, 1, 0]);
+        assert_eq!(slice(Some(-10), Some(10), 1), vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(slice(Some(5), Some(2), 1), Vec::<usize>::new());
+        assert_eq!(slice(Some(2), None, i64::MAX), vec![2]);
+        assert_eq!(slice(Some(-1), None, i64::MIN), vec![6]);
+        assert_eq!(slice(Some(1), Some(i64::MAX), 1), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(slice(Some(i64::MIN), Some(2), 1), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_render_filter_dictsort_path() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string =
+                "{% for p in people|dictsort:'pet.age' %}{{ p.name }}{% endfor %}".to_string();
+            let people = py
+                .eval(
+                    c"[{'name': 'a', 'pet': {'age': 3}}, {'name': 'b', 'pet': {'age': 1}}, {'name': 'c', 'pet': {'age': 2}}]",
+                    None,
+                    None,
+                )
+                .unwrap();
+            let context = PyDict::new(py);
+            context.set_item("people", people).unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "bca");
+        })
+    }
+
+    #[test]
+    fn test_render_filter_dictsort_incomparable() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let engine = EngineData::empty();
+            let template_string = "{{ data|dictsortreversed:'a' }}".to_string();
+            let data = py.eval(c"[{'a': 1}, {'a': 'x'}]", None, None).unwrap();
+            let context = PyDict::new(py);
+            context.set_item("data", data).unwrap();
+            let template = Template::new_from_string(py, template_string, &engine).unwrap();
+            let result = template.render(py, Some(context.into_any()), None).unwrap();
+
+            assert_eq!(result, "");
+        })
+    }
 }
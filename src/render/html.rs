@@ -0,0 +1,47 @@
+//! Ports of the text helpers in `django.utils.html`.
+
+/// Escape `value` for use in a JavaScript string literal.
+pub fn escapejs(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' | '\'' | '"' | '>' | '<' | '&' | '=' | '-' | ';' | '`' | '\u{2028}'
+            | '\u{2029}' => escaped.push_str(&format!("\\u{:04X}", c as u32)),
+            c if (c as u32) < 32 => escaped.push_str(&format!("\\u{:04X}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pyo3::prelude::*;
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn test_escapejs() {
+        assert_eq!(
+            escapejs("<a href=\"x\">'\\`;</a>\u{2028}\n"),
+            "\\u003Ca href\\u003D\\u0022x\\u0022\\u003E\\u0027\\u005C\\u0060\\u003B\\u003C/a\\u003E\\u2028\\u000A"
+        );
+    }
+
+    #[test]
+    fn test_escapejs_matches_django_escapejs() {
+        Python::initialize();
+
+        fn matches(value: String) -> bool {
+            Python::attach(|py| {
+                let html = PyModule::import(py, "django.utils.html").unwrap();
+                let django_escapejs = html.getattr("escapejs").unwrap();
+
+                let escaped: String = django_escapejs.call1((&value,)).unwrap().extract().unwrap();
+                escaped == escapejs(&value)
+            })
+        }
+        quickcheck(matches as fn(String) -> bool)
+    }
+}
@@ -0,0 +1,242 @@
+//! Ports of the filters in `django.contrib.humanize`.
+
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDateTime, PyDelta, PyDeltaAccess, PyDict, PyTzInfoAccess};
+
+use crate::render::timesince::timesince;
+use crate::render::translation::{gettext, ngettext};
+
+/// The numbers `apnumber` spells out.
+pub const AP_NUMBERS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The powers of ten `intword` names, with the singular and plural forms of
+/// each name.
+pub const INTWORD_CONVERTERS: [(u32, &str, &str); 11] = [
+    (6, "%(value)s million", "%(value)s million"),
+    (9, "%(value)s billion", "%(value)s billion"),
+    (12, "%(value)s trillion", "%(value)s trillion"),
+    (15, "%(value)s quadrillion", "%(value)s quadrillion"),
+    (18, "%(value)s quintillion", "%(value)s quintillion"),
+    (21, "%(value)s sextillion", "%(value)s sextillion"),
+    (24, "%(value)s septillion", "%(value)s septillion"),
+    (27, "%(value)s octillion", "%(value)s octillion"),
+    (30, "%(value)s nonillion", "%(value)s nonillion"),
+    (33, "%(value)s decillion", "%(value)s decillion"),
+    (100, "%(value)s googol", "%(value)s googol"),
+];
+
+/// The translation contexts and formats `ordinal` uses for each last digit.
+const ORDINAL_FORMATS: [(&str, &str); 10] = [
+    ("ordinal 0", "{}th"),
+    ("ordinal 1", "{}st"),
+    ("ordinal 2", "{}nd"),
+    ("ordinal 3", "{}rd"),
+    ("ordinal 4", "{}th"),
+    ("ordinal 5", "{}th"),
+    ("ordinal 6", "{}th"),
+    ("ordinal 7", "{}th"),
+    ("ordinal 8", "{}th"),
+    ("ordinal 9", "{}th"),
+];
+
+/// The messages `naturaltime` uses for times less than a day away, from the
+/// past and future seconds to the future hours.
+const NATURAL_TIMES: [(&str, &str); 6] = [
+    ("a second ago", "%(count)s\u{a0}seconds ago"),
+    ("a minute ago", "%(count)s\u{a0}minutes ago"),
+    ("an hour ago", "%(count)s\u{a0}hours ago"),
+    ("a second from now", "%(count)s\u{a0}seconds from now"),
+    ("a minute from now", "%(count)s\u{a0}minutes from now"),
+    ("an hour from now", "%(count)s\u{a0}hours from now"),
+];
+
+/// Python's `repr` of a float, or `None` if it uses scientific notation or
+/// the value is not finite.
+pub fn float_repr(value: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+    let repr: String = value.repr()?.extract()?;
+    Ok(match repr.contains(['e', 'n']) {
+        true => None,
+        false => Some(repr),
+    })
+}
+
+/// Insert commas every three digits into the integer at the start of `value`,
+/// like `intcomma` does without localization.
+///
+/// Returns `None` for non-ASCII values, as Python's `\d` matches other digits.
+pub fn intcomma(value: &str) -> Option<String> {
+    if !value.is_ascii() {
+        return None;
+    }
+    let (sign, rest) = match value.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", value),
+    };
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    if end == 0 {
+        return Some(value.to_string());
+    }
+    let (digits, suffix) = rest.split_at(end);
+    let mut grouped = String::with_capacity(value.len() + digits.len() / 3);
+    grouped.push_str(sign);
+    for (index, digit) in digits.chars().enumerate() {
+        if index != 0 && (digits.len() - index) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped.push_str(suffix);
+    Some(grouped)
+}
+
+/// Round to an integer away from one, like `intword` does to pick between
+/// the singular and plural.
+pub fn round_away_from_one(value: f64) -> i64 {
+    let value = value - 1.0;
+    let rounded = match value.is_sign_negative() {
+        true => value.floor(),
+        false => value.ceil(),
+    };
+    rounded as i64 + 1
+}
+
+/// The translation context and format `ordinal` uses for a non-negative
+/// `value`.
+pub fn ordinal_format(value: &BigInt) -> (&'static str, &'static str) {
+    let last_digits = (value % 100u8)
+        .to_usize()
+        .expect("A remainder of 100 is a small integer");
+    match last_digits {
+        11..=13 => ("ordinal 11, 12, 13", "{}th"),
+        last_digits => ORDINAL_FORMATS[last_digits % 10],
+    }
+}
+
+/// Substitute `value` for `key` in a translated message, like Python's `%`
+/// operator with a dict.
+pub fn interpolate<'py>(
+    py: Python<'py>,
+    message: &str,
+    key: &str,
+    value: impl IntoPyObject<'py>,
+) -> PyResult<String> {
+    let values = PyDict::new(py);
+    values.set_item(key, value)?;
+    message.into_pyobject(py)?.rem(values)?.extract()
+}
+
+/// Describe how long ago or from now `value` is, like `naturaltime`.
+///
+/// Returns `None` for datetimes `timesince` does not handle natively.
+pub fn naturaltime(value: &Bound<'_, PyDateTime>) -> PyResult<Option<String>> {
+    let py = value.py();
+    let datetime = py.import(intern!(py, "datetime"))?;
+    let is_aware = !value.call_method0(intern!(py, "utcoffset"))?.is_none();
+    let timezone = match is_aware {
+        true => Some(
+            datetime
+                .getattr(intern!(py, "timezone"))?
+                .getattr(intern!(py, "utc"))?,
+        ),
+        false => None,
+    };
+    let now = datetime
+        .getattr(intern!(py, "datetime"))?
+        .call_method1(intern!(py, "now"), (timezone,))?;
+    let past = value.lt(&now)?;
+    let delta = match past {
+        true => now.sub(value)?,
+        false => value.sub(&now)?,
+    };
+    let delta = delta.cast_into::<PyDelta>()?;
+    let seconds = delta.get_seconds() as u64;
+    if delta.get_days() != 0 {
+        // Like `timesince`, compare the times in the timezone of the value.
+        let now = match is_aware {
+            true => now.call_method1(intern!(py, "astimezone"), (value.get_tzinfo(),))?,
+            false => now,
+        };
+        let (context, message) = match past {
+            true => ("naturaltime-past", "%(delta)s ago"),
+            false => ("naturaltime-future", "%(delta)s from now"),
+        };
+        let Some(delta) = timesince(value, Some(&now), !past, Some(context))? else {
+            return Ok(None);
+        };
+        return interpolate(py, &gettext(py, message)?, "delta", delta).map(Some);
+    }
+    let (count, unit) = match seconds {
+        0 => return gettext(py, "now").map(Some),
+        1..60 => (seconds, 0),
+        60..3600 => (seconds / 60, 1),
+        _ => (seconds / 3600, 2),
+    };
+    let (singular, plural) = match past {
+        true => NATURAL_TIMES[unit],
+        false => NATURAL_TIMES[unit + 3],
+    };
+    let message = ngettext(py, singular, plural, count)?;
+    interpolate(py, &message, "count", count).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pyo3::types::PyFloat;
+
+    #[test]
+    fn test_float_repr() {
+        Python::initialize();
+
+        Python::attach(|py| {
+            let repr = |value: f64| float_repr(&PyFloat::new(py, value)).unwrap();
+            assert_eq!(repr(1.0).unwrap(), "1.0");
+            assert_eq!(repr(-1234.5).unwrap(), "-1234.5");
+            assert_eq!(repr(1e16), None);
+            assert_eq!(repr(f64::NAN), None);
+            assert_eq!(repr(f64::INFINITY), None);
+        })
+    }
+
+    #[test]
+    fn test_intcomma() {
+        assert_eq!(intcomma("100").unwrap(), "100");
+        assert_eq!(intcomma("1000").unwrap(), "1,000");
+        assert_eq!(intcomma("-1234567.25").unwrap(), "-1,234,567.25");
+        assert_eq!(intcomma("-123").unwrap(), "-123");
+        assert_eq!(intcomma("abc").unwrap(), "abc");
+        assert_eq!(intcomma("-").unwrap(), "-");
+        assert_eq!(intcomma("١٢٣٤"), None);
+    }
+
+    #[test]
+    fn test_round_away_from_one() {
+        assert_eq!(round_away_from_one(1.0), 1);
+        assert_eq!(round_away_from_one(1.2), 2);
+        assert_eq!(round_away_from_one(0.5), 0);
+        assert_eq!(round_away_from_one(-1.5), -2);
+    }
+
+    #[test]
+    fn test_ordinal_format() {
+        assert_eq!(ordinal_format(&BigInt::from(1)), ("ordinal 1", "{}st"));
+        assert_eq!(
+            ordinal_format(&BigInt::from(12)),
+            ("ordinal 11, 12, 13", "{}th")
+        );
+        assert_eq!(ordinal_format(&BigInt::from(103)), ("ordinal 3", "{}rd"));
+        assert_eq!(
+            ordinal_format(&BigInt::from(111)),
+            ("ordinal 11, 12, 13", "{}th")
+        );
+        assert_eq!(ordinal_format(&BigInt::from(20)), ("ordinal 0", "{}th"));
+    }
+}
@@ -0,0 +1,425 @@
+//! Decimal rounding for `floatformat` and `filesizeformat`, and a port of
+//! `django.utils.numberformat`.
+
+use std::cmp::Ordering;
+
+use num_bigint::BigInt;
+use num_traits::Zero;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// A finite decimal number, as Python's `decimal.Decimal` stores it.
+///
+/// The value is `coefficient * 10 ** exponent`, where `coefficient` is never
+/// negative.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Decimal {
+    pub negative: bool,
+    pub coefficient: BigInt,
+    pub exponent: i64,
+}
+
+/// The result of parsing a string with Python's `Decimal` constructor.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParsedDecimal {
+    Finite(Decimal),
+    /// Infinities and NaNs
+    NonFinite,
+}
+
+impl Decimal {
+    /// Parse a string with the same grammar as Python's `Decimal`.
+    ///
+    /// Returns `None` if Python would raise `InvalidOperation`.
+    pub fn parse(value: &str) -> Option<ParsedDecimal> {
+        let value = value.trim();
+        let (negative, value) = match value.as_bytes().first() {
+            Some(b'-') => (true, &value[1..]),
+            Some(b'+') => (false, &value[1..]),
+            _ => (false, value),
+        };
+        let lowercase = value.to_ascii_lowercase();
+        if lowercase == "inf" || lowercase == "infinity" {
+            return Some(ParsedDecimal::NonFinite);
+        }
+        // NaNs may carry diagnostic digits, e.g. "NaN123".
+        if let Some(diagnostic) = lowercase
+            .strip_prefix("nan")
+            .or_else(|| lowercase.strip_prefix("snan"))
+            && diagnostic.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Some(ParsedDecimal::NonFinite);
+        }
+
+        let (mantissa, exponent) = match value.find(['e', 'E']) {
+            Some(index) => (&value[..index], Some(&value[index + 1..])),
+            None => (value, None),
+        };
+        let (integer, fraction) = match mantissa.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (mantissa, ""),
+        };
+        let integer = strip_underscores(integer)?;
+        let fraction = strip_underscores(fraction)?;
+        if integer.is_empty() && fraction.is_empty() {
+            return None;
+        }
+        let mut exponent = match exponent {
+            Some(exponent) => {
+                let exponent = strip_signed_underscores(exponent)?;
+                exponent.parse::<i64>().ok()?
+            }
+            None => 0,
+        };
+        exponent -= fraction.len() as i64;
+        let digits = integer + &fraction;
+        let coefficient = digits.parse::<BigInt>().ok()?;
+        Some(ParsedDecimal::Finite(Self {
+            negative,
+            coefficient,
+            exponent,
+        }))
+    }
+
+    /// The number of digits in the coefficient, as in `len(d.as_tuple().digits)`.
+    pub fn digit_count(&self) -> usize {
+        match self.coefficient.is_zero() {
+            true => 1,
+            false => self.coefficient.to_string().len(),
+        }
+    }
+
+    /// Whether the number has a non-zero fractional part.
+    pub fn has_fraction(&self) -> bool {
+        if self.exponent >= 0 {
+            return false;
+        }
+        let scale = BigInt::from(10).pow(self.exponent.unsigned_abs() as u32);
+        !(&self.coefficient % scale).is_zero()
+    }
+
+    /// The integer part, truncated towards zero, as in `int(d)`.
+    pub fn truncate(&self) -> BigInt {
+        let magnitude = match self.exponent >= 0 {
+            true => &self.coefficient * BigInt::from(10).pow(self.exponent as u32),
+            false => &self.coefficient / BigInt::from(10).pow(self.exponent.unsigned_abs() as u32),
+        };
+        match self.negative {
+            true => -magnitude,
+            false => magnitude,
+        }
+    }
+
+    /// Round to `places` decimal places with `ROUND_HALF_UP` and format the
+    /// result without scientific notation, as `floatformat` does.
+    pub fn round_half_up(&self, places: u32) -> String {
+        self.round(places, false)
+    }
+
+    /// Round to `places` decimal places with `ROUND_HALF_EVEN`, as Python's
+    /// `round` does, and format the result without scientific notation.
+    pub fn round_half_even(&self, places: u32) -> String {
+        self.round(places, true)
+    }
+
+    fn round(&self, places: u32, half_even: bool) -> String {
+        let shift = self.exponent + places as i64;
+        let coefficient = match shift >= 0 {
+            true => &self.coefficient * BigInt::from(10).pow(shift as u32),
+            false => {
+                let scale = BigInt::from(10).pow(shift.unsigned_abs() as u32);
+                let (quotient, remainder) =
+                    (&self.coefficient / &scale, &self.coefficient % &scale);
+                let round_up = match (remainder * 2u8).cmp(&scale) {
+                    Ordering::Greater => true,
+                    Ordering::Equal => !half_even || quotient.bit(0),
+                    Ordering::Less => false,
+                };
+                match round_up {
+                    true => quotient + 1,
+                    false => quotient,
+                }
+            }
+        };
+        let mut digits = coefficient.to_string();
+        let places = places as usize;
+        if digits.len() <= places {
+            digits.insert_str(0, &"0".repeat(places + 1 - digits.len()));
+        }
+        digits.insert(digits.len() - places, '.');
+        if self.negative && !coefficient.is_zero() {
+            digits.insert(0, '-');
+        }
+        digits
+    }
+}
+
+fn strip_underscores(digits: &str) -> Option<String> {
+    if digits.is_empty() {
+        return Some(String::new());
+    }
+    // Underscores are only allowed between digits.
+    if digits.starts_with('_') || digits.ends_with('_') || digits.contains("__") {
+        return None;
+    }
+    let digits: String = digits.chars().filter(|&c| c != '_').collect();
+    match digits.bytes().all(|b| b.is_ascii_digit()) {
+        true => Some(digits),
+        false => None,
+    }
+}
+
+fn strip_signed_underscores(exponent: &str) -> Option<String> {
+    let (sign, digits) = match exponent.as_bytes().first() {
+        Some(b'-') => ("-", &exponent[1..]),
+        Some(b'+') => ("", &exponent[1..]),
+        _ => ("", exponent),
+    };
+    let digits = strip_underscores(digits)?;
+    match digits.is_empty() {
+        true => None,
+        false => Some(format!("{sign}{digits}")),
+    }
+}
+
+/// How the integer part of a number is grouped, from `NUMBER_GROUPING`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Grouping {
+    Single(i64),
+    Sequence(Vec<i64>),
+}
+
+/// The locale settings used by `number_format`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberFormat {
+    pub decimal_separator: String,
+    pub thousand_separator: String,
+    pub grouping: Option<Grouping>,
+}
+
+impl NumberFormat {
+    /// Load the separators and grouping like `django.utils.formats.number_format`.
+    ///
+    /// `grouping` is `None` when the number should not be grouped.
+    pub fn load(py: Python<'_>, use_l10n: bool, force_grouping: bool) -> PyResult<Self> {
+        let formats = py.import(intern!(py, "django.utils.formats"))?;
+        let get_format = |name| -> PyResult<Bound<'_, PyAny>> {
+            let kwargs = PyDict::new(py);
+            kwargs.set_item(intern!(py, "use_l10n"), use_l10n)?;
+            formats
+                .getattr(intern!(py, "get_format"))?
+                .call((name,), Some(&kwargs))
+        };
+        let decimal_separator = get_format("DECIMAL_SEPARATOR")?.str()?.extract()?;
+        let use_grouping = force_grouping
+            || (use_l10n
+                && py
+                    .import(intern!(py, "django.conf"))?
+                    .getattr(intern!(py, "settings"))?
+                    .getattr(intern!(py, "USE_THOUSAND_SEPARATOR"))?
+                    .is_truthy()?);
+        let grouping = match use_grouping {
+            false => None,
+            true => {
+                let grouping = get_format("NUMBER_GROUPING")?;
+                match grouping.extract::<i64>() {
+                    Ok(0) => None,
+                    Ok(grouping) => Some(Grouping::Single(grouping)),
+                    Err(_) => Some(Grouping::Sequence(grouping.extract()?)),
+                }
+            }
+        };
+        let thousand_separator = match grouping {
+            Some(_) => get_format("THOUSAND_SEPARATOR")?.str()?.extract()?,
+            None => String::new(),
+        };
+        Ok(Self {
+            decimal_separator,
+            thousand_separator,
+            grouping,
+        })
+    }
+
+    /// Format a number string like `django.utils.numberformat.format`.
+    pub fn format(&self, number: &str, decimal_pos: Option<usize>) -> String {
+        let (sign, number) = match number.strip_prefix('-') {
+            Some(number) => ("-", number),
+            None => ("", number),
+        };
+        let (int_part, mut dec_part) = match number.split_once('.') {
+            Some((int_part, dec_part)) => match decimal_pos {
+                Some(decimal_pos) => (
+                    int_part,
+                    dec_part.chars().take(decimal_pos).collect::<String>(),
+                ),
+                None => (int_part, dec_part.to_string()),
+            },
+            None => (number, String::new()),
+        };
+        if let Some(decimal_pos) = decimal_pos {
+            let len = dec_part.chars().count();
+            if decimal_pos > len {
+                dec_part.push_str(&"0".repeat(decimal_pos - len));
+            }
+        }
+        if !dec_part.is_empty() {
+            dec_part.insert_str(0, &self.decimal_separator);
+        }
+        let int_part = match &self.grouping {
+            Some(grouping) => self.group(int_part, grouping),
+            None => int_part.to_string(),
+        };
+        format!("{sign}{int_part}{dec_part}")
+    }
+
+    fn group(&self, int_part: &str, grouping: &Grouping) -> String {
+        let mut intervals = match grouping {
+            Grouping::Single(grouping) => vec![*grouping, 0],
+            Grouping::Sequence(intervals) => intervals.clone(),
+        }
+        .into_iter();
+        let mut active_interval = intervals.next().unwrap_or_default();
+        let separator: String = self.thousand_separator.chars().rev().collect();
+        let mut grouped = String::with_capacity(int_part.len() * 2);
+        let mut count = 0;
+        for digit in int_part.chars().rev() {
+            if count != 0 && count == active_interval {
+                if let Some(interval) = intervals.next()
+                    && interval != 0
+                {
+                    active_interval = interval;
+                }
+                grouped.push_str(&separator);
+                count = 0;
+            }
+            grouped.push(digit);
+            count += 1;
+        }
+        grouped.chars().rev().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal(value: &str) -> Decimal {
+        match Decimal::parse(value) {
+            Some(ParsedDecimal::Finite(decimal)) => decimal,
+            parsed => panic!("{value} parsed as {parsed:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            decimal("-12.340"),
+            Decimal {
+                negative: true,
+                coefficient: BigInt::from(12340),
+                exponent: -3,
+            }
+        );
+        assert_eq!(
+            decimal("1.5e+20"),
+            Decimal {
+                negative: false,
+                coefficient: BigInt::from(15),
+                exponent: 19,
+            }
+        );
+        assert_eq!(decimal(" 1_000 ").coefficient, BigInt::from(1000));
+        assert_eq!(decimal(".5").exponent, -1);
+        assert_eq!(decimal("5.").exponent, 0);
+    }
+
+    #[test]
+    fn test_parse_non_finite() {
+        for value in ["inf", "-Infinity", "nan", "sNaN", "NaN123"] {
+            assert_eq!(Decimal::parse(value), Some(ParsedDecimal::NonFinite));
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for value in ["", "abc", "1.2.3", "e5", "1e", "1__0", "_1", "--1", "1 2"] {
+            assert_eq!(Decimal::parse(value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_digit_count() {
+        assert_eq!(decimal("0.00").digit_count(), 1);
+        assert_eq!(decimal("007").digit_count(), 1);
+        assert_eq!(decimal("123.45").digit_count(), 5);
+    }
+
+    #[test]
+    fn test_has_fraction() {
+        assert!(decimal("1.5").has_fraction());
+        assert!(!decimal("1.000").has_fraction());
+        assert!(!decimal("1e3").has_fraction());
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(decimal("-1.9").truncate(), BigInt::from(-1));
+        assert_eq!(decimal("1e3").truncate(), BigInt::from(1000));
+    }
+
+    #[test]
+    fn test_round_half_up() {
+        assert_eq!(decimal("34.23234").round_half_up(3), "34.232");
+        assert_eq!(decimal("34.26000").round_half_up(1), "34.3");
+        assert_eq!(decimal("0.125").round_half_up(2), "0.13");
+        assert_eq!(decimal("-0.125").round_half_up(2), "-0.13");
+        assert_eq!(decimal("-0.001").round_half_up(2), "0.00");
+        assert_eq!(decimal("34.5").round_half_up(0), "35.");
+        assert_eq!(decimal("1e3").round_half_up(1), "1000.0");
+    }
+
+    #[test]
+    fn test_round_half_even() {
+        assert_eq!(decimal("1.25").round_half_even(1), "1.2");
+        assert_eq!(decimal("1.35").round_half_even(1), "1.4");
+        assert_eq!(decimal("1.2501").round_half_even(1), "1.3");
+        assert_eq!(decimal("-0.25").round_half_even(1), "-0.2");
+        assert_eq!(decimal("1e3").round_half_even(1), "1000.0");
+    }
+
+    #[test]
+    fn test_format_grouping() {
+        let number_format = NumberFormat {
+            decimal_separator: ",".to_string(),
+            thousand_separator: ".".to_string(),
+            grouping: Some(Grouping::Single(3)),
+        };
+        assert_eq!(
+            number_format.format("-1234567.891", Some(2)),
+            "-1.234.567,89"
+        );
+        assert_eq!(number_format.format("123", Some(0)), "123");
+    }
+
+    #[test]
+    fn test_format_sequence_grouping() {
+        let number_format = NumberFormat {
+            decimal_separator: ".".to_string(),
+            thousand_separator: ",".to_string(),
+            grouping: Some(Grouping::Sequence(vec![3, 2, 0])),
+        };
+        assert_eq!(number_format.format("123456789", None), "12,34,56,789");
+    }
+
+    #[test]
+    fn test_format_pads_decimals() {
+        let number_format = NumberFormat {
+            decimal_separator: ".".to_string(),
+            thousand_separator: String::new(),
+            grouping: None,
+        };
+        assert_eq!(number_format.format("1.5", Some(3)), "1.500");
+        assert_eq!(number_format.format("7.", Some(0)), "7");
+    }
+}
@@ -0,0 +1,362 @@
+//! Python's printf-style `%` formatting for the `stringformat` filter.
+//!
+//! Only a single specifier with one of the common `s`, `d`, `i`, `u`, `f`,
+//! `F`, `x`, `X` or `o` conversions is handled here. Everything else is left
+//! to Python.
+
+use num_bigint::{BigInt, ToBigInt};
+use num_traits::{Signed, ToPrimitive};
+
+/// Larger widths and precisions are left to Python, which may refuse them.
+const MAX_WIDTH: usize = 9999;
+
+/// Python refuses to convert integers with more digits to decimal strings.
+const MAX_INT_DIGITS: usize = 4300;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Conversion {
+    Str,
+    Int,
+    Float { upper: bool },
+    Hex { upper: bool },
+    Octal,
+}
+
+/// A value to format.
+pub enum Value<'a> {
+    Str(&'a str),
+    Int(BigInt),
+    Float(f64),
+    Bool(bool),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct FormatSpec {
+    left_adjust: bool,
+    zero_pad: bool,
+    plus_sign: bool,
+    space_sign: bool,
+    alternate: bool,
+    width: usize,
+    precision: Option<usize>,
+    conversion: Conversion,
+}
+
+/// Parse an optional decimal number at the start of `bytes`.
+fn parse_number(bytes: &[u8]) -> Option<(usize, &[u8])> {
+    let end = bytes
+        .iter()
+        .position(|byte| !byte.is_ascii_digit())
+        .unwrap_or(bytes.len());
+    let number = match end {
+        0 => 0,
+        end => std::str::from_utf8(&bytes[..end]).ok()?.parse().ok()?,
+    };
+    match number > MAX_WIDTH {
+        true => None,
+        false => Some((number, &bytes[end..])),
+    }
+}
+
+impl FormatSpec {
+    /// Parse a conversion specifier without its leading `%`.
+    ///
+    /// Returns `None` unless `spec` is exactly one supported specifier.
+    pub fn parse(spec: &str) -> Option<Self> {
+        let mut format_spec = Self {
+            left_adjust: false,
+            zero_pad: false,
+            plus_sign: false,
+            space_sign: false,
+            alternate: false,
+            width: 0,
+            precision: None,
+            conversion: Conversion::Str,
+        };
+        let mut rest = spec.as_bytes();
+        while let Some((flag, remaining)) = rest.split_first() {
+            match flag {
+                b'-' => format_spec.left_adjust = true,
+                b'0' => format_spec.zero_pad = true,
+                b'+' => format_spec.plus_sign = true,
+                b' ' => format_spec.space_sign = true,
+                b'#' => format_spec.alternate = true,
+                _ => break,
+            }
+            rest = remaining;
+        }
+        (format_spec.width, rest) = parse_number(rest)?;
+        if let Some(remaining) = rest.strip_prefix(b".") {
+            let (precision, remaining) = parse_number(remaining)?;
+            format_spec.precision = Some(precision);
+            rest = remaining;
+        }
+        format_spec.conversion = match rest {
+            b"s" => Conversion::Str,
+            b"d" | b"i" | b"u" => Conversion::Int,
+            b"f" => Conversion::Float { upper: false },
+            b"F" => Conversion::Float { upper: true },
+            b"x" => Conversion::Hex { upper: false },
+            b"X" => Conversion::Hex { upper: true },
+            b"o" => Conversion::Octal,
+            _ => return None,
+        };
+        Some(format_spec)
+    }
+
+    /// Format `value` like Python's `%` operator.
+    ///
+    /// Returns `None` if Python would raise, or the value is one this
+    /// module doesn't handle.
+    pub fn format(&self, value: Value<'_>) -> Option<String> {
+        match self.conversion {
+            Conversion::Str => {
+                let text = match value {
+                    Value::Str(text) => text.to_string(),
+                    Value::Int(int) => decimal_digits(&int)?,
+                    Value::Bool(true) => "True".to_string(),
+                    Value::Bool(false) => "False".to_string(),
+                    // Python's `repr` of floats is not ported.
+                    Value::Float(_) => return None,
+                };
+                Some(self.format_text(&text))
+            }
+            Conversion::Int => {
+                let int = match value {
+                    Value::Int(int) => int,
+                    Value::Bool(bool) => BigInt::from(bool as u8),
+                    Value::Float(float) => float.trunc().to_bigint()?,
+                    Value::Str(_) => return None,
+                };
+                let digits = decimal_digits(&int.abs())?;
+                Some(self.format_integer(int.is_negative(), "", digits))
+            }
+            Conversion::Hex { upper } => {
+                let int = integer(value)?;
+                let mut digits = int.abs().to_str_radix(16);
+                let prefix = match (self.alternate, upper) {
+                    (false, _) => "",
+                    (true, false) => "0x",
+                    (true, true) => "0X",
+                };
+                if upper {
+                    digits.make_ascii_uppercase();
+                }
+                Some(self.format_integer(int.is_negative(), prefix, digits))
+            }
+            Conversion::Octal => {
+                let int = integer(value)?;
+                let prefix = match self.alternate {
+                    true => "0o",
+                    false => "",
+                };
+                Some(self.format_integer(int.is_negative(), prefix, int.abs().to_str_radix(8)))
+            }
+            Conversion::Float { upper } => {
+                let float = match value {
+                    Value::Float(float) => float,
+                    Value::Int(int) => int.to_f64().filter(|float| float.is_finite())?,
+                    Value::Bool(bool) => bool as u8 as f64,
+                    Value::Str(_) => return None,
+                };
+                Some(self.format_float(float, upper))
+            }
+        }
+    }
+
+    fn format_text(&self, text: &str) -> String {
+        let text = match self.precision {
+            Some(precision) => text.chars().take(precision).collect(),
+            None => text.to_string(),
+        };
+        let padding = " ".repeat(self.width.saturating_sub(text.chars().count()));
+        match self.left_adjust {
+            true => text + &padding,
+            false => padding + &text,
+        }
+    }
+
+    fn format_integer(&self, negative: bool, prefix: &str, mut digits: String) -> String {
+        if let Some(precision) = self.precision
+            && digits.len() < precision
+        {
+            digits.insert_str(0, &"0".repeat(precision - digits.len()));
+        }
+        self.format_number(negative, prefix, &digits)
+    }
+
+    fn format_float(&self, float: f64, upper: bool) -> String {
+        let precision = self.precision.unwrap_or(6);
+        let mut digits = match float {
+            float if float.is_nan() => "nan".to_string(),
+            float if float.is_infinite() => "inf".to_string(),
+            float => {
+                let mut digits = format!("{:.precision$}", float.abs());
+                if self.alternate && precision == 0 {
+                    digits.push('.');
+                }
+                digits
+            }
+        };
+        if upper {
+            digits.make_ascii_uppercase();
+        }
+        // Python never shows the sign of a NaN.
+        let negative = float.is_sign_negative() && !float.is_nan();
+        self.format_number(negative, "", &digits)
+    }
+
+    /// Add the sign, prefix and padding to the digits of a number.
+    fn format_number(&self, negative: bool, prefix: &str, digits: &str) -> String {
+        let sign = match negative {
+            true => "-",
+            false if self.plus_sign => "+",
+            false if self.space_sign => " ",
+            false => "",
+        };
+        let padding = self
+            .width
+            .saturating_sub(sign.len() + prefix.len() + digits.len());
+        match (self.left_adjust, self.zero_pad) {
+            (true, _) => format!("{sign}{prefix}{digits}{}", " ".repeat(padding)),
+            (false, true) => format!("{sign}{prefix}{}{digits}", "0".repeat(padding)),
+            (false, false) => format!("{}{sign}{prefix}{digits}", " ".repeat(padding)),
+        }
+    }
+}
+
+/// The value of an integer for the `x`, `X` and `o` conversions.
+fn integer(value: Value<'_>) -> Option<BigInt> {
+    match value {
+        Value::Int(int) => Some(int),
+        Value::Bool(bool) => Some(BigInt::from(bool as u8)),
+        Value::Float(_) | Value::Str(_) => None,
+    }
+}
+
+fn decimal_digits(int: &BigInt) -> Option<String> {
+    let digits = int.to_string();
+    match digits.trim_start_matches('-').len() > MAX_INT_DIGITS {
+        true => None,
+        false => Some(digits),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pyo3::prelude::*;
+    use quickcheck::{Arbitrary, Gen, TestResult, quickcheck};
+
+    fn format(spec: &str, value: Value<'_>) -> Option<String> {
+        FormatSpec::parse(spec)?.format(value)
+    }
+
+    #[test]
+    fn test_parse_unsupported() {
+        for spec in [
+            "", "%", "r", "5", ".2", "ld", "*d", "s ", "5.2.1f", "10000s",
+        ] {
+            assert_eq!(FormatSpec::parse(spec), None, "{spec}");
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format("5s", Value::Str("abc")).unwrap(), "  abc");
+        assert_eq!(format("-5.2s", Value::Str("abc")).unwrap(), "ab   ");
+        assert_eq!(
+            format("05.3d", Value::Int(BigInt::from(-5))).unwrap(),
+            "-0005"
+        );
+        assert_eq!(format("+d", Value::Float(2.9)).unwrap(), "+2");
+        assert_eq!(
+            format("#06x", Value::Int(BigInt::from(31))).unwrap(),
+            "0x001f"
+        );
+        assert_eq!(format("#o", Value::Bool(true)).unwrap(), "0o1");
+        assert_eq!(format(".2f", Value::Float(0.125)).unwrap(), "0.12");
+        assert_eq!(format("#.0f", Value::Int(BigInt::from(2))).unwrap(), "2.");
+        assert_eq!(
+            format("05F", Value::Float(f64::NEG_INFINITY)).unwrap(),
+            "-0INF"
+        );
+    }
+
+    #[test]
+    fn test_format_unsupported() {
+        assert_eq!(format("s", Value::Float(1.0)), None);
+        assert_eq!(format("d", Value::Str("1")), None);
+        assert_eq!(format("x", Value::Float(1.0)), None);
+        assert_eq!(format("d", Value::Float(f64::NAN)), None);
+    }
+
+    #[derive(Clone, Debug)]
+    struct Spec(String);
+
+    impl Arbitrary for Spec {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let mut spec = String::new();
+            for _ in 0..usize::arbitrary(g) % 4 {
+                spec.push(*g.choose(&['-', '0', '+', ' ', '#']).unwrap());
+            }
+            if bool::arbitrary(g) {
+                spec.push_str(&(u8::arbitrary(g) % 20).to_string());
+            }
+            if bool::arbitrary(g) {
+                spec.push('.');
+                if bool::arbitrary(g) {
+                    spec.push_str(&(u8::arbitrary(g) % 20).to_string());
+                }
+            }
+            spec.push(
+                *g.choose(&['s', 'd', 'i', 'u', 'f', 'F', 'x', 'X', 'o'])
+                    .unwrap(),
+            );
+            Self(spec)
+        }
+    }
+
+    #[test]
+    fn test_format_matches_python() {
+        Python::initialize();
+
+        fn matches(spec: Spec, int: i64, float: f64, text: String, bool: bool) -> TestResult {
+            Python::attach(|py| {
+                let python_format = py
+                    .eval(c"lambda spec, value: ('%' + spec) % value", None, None)
+                    .unwrap();
+                let python = |value: Bound<'_, PyAny>| {
+                    python_format
+                        .call1((&spec.0, value))
+                        .and_then(|formatted| formatted.extract::<String>())
+                        .ok()
+                };
+                let spec = FormatSpec::parse(&spec.0).unwrap();
+                let mut results = vec![
+                    (
+                        spec.format(Value::Int(BigInt::from(int))),
+                        python(int.into_pyobject(py).unwrap().into_any()),
+                    ),
+                    (
+                        spec.format(Value::Float(float)),
+                        python(float.into_pyobject(py).unwrap().into_any()),
+                    ),
+                    (
+                        spec.format(Value::Str(&text)),
+                        python(text.as_str().into_pyobject(py).unwrap().into_any()),
+                    ),
+                    (
+                        spec.format(Value::Bool(bool)),
+                        python(bool.into_pyobject(py).unwrap().to_owned().into_any()),
+                    ),
+                ];
+                // Values left to Python aren't compared.
+                results.retain(|(ours, _)| ours.is_some());
+                TestResult::from_bool(results.iter().all(|(ours, python)| ours == python))
+            })
+        }
+        quickcheck(matches as fn(Spec, i64, f64, String, bool) -> TestResult)
+    }
+}
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::collections::VecDeque;
 use std::sync::Arc;
 
+use md5::{Digest, Md5};
 use num_bigint::{BigInt, Sign};
 use num_traits::cast::ToPrimitive;
 use pyo3::exceptions::PyAttributeError;
@@ -12,8 +13,10 @@ use pyo3::types::{PyBool, PyDict, PyList, PyNone, PyString, PyTuple};
 use super::types::{AsBorrowedContent, Content, Context, PyContext};
 use super::{Evaluate, Render, RenderResult, Resolve, ResolveFailures, ResolveResult};
 use crate::error::{AnnotatePyErr, PyRenderError, RenderError};
-use crate::parse::{For, IfCondition, SimpleTag, Tag, Url};
-use crate::template::django_rusty_templates::NoReverseMatch;
+use crate::parse::{Cache, For, IfCondition, SimpleTag, Tag, Url};
+use crate::template::django_rusty_templates::{
+    InvalidCacheBackendError, NoReverseMatch, TemplateSyntaxError,
+};
 use crate::types::TemplateString;
 use crate::utils::PyResultMethods;
 
@@ -46,9 +49,14 @@ impl Resolve for Url {
         py: Python<'py>,
         template: TemplateString<'t>,
         context: &mut Context,
-        failures: ResolveFailures,
+        _failures: ResolveFailures,
     ) -> ResolveResult<'t, 'py> {
-        let view_name = match self.view_name.resolve(py, template, context, failures)? {
+        let view_name = match self.view_name.resolve_or_invalid(
+            py,
+            template,
+            context,
+            ResolveFailures::Raise,
+        )? {
             Some(view_name) => view_name,
             None => "".as_content(),
         };
@@ -59,7 +67,12 @@ impl Resolve for Url {
         let url = if self.kwargs.is_empty() {
             let py_args = PyList::empty(py);
             for arg in &self.args {
-                py_args.append(arg.resolve(py, template, context, failures)?)?;
+                py_args.append(arg.resolve_or_invalid(
+                    py,
+                    template,
+                    context,
+                    ResolveFailures::Raise,
+                )?)?;
             }
             reverse.call1((
                 view_name,
@@ -71,7 +84,10 @@ impl Resolve for Url {
         } else {
             let kwargs = PyDict::new(py);
             for (key, value) in &self.kwargs {
-                kwargs.set_item(key, value.resolve(py, template, context, failures)?)?;
+                kwargs.set_item(
+                    key,
+                    value.resolve_or_invalid(py, template, context, ResolveFailures::Raise)?,
+                )?;
             }
             reverse.call1((view_name, py.None(), py.None(), kwargs, current_app))
         };
@@ -650,14 +666,178 @@ impl Render for Tag {
                     falsey.render(py, template, context)?
                 }
             }
+            Self::Cache(cache) => cache.render(py, template, context)?,
             Self::For(for_tag) => for_tag.render(py, template, context)?,
+            Self::GetCurrentTimezone { variable } => {
+                let timezone = py.import("django.utils.timezone")?;
+                let name = timezone.call_method0("get_current_timezone_name")?;
+                context.insert(variable.clone(), name);
+                Cow::Borrowed("")
+            }
             Self::Load => Cow::Borrowed(""),
+            Self::Localize { enabled, nodes } => {
+                let use_l10n = context.use_l10n.replace(*enabled);
+                let rendered = nodes.render(py, template, context);
+                context.use_l10n = use_l10n;
+                rendered?
+            }
+            Self::LocalTime { enabled, nodes } => {
+                let use_tz = context.use_tz.replace(*enabled);
+                let rendered = nodes.render(py, template, context);
+                context.use_tz = use_tz;
+                rendered?
+            }
             Self::SimpleTag(simple_tag) => simple_tag.render(py, template, context)?,
+            Self::Timezone { timezone, nodes } => {
+                let timezone = match timezone.resolve_or_invalid(
+                    py,
+                    template,
+                    context,
+                    ResolveFailures::Raise,
+                )? {
+                    Some(timezone) => timezone.to_py(py)?,
+                    None => PyString::new(py, "").into_any(),
+                };
+                let django_timezone = py.import("django.utils.timezone")?;
+                let timezone_override = django_timezone.call_method1("override", (timezone,))?;
+                timezone_override.call_method0("__enter__")?;
+                let rendered = nodes.render(py, template, context);
+                let none = py.None();
+                let exited = timezone_override.call_method1("__exit__", (&none, &none, &none));
+                // Report a render error before any error restoring the timezone.
+                let rendered = rendered?;
+                exited?;
+                rendered
+            }
             Self::Url(url) => url.render(py, template, context)?,
         })
     }
 }
 
+/// Build the same cache key as Django's `make_template_fragment_key`.
+fn make_template_fragment_key(fragment_name: &str, vary_on: &[String]) -> String {
+    let mut hasher = Md5::new();
+    for arg in vary_on {
+        hasher.update(arg.as_bytes());
+        hasher.update(b":");
+    }
+    let digest: String = hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    format!("template.cache.{fragment_name}.{digest}")
+}
+
+impl Cache {
+    fn fragment_cache<'py>(
+        &self,
+        py: Python<'py>,
+        template: TemplateString<'_>,
+        context: &mut Context,
+    ) -> Result<Bound<'py, PyAny>, PyRenderError> {
+        let caches = py.import("django.core.cache")?.getattr("caches")?;
+        let (cache_name, at) = match &self.cache_name {
+            Some((cache_name, at)) => (cache_name, at),
+            None => {
+                return match caches
+                    .get_item("template_fragments")
+                    .ok_or_isinstance_of::<InvalidCacheBackendError>(py)?
+                {
+                    Ok(fragment_cache) => Ok(fragment_cache),
+                    Err(_) => Ok(caches.get_item("default")?),
+                };
+            }
+        };
+        let cache_name = match cache_name.resolve_or_invalid(
+            py,
+            template,
+            context,
+            ResolveFailures::IgnoreVariableDoesNotExist,
+        )? {
+            Some(cache_name) => cache_name.to_py(py)?,
+            None => PyString::new(py, "").into_any(),
+        };
+        match caches
+            .get_item(&cache_name)
+            .ok_or_isinstance_of::<InvalidCacheBackendError>(py)?
+        {
+            Ok(fragment_cache) => Ok(fragment_cache),
+            Err(_) => {
+                let error = TemplateSyntaxError::new_err(format!(
+                    "Invalid cache name specified for cache tag: {}",
+                    cache_name.repr()?
+                ));
+                Err(error.annotate(py, *at, "here", template).into())
+            }
+        }
+    }
+
+    fn expire_time<'py>(
+        &self,
+        py: Python<'py>,
+        template: TemplateString<'_>,
+        context: &mut Context,
+    ) -> Result<Option<BigInt>, PyRenderError> {
+        let expire_time = match self.expire_time.resolve_or_invalid(
+            py,
+            template,
+            context,
+            ResolveFailures::IgnoreVariableDoesNotExist,
+        )? {
+            Some(Content::Py(expire_time)) if expire_time.is_none() => return Ok(None),
+            Some(expire_time) => expire_time,
+            None => "".as_content(),
+        };
+        match expire_time.to_bigint() {
+            Some(expire_time) => Ok(Some(expire_time)),
+            None => {
+                let error = TemplateSyntaxError::new_err(format!(
+                    "\"cache\" tag got a non-integer timeout value: {}",
+                    expire_time.to_py(py)?.repr()?
+                ));
+                Err(error
+                    .annotate(py, self.expire_time_at, "here", template)
+                    .into())
+            }
+        }
+    }
+}
+
+impl Render for Cache {
+    fn render<'t>(
+        &self,
+        py: Python<'_>,
+        template: TemplateString<'t>,
+        context: &mut Context,
+    ) -> RenderResult<'t> {
+        let expire_time = self.expire_time(py, template, context)?;
+        let fragment_cache = self.fragment_cache(py, template, context)?;
+        let mut vary_on = Vec::with_capacity(self.vary_on.len());
+        for arg in &self.vary_on {
+            let arg = match arg.resolve_or_invalid(
+                py,
+                template,
+                context,
+                ResolveFailures::IgnoreVariableDoesNotExist,
+            )? {
+                Some(Content::String(arg)) => arg.into_raw().into_owned(),
+                Some(arg) => arg.to_py(py)?.str()?.extract()?,
+                None => String::new(),
+            };
+            vary_on.push(arg);
+        }
+        let key = make_template_fragment_key(&self.fragment_name, &vary_on);
+        let value = fragment_cache.call_method1("get", (&key,))?;
+        if !value.is_none() {
+            return Ok(Cow::Owned(value.extract()?));
+        }
+        let value = self.nodes.render(py, template, context)?;
+        fragment_cache.call_method1("set", (key, &value, expire_time))?;
+        Ok(value)
+    }
+}
+
 impl For {
     fn render_python<'t>(
         &self,
@@ -746,15 +926,15 @@ impl Render for For {
         template: TemplateString<'t>,
         context: &mut Context,
     ) -> RenderResult<'t> {
-        let iterable =
-            match self
-                .iterable
-                .iterable
-                .resolve(py, template, context, ResolveFailures::Raise)?
-            {
-                Some(iterable) => iterable,
-                None => return self.empty.render(py, template, context),
-            };
+        let iterable = match self.iterable.iterable.resolve(
+            py,
+            template,
+            context,
+            ResolveFailures::IgnoreVariableDoesNotExist,
+        )? {
+            Some(iterable) => iterable,
+            None => return self.empty.render(py, template, context),
+        };
         match iterable {
             Content::Py(iterable) => self.render_python(&iterable, py, template, context),
             Content::String(s) => self.render_string(s.as_raw(), py, template, context),
@@ -793,14 +973,14 @@ impl Render for SimpleTag {
     ) -> RenderResult<'t> {
         let mut args = VecDeque::new();
         for arg in &self.args {
-            match arg.resolve(py, template, context, ResolveFailures::Raise)? {
+            match arg.resolve_or_invalid(py, template, context, ResolveFailures::Raise)? {
                 None => return Ok(Cow::Borrowed("")),
                 Some(arg) => args.push_back(arg.to_py(py)?),
             }
         }
         let kwargs = PyDict::new(py);
         for (key, value) in &self.kwargs {
-            let value = value.resolve(py, template, context, ResolveFailures::Raise)?;
+            let value = value.resolve_or_invalid(py, template, context, ResolveFailures::Raise)?;
             kwargs.set_item(key, value)?;
         }
         if self.takes_context {
@@ -848,3 +1028,31 @@ impl Render for SimpleTag {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_make_template_fragment_key_without_vary_on() {
+        let key = make_template_fragment_key("a.fragment", &[]);
+        assert_eq!(
+            key,
+            "template.cache.a.fragment.d41d8cd98f00b204e9800998ecf8427e"
+        );
+    }
+
+    #[test]
+    fn test_make_template_fragment_key_with_many_vary_on() {
+        let vary_on = ["abc".to_string(), "def".to_string()];
+        let key = make_template_fragment_key("bar", &vary_on);
+        assert_eq!(key, "template.cache.bar.17c1a507a0cb58384f4c639067a93520");
+    }
+
+    #[test]
+    fn test_make_template_fragment_key_proper_escaping() {
+        let vary_on = ["abc:def%".to_string()];
+        let key = make_template_fragment_key("spam", &vary_on);
+        assert_eq!(key, "template.cache.spam.06c8ae8e8c430b69fb0a6443504153dc");
+    }
+}
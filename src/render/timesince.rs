@@ -0,0 +1,267 @@
+//! A port of `django.utils.timesince`.
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{
+    PyDate, PyDateAccess, PyDateTime, PyDict, PyTimeAccess, PyTzInfo, PyTzInfoAccess,
+};
+
+use crate::render::dateformat::Date;
+use crate::render::translation::gettext;
+
+const TIME_STRINGS: [(&str, &str); 6] = [
+    ("%(num)d year", "%(num)d years"),
+    ("%(num)d month", "%(num)d months"),
+    ("%(num)d week", "%(num)d weeks"),
+    ("%(num)d day", "%(num)d days"),
+    ("%(num)d hour", "%(num)d hours"),
+    ("%(num)d minute", "%(num)d minutes"),
+];
+
+const TIME_CHUNKS: [i64; 4] = [
+    60 * 60 * 24 * 7, // week
+    60 * 60 * 24,     // day
+    60 * 60,          // hour
+    60,               // minute
+];
+
+const MONTHS_DAYS: [u8; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// The number of units output, as in Django's default `depth`.
+const DEPTH: usize = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct DateTime {
+    date: Date,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    microsecond: u32,
+}
+
+impl DateTime {
+    /// Extract a `datetime` or a `date` (as midnight) and its timezone.
+    fn from_py<'py>(value: &Bound<'py, PyAny>) -> Option<(Self, Option<Bound<'py, PyTzInfo>>)> {
+        if let Ok(datetime) = value.cast::<PyDateTime>() {
+            let fields = Self {
+                date: Date {
+                    year: datetime.get_year(),
+                    month: datetime.get_month(),
+                    day: datetime.get_day(),
+                },
+                hour: datetime.get_hour(),
+                minute: datetime.get_minute(),
+                second: datetime.get_second(),
+                microsecond: datetime.get_microsecond(),
+            };
+            return Some((fields, datetime.get_tzinfo()));
+        }
+        let date = value.cast::<PyDate>().ok()?;
+        let fields = Self {
+            date: Date {
+                year: date.get_year(),
+                month: date.get_month(),
+                day: date.get_day(),
+            },
+            hour: 0,
+            minute: 0,
+            second: 0,
+            microsecond: 0,
+        };
+        Some((fields, None))
+    }
+
+    fn microseconds(&self) -> i64 {
+        let seconds = self.date.ordinal() * 24 * 60 * 60
+            + self.hour as i64 * 60 * 60
+            + self.minute as i64 * 60
+            + self.second as i64;
+        seconds * 1_000_000 + self.microsecond as i64
+    }
+
+    fn time(&self) -> (u8, u8, u8, u32) {
+        (self.hour, self.minute, self.second, self.microsecond)
+    }
+}
+
+/// Split the time between `d` and `now` into years, months, weeks, days,
+/// hours and minutes.
+///
+/// Returns `None` if `d` is not before `now`.
+fn partials(d: DateTime, now: DateTime) -> Option<[i64; 6]> {
+    // Ignore microseconds.
+    let since = (now.microseconds() - d.microseconds()).div_euclid(1_000_000);
+    if since <= 0 {
+        return None;
+    }
+
+    let mut total_months =
+        (now.date.year - d.date.year) as i64 * 12 + (now.date.month as i64 - d.date.month as i64);
+    if d.date.day > now.date.day || (d.date.day == now.date.day && d.time() > now.time()) {
+        total_months -= 1;
+    }
+    let years = total_months.div_euclid(12);
+    let months = total_months.rem_euclid(12);
+
+    // Shift `d` by the years and months to find the remaining time.
+    let pivot = match (years, months) {
+        (0, 0) => d,
+        _ => {
+            let mut year = d.date.year + years as i32;
+            let mut month = d.date.month + months as u8;
+            if month > 12 {
+                month -= 12;
+                year += 1;
+            }
+            DateTime {
+                date: Date {
+                    year,
+                    month,
+                    day: MONTHS_DAYS[month as usize - 1].min(d.date.day),
+                },
+                microsecond: 0,
+                ..d
+            }
+        }
+    };
+    let mut remaining_time = (now.microseconds() - pivot.microseconds()) as f64 / 1_000_000.0;
+    let mut partials = [years, months, 0, 0, 0, 0];
+    for (partial, chunk) in partials[2..].iter_mut().zip(TIME_CHUNKS) {
+        let count = (remaining_time / chunk as f64).floor();
+        *partial = count as i64;
+        remaining_time -= chunk as f64 * count;
+    }
+    Some(partials)
+}
+
+/// Format a count with Django's translated unit names, without line breaks.
+///
+/// The unit names are looked up in the translation `context` if given.
+fn time_string(py: Python<'_>, unit: usize, count: i64, context: Option<&str>) -> PyResult<String> {
+    let (singular, plural) = TIME_STRINGS[unit];
+    let translation = py.import(intern!(py, "django.utils.translation"))?;
+    let message = match context {
+        Some(context) => translation
+            .getattr(intern!(py, "npgettext"))?
+            .call1((context, singular, plural, count))?,
+        None => translation
+            .getattr(intern!(py, "ngettext"))?
+            .call1((singular, plural, count))?,
+    };
+    let num = PyDict::new(py);
+    num.set_item(intern!(py, "num"), count)?;
+    let string = message.rem(num)?.extract::<String>()?;
+    Ok(string.replace(' ', "\u{a0}"))
+}
+
+/// Format the time between `d` and `now` (default the current time) like
+/// Django's `timesince`, or the time until `d` when `reversed` is set.
+///
+/// `naturaltime` translates the unit names with its own translation
+/// `context`.
+///
+/// Returns `None` for values not handled natively: anything but `date` and
+/// `datetime` objects, or datetimes in different timezones.
+pub fn timesince<'py>(
+    d: &Bound<'py, PyAny>,
+    now: Option<&Bound<'py, PyAny>>,
+    reversed: bool,
+    context: Option<&str>,
+) -> PyResult<Option<String>> {
+    let py = d.py();
+    let Some((d, d_tz)) = DateTime::from_py(d) else {
+        return Ok(None);
+    };
+    let (now, now_tz) = match now {
+        Some(now) => match DateTime::from_py(now) {
+            Some(now) => now,
+            None => return Ok(None),
+        },
+        None => {
+            let now = py
+                .import(intern!(py, "datetime"))?
+                .getattr(intern!(py, "datetime"))?
+                .call_method1(intern!(py, "now"), (&d_tz,))?;
+            DateTime::from_py(&now).expect("datetime.now always returns a datetime")
+        }
+    };
+    match (&d_tz, &now_tz) {
+        (None, None) => {}
+        (Some(d_tz), Some(now_tz)) if d_tz.is(now_tz) => {}
+        _ => return Ok(None),
+    }
+    let (d, now) = match reversed {
+        true => (now, d),
+        false => (d, now),
+    };
+
+    let zero = || time_string(py, 5, 0, context).map(Some);
+    let Some(partials) = partials(d, now) else {
+        return zero();
+    };
+    let Some(first) = partials.iter().position(|&partial| partial != 0) else {
+        return zero();
+    };
+    let result = partials[first..]
+        .iter()
+        .take(DEPTH)
+        .take_while(|&&partial| partial != 0)
+        .enumerate()
+        .map(|(index, &partial)| time_string(py, first + index, partial, context))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(Some(result.join(&gettext(py, ", ")?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> DateTime {
+        DateTime {
+            date: Date { year, month, day },
+            hour,
+            minute,
+            second: 0,
+            microsecond: 0,
+        }
+    }
+
+    #[test]
+    fn test_partials_minutes() {
+        let d = datetime(2007, 8, 14, 13, 46);
+        let now = datetime(2007, 8, 14, 13, 47);
+        assert_eq!(partials(d, now), Some([0, 0, 0, 0, 0, 1]));
+    }
+
+    #[test]
+    fn test_partials_mixed() {
+        let d = datetime(2007, 8, 14, 13, 46);
+        let now = datetime(2008, 10, 1, 15, 0);
+        assert_eq!(partials(d, now), Some([1, 1, 2, 3, 1, 14]));
+    }
+
+    #[test]
+    fn test_partials_leap_day() {
+        let d = datetime(2016, 2, 29, 0, 0);
+        let now = datetime(2017, 2, 28, 0, 0);
+        assert_eq!(partials(d, now), Some([0, 11, 4, 2, 0, 0]));
+    }
+
+    #[test]
+    fn test_partials_future() {
+        let d = datetime(2007, 8, 14, 13, 46);
+        assert_eq!(partials(d, d), None);
+        let now = datetime(2007, 8, 14, 13, 45);
+        assert_eq!(partials(d, now), None);
+    }
+
+    #[test]
+    fn test_partials_ignores_microseconds() {
+        let d = datetime(2007, 8, 14, 13, 46);
+        let now = DateTime {
+            microsecond: 999_999,
+            ..d
+        };
+        assert_eq!(partials(d, now), None);
+    }
+}
@@ -0,0 +1,29 @@
+//! Django's translation functions, looked up once and shared by the filters.
+
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+
+static GETTEXT: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+static PGETTEXT: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+static NGETTEXT: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+
+pub fn gettext(py: Python<'_>, message: &str) -> PyResult<String> {
+    GETTEXT
+        .import(py, "django.utils.translation", "gettext")?
+        .call1((message,))?
+        .extract()
+}
+
+pub fn pgettext(py: Python<'_>, context: &str, message: &str) -> PyResult<String> {
+    PGETTEXT
+        .import(py, "django.utils.translation", "pgettext")?
+        .call1((context, message))?
+        .extract()
+}
+
+pub fn ngettext(py: Python<'_>, singular: &str, plural: &str, number: u64) -> PyResult<String> {
+    NGETTEXT
+        .import(py, "django.utils.translation", "ngettext")?
+        .call1((singular, plural, number))?
+        .extract()
+}
@@ -0,0 +1,570 @@
+//! A port of `django.utils.text.Truncator`.
+
+use std::collections::VecDeque;
+
+use html_escape::{decode_html_entities, encode_quoted_attribute};
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::canonical_combining_class;
+
+const TRUNCATED_TEXT: &str = "%(truncated_text)s";
+
+/// Void elements never have a closing tag, from `django.utils.html.VOID_ELEMENTS`.
+const VOID_ELEMENTS: [&str; 16] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr", // Deprecated tags.
+    "frame", "spacer",
+];
+
+/// Tags whose content is not parsed as HTML.
+const CDATA_CONTENT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+fn is_combining(c: char) -> bool {
+    canonical_combining_class(c) != 0
+}
+
+/// Add the replacement to truncated text.
+///
+/// The replacement may contain `%(truncated_text)s` to place the text,
+/// otherwise it is appended unless the text already ends with it.
+fn add_truncation_text(text: &str, replacement: &str) -> String {
+    if !replacement.contains(TRUNCATED_TEXT) {
+        return match text.ends_with(replacement) {
+            true => text.to_string(),
+            false => format!("{text}{replacement}"),
+        };
+    }
+    // Apply Python's `%` formatting with a `truncated_text` key.
+    let mut output = String::with_capacity(text.len() + replacement.len());
+    let mut rest = replacement;
+    while let Some(index) = rest.find('%') {
+        output.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if let Some(after) = rest.strip_prefix(TRUNCATED_TEXT) {
+            output.push_str(text);
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("%%") {
+            output.push('%');
+            rest = after;
+        } else {
+            output.push('%');
+            rest = &rest[1..];
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/// The number of characters of text kept before adding the replacement.
+fn truncate_chars_length(length: usize, replacement: &str) -> usize {
+    let mut truncate_len = length;
+    for c in add_truncation_text("", replacement).chars() {
+        if !is_combining(c) {
+            truncate_len -= 1;
+            if truncate_len == 0 {
+                break;
+            }
+        }
+    }
+    truncate_len
+}
+
+/// Truncate text to at most `length` characters, including the replacement.
+///
+/// Combining characters do not count towards the length.
+pub fn truncate_chars(text: &str, length: usize, replacement: &str) -> String {
+    if length == 0 {
+        return String::new();
+    }
+    let text: String = text.nfc().collect();
+    let truncate_len = truncate_chars_length(length, replacement);
+    let mut s_len = 0;
+    let mut end_index = None;
+    for (index, c) in text.char_indices() {
+        if is_combining(c) {
+            continue;
+        }
+        s_len += 1;
+        if end_index.is_none() && s_len > truncate_len {
+            end_index = Some(index);
+        }
+        if s_len > length {
+            return add_truncation_text(&text[..end_index.unwrap_or_default()], replacement);
+        }
+    }
+    text
+}
+
+/// Truncate text to at most `length` whitespace separated words.
+///
+/// Runs of whitespace are collapsed to a single space, as Django does.
+pub fn truncate_words(text: &str, length: usize, replacement: &str) -> String {
+    if length == 0 {
+        return String::new();
+    }
+    let words: Vec<_> = text.split_whitespace().collect();
+    match words.len() > length {
+        true => add_truncation_text(&words[..length].join(" "), replacement),
+        false => words.join(" "),
+    }
+}
+
+/// Truncate HTML to at most `length` characters of text, closing any tags
+/// left open.
+pub fn truncate_html_chars(text: &str, length: usize, replacement: &str) -> String {
+    if length == 0 {
+        return String::new();
+    }
+    let text: String = text.nfc().collect();
+    let mode = Mode::Chars {
+        length,
+        processed_chars: 0,
+    };
+    let remaining = truncate_chars_length(length, replacement);
+    HtmlTruncator::new(&text, mode, remaining, replacement).truncate()
+}
+
+/// Truncate HTML to at most `length` words of text, closing any tags left open.
+pub fn truncate_html_words(text: &str, length: usize, replacement: &str) -> String {
+    if length == 0 {
+        return String::new();
+    }
+    HtmlTruncator::new(text, Mode::Words, length, replacement).truncate()
+}
+
+/// Split text into words like `re.split(r"(?<=\S)\s+(?=\S)", text)`.
+///
+/// Leading and trailing whitespace stays attached to the first and last words.
+fn split_words(text: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut whitespace_start = None;
+    let mut previous_is_text = false;
+    for (index, c) in text.char_indices() {
+        if c.is_whitespace() {
+            if previous_is_text {
+                whitespace_start = Some(index);
+            }
+            previous_is_text = false;
+            continue;
+        }
+        if let Some(whitespace_start) = whitespace_start.take() {
+            words.push(&text[start..whitespace_start]);
+            start = index;
+        }
+        previous_is_text = true;
+    }
+    words.push(&text[start..]);
+    words
+}
+
+enum Mode {
+    Chars {
+        length: usize,
+        processed_chars: usize,
+    },
+    Words,
+}
+
+/// Raised when the truncation point is reached, like Django's
+/// `TruncationCompleted` exception.
+struct TruncationCompleted;
+
+/// A port of Django's `TruncateHTMLParser`, including the parts of Python's
+/// `html.parser.HTMLParser` it relies on.
+struct HtmlTruncator<'a> {
+    raw: &'a str,
+    raw_len: usize,
+    mode: Mode,
+    remaining: usize,
+    replacement: &'a str,
+    /// Open tags, most recently opened first.
+    tags: VecDeque<String>,
+    output: Vec<String>,
+    output_len: usize,
+}
+
+impl<'a> HtmlTruncator<'a> {
+    fn new(raw: &'a str, mode: Mode, remaining: usize, replacement: &'a str) -> Self {
+        Self {
+            raw,
+            raw_len: raw.chars().count(),
+            mode,
+            remaining,
+            replacement,
+            tags: VecDeque::new(),
+            output: Vec::new(),
+            output_len: 0,
+        }
+    }
+
+    fn truncate(mut self) -> String {
+        if self.parse().is_err() {
+            let tags = std::mem::take(&mut self.tags);
+            for tag in tags {
+                self.push(format!("</{tag}>"));
+            }
+        }
+        self.output.concat()
+    }
+
+    fn push(&mut self, output: String) {
+        self.output_len += output.chars().count();
+        self.output.push(output);
+    }
+
+    fn handle_data(&mut self, data: &str) -> Result<(), TruncationCompleted> {
+        let (data_len, output) = match &mut self.mode {
+            Mode::Chars {
+                length,
+                processed_chars,
+            } => {
+                let data_len = data.chars().count();
+                *processed_chars += data_len;
+                if *processed_chars == *length && self.output_len + data_len == self.raw_len {
+                    self.push(data.to_string());
+                    return Err(TruncationCompleted);
+                }
+                let kept: String = data.chars().take(self.remaining).collect();
+                (data_len, encode_quoted_attribute(&kept).into_owned())
+            }
+            Mode::Words => {
+                let words = split_words(data);
+                let kept = words[..self.remaining.min(words.len())].join(" ");
+                (words.len(), encode_quoted_attribute(&kept).into_owned())
+            }
+        };
+        if self.remaining < data_len {
+            self.remaining = 0;
+            let output = add_truncation_text(&output, self.replacement);
+            self.push(output);
+            return Err(TruncationCompleted);
+        }
+        self.remaining -= data_len;
+        self.push(output);
+        Ok(())
+    }
+
+    fn handle_starttag(&mut self, tag: String, text: &str) {
+        self.push(text.to_string());
+        if !VOID_ELEMENTS.contains(&tag.as_str()) {
+            self.tags.push_front(tag);
+        }
+    }
+
+    fn handle_endtag(&mut self, tag: &str) {
+        if !VOID_ELEMENTS.contains(&tag) {
+            self.push(format!("</{tag}>"));
+            if let Some(index) = self.tags.iter().position(|open| open == tag) {
+                self.tags.remove(index);
+            }
+        }
+    }
+
+    /// Whether the text from `start` may end with an incomplete character
+    /// reference, in which case Python's parser waits for more data.
+    fn incomplete_charref(&self, start: usize) -> bool {
+        let rest = &self.raw[start..];
+        let Some(amp) = rest.rfind('&') else {
+            return false;
+        };
+        rest[amp..].chars().count() <= 34
+            && !rest[amp..].contains(|c: char| c.is_whitespace() || c == ';')
+    }
+
+    fn parse(&mut self) -> Result<(), TruncationCompleted> {
+        let raw = self.raw;
+        let mut i = 0;
+        let mut cdata_elem: Option<String> = None;
+        while i < raw.len() {
+            if let Some(elem) = &cdata_elem {
+                // Raw text until the matching end tag.
+                let Some((j, end, tag)) = find_cdata_end(&raw[i..], elem) else {
+                    return Ok(());
+                };
+                if j > 0 {
+                    self.handle_data(&raw[i..i + j])?;
+                }
+                self.handle_endtag(&tag);
+                cdata_elem = None;
+                i += end;
+                continue;
+            }
+
+            let j = match raw[i..].find('<') {
+                Some(j) => i + j,
+                None if self.incomplete_charref(i) => return Ok(()),
+                None => raw.len(),
+            };
+            if i < j {
+                self.handle_data(&decode_html_entities(&raw[i..j]))?;
+            }
+            i = j;
+            if i == raw.len() {
+                break;
+            }
+
+            let rest = &raw[i..];
+            let next = rest[1..].chars().next();
+            let k = if next.is_some_and(|c| c.is_ascii_alphabetic()) {
+                let Some((k, tag)) = parse_starttag(rest) else {
+                    return Ok(());
+                };
+                let text = &rest[..k];
+                if text.ends_with("/>") {
+                    let is_void = VOID_ELEMENTS.contains(&tag.as_str());
+                    self.handle_starttag(tag.clone(), text);
+                    if !is_void {
+                        self.handle_endtag(&tag);
+                    }
+                } else {
+                    if CDATA_CONTENT_ELEMENTS.contains(&tag.as_str()) {
+                        cdata_elem = Some(tag.clone());
+                    }
+                    self.handle_starttag(tag, text);
+                }
+                k
+            } else if rest.starts_with("</") {
+                let Some(gt) = rest.find('>') else {
+                    return Ok(());
+                };
+                if let Some(tag) = endtag_name(&rest[2..gt]) {
+                    self.handle_endtag(&tag);
+                }
+                gt + 1
+            } else if let Some(comment) = rest.strip_prefix("<!--") {
+                // Comments are dropped.
+                match comment.find("-->") {
+                    Some(end) => end + 7,
+                    None => return Ok(()),
+                }
+            } else if rest.starts_with("<?") || rest.starts_with("<!") {
+                // Processing instructions and declarations are dropped.
+                match rest.find('>') {
+                    Some(end) => end + 1,
+                    None => return Ok(()),
+                }
+            } else if next.is_some() {
+                self.handle_data("<")?;
+                1
+            } else {
+                return Ok(());
+            };
+            i += k;
+        }
+        Ok(())
+    }
+}
+
+/// Find the end of a start tag at the beginning of `text`.
+///
+/// Returns the length of the tag and its lowercase name, or `None` if the
+/// tag is incomplete.
+fn parse_starttag(text: &str) -> Option<(usize, String)> {
+    let name_end = text[1..]
+        .find(['\t', '\n', '\r', '\x0c', ' ', '/', '>', '\0'])
+        .map_or(text.len(), |end| end + 1);
+    let tag = text[1..name_end].to_lowercase();
+
+    let mut chars = text[name_end..].char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '>' => return Some((name_end + index + 1, tag)),
+            '=' => {
+                while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+                if let Some((_, quote @ ('"' | '\''))) = chars.peek().copied() {
+                    chars.next();
+                    chars.find(|&(_, c)| c == quote)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The lowercase name of an end tag from the text between `</` and `>`.
+fn endtag_name(text: &str) -> Option<String> {
+    // Whitespace is allowed around simple tag names, as in `</ p >`.
+    let trimmed = text.trim();
+    let text = match trimmed.chars().all(is_endtag_char) {
+        true => trimmed,
+        false => text,
+    };
+    if !text.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        // Empty `</>` tags and bogus comments are dropped.
+        return None;
+    }
+    let end = text
+        .find(['\t', '\n', '\r', '\x0c', ' ', '/', '\0'])
+        .unwrap_or(text.len());
+    Some(text[..end].to_lowercase())
+}
+
+fn is_endtag_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | ':' | '_')
+}
+
+/// Find the end tag closing a `script` or `style` element.
+///
+/// Returns the start and end of the end tag and the tag name.
+fn find_cdata_end(text: &str, elem: &str) -> Option<(usize, usize, String)> {
+    let lowercase = text.to_ascii_lowercase();
+    let mut offset = 0;
+    while let Some(start) = lowercase[offset..].find("</") {
+        let start = offset + start;
+        let after = lowercase[start + 2..].trim_start();
+        if let Some(after) = after.strip_prefix(elem) {
+            let after = after.trim_start();
+            if after.starts_with('>') {
+                let end = lowercase.len() - after.len() + 1;
+                return Some((start, end, elem.to_string()));
+            }
+        }
+        offset = start + 2;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ELLIPSIS: &str = "%(truncated_text)s…";
+
+    #[test]
+    fn test_add_truncation_text() {
+        assert_eq!(add_truncation_text("abc", ELLIPSIS), "abc…");
+        assert_eq!(add_truncation_text("abc", " …"), "abc …");
+        assert_eq!(add_truncation_text("abc …", " …"), "abc …");
+        assert_eq!(
+            add_truncation_text("abc", "[%(truncated_text)s] 100%%"),
+            "[abc] 100%"
+        );
+    }
+
+    #[test]
+    fn test_truncate_chars() {
+        let text = "The quick brown fox jumped over the lazy dog.";
+        assert_eq!(truncate_chars(text, 100, ELLIPSIS), text);
+        assert_eq!(truncate_chars(text, 21, ELLIPSIS), "The quick brown fox …");
+        assert_eq!(truncate_chars(text, 1, ELLIPSIS), "…");
+        assert_eq!(truncate_chars(text, 0, ELLIPSIS), "");
+    }
+
+    #[test]
+    fn test_truncate_chars_combining() {
+        // The combining accents do not count towards the length.
+        let text = "oüoüoüoü";
+        let decomposed: String = text.nfd().collect();
+        assert_eq!(truncate_chars(&decomposed, 8, ELLIPSIS), "oüoüoüoü");
+        assert_eq!(
+            truncate_chars("o\u{301}o\u{301}o\u{301}", 2, ELLIPSIS),
+            "ó…"
+        );
+    }
+
+    #[test]
+    fn test_truncate_words() {
+        let text = "The quick  brown fox jumped over the lazy dog.";
+        assert_eq!(truncate_words(text, 3, " …"), "The quick brown …");
+        assert_eq!(
+            truncate_words(text, 10, " …"),
+            "The quick brown fox jumped over the lazy dog."
+        );
+    }
+
+    #[test]
+    fn test_split_words() {
+        assert_eq!(split_words(" a  b\nc "), [" a", "b", "c "]);
+        assert_eq!(split_words("\n"), ["\n"]);
+    }
+
+    #[test]
+    fn test_truncate_html_words() {
+        let text = "<p id=\"par\"><strong><em>The quick brown fox jumped over the lazy dog.</em></strong></p>";
+        assert_eq!(
+            truncate_html_words(text, 4, " …"),
+            "<p id=\"par\"><strong><em>The quick brown fox …</em></strong></p>"
+        );
+        assert_eq!(truncate_html_words(text, 100, " …"), text);
+    }
+
+    #[test]
+    fn test_truncate_html_words_void_elements() {
+        let text = "<br>The <hr/>quick brown fox jumped over the lazy dog.";
+        assert_eq!(
+            truncate_html_words(text, 3, " …"),
+            "<br>The <hr/>quick brown …"
+        );
+    }
+
+    #[test]
+    fn test_truncate_html_words_self_closing() {
+        let text = "<div/>The quick brown fox";
+        assert_eq!(
+            truncate_html_words(text, 2, " …"),
+            "<div/></div>The quick …"
+        );
+    }
+
+    #[test]
+    fn test_truncate_html_chars() {
+        let text = "<p id=\"par\"><strong><em>The quick brown fox jumped over the lazy dog.</em></strong></p>";
+        assert_eq!(
+            truncate_html_chars(text, 10, ELLIPSIS),
+            "<p id=\"par\"><strong><em>The quick…</em></strong></p>"
+        );
+        assert_eq!(truncate_html_chars(text, 100, ELLIPSIS), text);
+    }
+
+    #[test]
+    fn test_truncate_html_chars_exact_plain_text() {
+        assert_eq!(truncate_html_chars("abcdef", 6, ELLIPSIS), "abcdef");
+        assert_eq!(
+            truncate_html_chars("<p>abcdef</p>", 6, ELLIPSIS),
+            "<p>abcde…</p>"
+        );
+    }
+
+    #[test]
+    fn test_truncate_html_entities() {
+        let text = "<i>Buenos d&iacute;as! &#x00bf;C&oacute;mo est&aacute;?</i>";
+        assert_eq!(
+            truncate_html_words(text, 3, " …"),
+            "<i>Buenos días! ¿Cómo …</i>"
+        );
+        assert_eq!(
+            truncate_html_chars("<p>I &lt;3 python, what about you?</p>", 6, ELLIPSIS),
+            "<p>I &lt;3 …</p>"
+        );
+    }
+
+    #[test]
+    fn test_truncate_html_comments() {
+        let text = "<p>Hello <!-- a comment --> world and more</p>";
+        assert_eq!(truncate_html_words(text, 2, " …"), "<p>Hello  world …</p>");
+    }
+
+    #[test]
+    fn test_truncate_html_attributes_with_gt() {
+        let text = "<a title=\"1 > 0\">one two three</a>";
+        assert_eq!(
+            truncate_html_words(text, 2, " …"),
+            "<a title=\"1 > 0\">one two …</a>"
+        );
+    }
+
+    #[test]
+    fn test_truncate_html_incomplete_tag() {
+        assert_eq!(truncate_html_words("one two <b", 5, " …"), "one two ");
+    }
+
+    #[test]
+    fn test_truncate_html_script() {
+        let text = "<script>a < b</script> one two three";
+        assert_eq!(
+            truncate_html_words(text, 4, " …"),
+            "<script>a &lt; b</script> one …"
+        );
+    }
+}
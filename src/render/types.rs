@@ -10,10 +10,11 @@ use num_bigint::{BigInt, ToBigInt};
 use pyo3::exceptions::{PyAttributeError, PyKeyError, PyTypeError};
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::sync::MutexExt;
-use pyo3::types::{PyBool, PyDict, PyInt, PyString, PyType};
+use pyo3::sync::{MutexExt, PyOnceLock};
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyString, PyType};
 
 use crate::error::{AnnotatePyErr, PyRenderError, RenderError};
+use crate::render::numberformat::NumberFormat;
 use crate::types::TemplateString;
 use crate::utils::PyResultMethods;
 
@@ -55,7 +56,16 @@ pub struct Context {
     loops: Vec<ForLoop>,
     pub request: Option<Py<PyAny>>,
     pub autoescape: bool,
+    /// Overrides `USE_L10N` inside `{% localize %}` blocks.
+    pub use_l10n: Option<bool>,
+    /// Overrides `USE_TZ` inside `{% localtime %}` blocks.
+    pub use_tz: Option<bool>,
+    /// Rendered in place of variables that fail to resolve.
+    pub string_if_invalid: String,
     names: Vec<HashSet<String>>,
+    /// Number formats loaded during this render, keyed by `use_l10n` and
+    /// `force_grouping`.
+    number_formats: HashMap<(bool, bool), NumberFormat>,
 }
 
 impl Context {
@@ -69,8 +79,12 @@ impl Context {
             request,
             context,
             autoescape,
+            use_l10n: None,
+            use_tz: None,
+            string_if_invalid: String::new(),
             loops: Vec::new(),
             names: Vec::new(),
+            number_formats: HashMap::new(),
         }
     }
 
@@ -83,11 +97,33 @@ impl Context {
                 .map(|(k, v)| (k.clone(), v.iter().map(|v| v.clone_ref(py)).collect()))
                 .collect(),
             autoescape: self.autoescape,
+            use_l10n: self.use_l10n,
+            use_tz: self.use_tz,
+            string_if_invalid: self.string_if_invalid.clone(),
             loops: self.loops.clone(),
             names: self.names.clone(),
+            number_formats: self.number_formats.clone(),
         }
     }
 
+    /// The separators and grouping for `use_l10n` and `force_grouping`,
+    /// loaded from Django's settings once per render.
+    pub fn number_format(
+        &mut self,
+        py: Python<'_>,
+        use_l10n: bool,
+        force_grouping: bool,
+    ) -> PyResult<&NumberFormat> {
+        Ok(
+            match self.number_formats.entry((use_l10n, force_grouping)) {
+                std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                std::collections::hash_map::Entry::Vacant(entry) => {
+                    entry.insert(NumberFormat::load(py, use_l10n, force_grouping)?)
+                }
+            },
+        )
+    }
+
     pub fn get(&self, key: &str) -> Option<&Py<PyAny>> {
         self.context.get(key)?.last()
     }
@@ -372,6 +408,9 @@ impl<'t, 'py> ContentString<'t> {
     }
 }
 
+static LOCALIZE: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+static TEMPLATE_LOCALTIME: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+
 fn resolve_python<'t>(value: Bound<'_, PyAny>, context: &Context) -> PyResult<ContentString<'t>> {
     if !context.autoescape {
         return Ok(ContentString::String(
@@ -395,6 +434,28 @@ fn resolve_python<'t>(value: Bound<'_, PyAny>, context: &Context) -> PyResult<Co
     )
 }
 
+/// Python's `repr` of `float`, if it doesn't use scientific notation.
+fn python_float_repr(float: f64) -> Option<String> {
+    let abs = float.abs();
+    // Rust's `Debug` switches to scientific notation at the same thresholds.
+    match float.is_finite() && (abs == 0.0 || (1e-4..1e16).contains(&abs)) {
+        true => Some(format!("{float:?}")),
+        false => None,
+    }
+}
+
+fn localize_python<'t, 'py>(
+    value: Bound<'py, PyAny>,
+    context: &Context,
+) -> PyResult<Content<'t, 'py>> {
+    let py = value.py();
+    let template_localtime =
+        TEMPLATE_LOCALTIME.import(py, "django.utils.timezone", "template_localtime")?;
+    let value = template_localtime.call1((value, context.use_tz))?;
+    let localize = LOCALIZE.import(py, "django.utils.formats", "localize")?;
+    Ok(Content::Py(localize.call1((value, context.use_l10n))?))
+}
+
 #[derive(Debug, IntoPyObject)]
 pub enum Content<'t, 'py> {
     Py(Bound<'py, PyAny>),
@@ -416,6 +477,42 @@ impl<'t, 'py> Content<'t, 'py> {
         })
     }
 
+    /// Convert values to the active timezone and locale, as Django does when
+    /// outputting a variable.
+    pub fn localize(self, py: Python<'py>, context: &mut Context) -> PyResult<Self> {
+        // Numbers are formatted natively, as `django.utils.formats.localize`
+        // only needs the separators and grouping for them.
+        let number = match self {
+            Self::Int(ref int) => int.to_string(),
+            Self::Float(float) => match python_float_repr(float) {
+                Some(number) => number,
+                None => return localize_python(PyFloat::new(py, float).into_any(), context),
+            },
+            Self::Py(ref value) if value.is_exact_instance_of::<PyBool>() => return Ok(self),
+            Self::Py(ref value) if value.is_exact_instance_of::<PyInt>() => {
+                value.extract::<BigInt>()?.to_string()
+            }
+            Self::Py(value) if value.is_exact_instance_of::<PyFloat>() => {
+                match python_float_repr(value.extract()?) {
+                    Some(number) => number,
+                    None => return localize_python(value, context),
+                }
+            }
+            Self::Py(value) if !value.is_instance_of::<PyString>() => {
+                return localize_python(value, context);
+            }
+            content => return Ok(content),
+        };
+        let use_l10n = context.use_l10n.unwrap_or(true);
+        let number = context
+            .number_format(py, use_l10n, false)?
+            .format(&number, None);
+        Ok(Self::String(match context.autoescape {
+            false => ContentString::String(Cow::Owned(number)),
+            true => ContentString::HtmlUnsafe(Cow::Owned(number)),
+        }))
+    }
+
     pub fn resolve_string(self, context: &Context) -> PyResult<ContentString<'t>> {
         Ok(match self {
             Self::String(content) => content,
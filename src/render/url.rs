@@ -0,0 +1,103 @@
+//! Percent-encoding for the `urlencode` and `iriencode` filters.
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+/// The characters `django.utils.encoding.iri_to_uri` leaves unquoted, in
+/// addition to those `quote` never quotes.
+const IRI_SAFE: &str = "/#%[]=:;$&()+,!?*@'~";
+
+/// Characters `urllib.parse.quote` never quotes.
+fn is_always_safe(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'.' | b'-' | b'~')
+}
+
+/// Percent-encode `value` like Python's `urllib.parse.quote`.
+///
+/// The UTF-8 bytes of `value` are quoted unless they are ASCII letters,
+/// digits, `_.-~` or in `safe`. Non-ASCII characters in `safe` are ignored.
+pub fn quote<'a>(value: &'a str, safe: &str) -> Cow<'a, str> {
+    let is_safe =
+        |byte: u8| is_always_safe(byte) || (byte.is_ascii() && safe.as_bytes().contains(&byte));
+    if value.bytes().all(is_safe) {
+        return Cow::Borrowed(value);
+    }
+    let mut quoted = String::with_capacity(value.len() * 3);
+    for byte in value.bytes() {
+        match is_safe(byte) {
+            true => quoted.push(byte as char),
+            false => write!(quoted, "%{byte:02X}").expect("Writing to a String cannot fail"),
+        }
+    }
+    Cow::Owned(quoted)
+}
+
+/// Convert an IRI to a URI like `django.utils.encoding.iri_to_uri`.
+pub fn iri_to_uri(iri: &str) -> Cow<'_, str> {
+    quote(iri, IRI_SAFE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pyo3::prelude::*;
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("a b/c", "/"), "a%20b/c");
+        assert_eq!(quote("a b/c", ""), "a%20b%2Fc");
+        assert_eq!(quote("¿Sí?", "?é"), "%C2%BFS%C3%AD?");
+        assert_eq!(quote("", "/"), "");
+    }
+
+    #[test]
+    fn test_iri_to_uri() {
+        assert_eq!(
+            iri_to_uri("/blog/for/Jürgen Münster/"),
+            "/blog/for/J%C3%BCrgen%20M%C3%BCnster/"
+        );
+        assert_eq!(iri_to_uri("?a=1&b=%20"), "?a=1&b=%20");
+    }
+
+    #[test]
+    fn test_quote_matches_python_quote() {
+        Python::initialize();
+
+        fn matches(value: String, safe: String) -> bool {
+            Python::attach(|py| {
+                let parse = PyModule::import(py, "urllib.parse").unwrap();
+                let python_quote = parse.getattr("quote").unwrap();
+
+                let quoted: String = python_quote
+                    .call1((&value, &safe))
+                    .unwrap()
+                    .extract()
+                    .unwrap();
+                quoted == quote(&value, &safe)
+            })
+        }
+        quickcheck(matches as fn(String, String) -> bool)
+    }
+
+    #[test]
+    fn test_iri_to_uri_matches_django_iri_to_uri() {
+        Python::initialize();
+
+        fn matches(value: String) -> bool {
+            Python::attach(|py| {
+                let encoding = PyModule::import(py, "django.utils.encoding").unwrap();
+                let django_iri_to_uri = encoding.getattr("iri_to_uri").unwrap();
+
+                let uri: String = django_iri_to_uri
+                    .call1((&value,))
+                    .unwrap()
+                    .extract()
+                    .unwrap();
+                uri == iri_to_uri(&value)
+            })
+        }
+        quickcheck(matches as fn(String) -> bool)
+    }
+}
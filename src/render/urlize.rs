@@ -0,0 +1,394 @@
+//! A port of `django.utils.html.Urlizer`.
+//!
+//! Detection, punctuation trimming and escaping happen in Rust. Building the
+//! `href` itself is delegated to Django's `smart_urlquote`, and non-ASCII
+//! email domains to `punycode`, so IDNA and query string handling match
+//! Django exactly.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use html_escape::encode_quoted_attribute;
+use pyo3::exceptions::PyUnicodeError;
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+use regex::Regex;
+
+use crate::utils::PyResultMethods;
+
+const MAX_URL_LENGTH: usize = 2048;
+
+const TRAILING_PUNCTUATION: [char; 5] = ['.', ',', ':', ';', '!'];
+const TRAILING_PUNCTUATION_NO_SEMICOLON: [char; 4] = ['.', ',', ':', '!'];
+const WRAPPING_PUNCTUATION: [(char, char); 2] = [('(', ')'), ('[', ']')];
+
+static SIMPLE_URL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^https?://\[?\w").expect("Static string will never panic"));
+
+// Django's pattern also requires that the word doesn't start with `http`,
+// which the regex crate can't express as a lookahead.
+static SIMPLE_URL_2_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^\w[^@]+\.(com|edu|gov|int|mil|net|org)($|/.*)$")
+        .expect("Static string will never panic")
+});
+
+// Python's `\s` also matches the ASCII information separators.
+static WORD_SPLIT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"[\s\x1c-\x1f<>"']+"#).expect("Static string will never panic"));
+
+static UNESCAPE: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+static SMART_URLQUOTE: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+static PUNYCODE: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+
+/// Convert URLs and email addresses in `text` into links.
+///
+/// `autoescape` should only be set when the text is not already safe.
+pub fn urlize(
+    py: Python<'_>,
+    text: &str,
+    trim_url_limit: Option<usize>,
+    nofollow: bool,
+    autoescape: bool,
+) -> PyResult<String> {
+    let urlizer = Urlizer {
+        py,
+        trim_url_limit,
+        nofollow,
+        autoescape,
+    };
+    let mut cache = HashMap::new();
+    let mut urlized = String::with_capacity(text.len());
+    let mut start = 0;
+    for separator in WORD_SPLIT_RE.find_iter(text) {
+        urlizer.push_word(&text[start..separator.start()], &mut cache, &mut urlized)?;
+        urlizer.push_word(separator.as_str(), &mut cache, &mut urlized)?;
+        start = separator.end();
+    }
+    urlizer.push_word(&text[start..], &mut cache, &mut urlized)?;
+    Ok(urlized)
+}
+
+struct Urlizer<'py> {
+    py: Python<'py>,
+    trim_url_limit: Option<usize>,
+    nofollow: bool,
+    autoescape: bool,
+}
+
+impl Urlizer<'_> {
+    fn push_word<'a>(
+        &self,
+        word: &'a str,
+        cache: &mut HashMap<&'a str, String>,
+        urlized: &mut String,
+    ) -> PyResult<()> {
+        if !word.contains(['.', '@', ':']) {
+            urlized.push_str(&self.escape(word));
+            return Ok(());
+        }
+        // Only words that may be links are cached, since they can call into Python.
+        match cache.get(word) {
+            Some(link) => urlized.push_str(link),
+            None => {
+                let link = self.handle_word(word)?;
+                urlized.push_str(&link);
+                cache.insert(word, link);
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_word(&self, word: &str) -> PyResult<String> {
+        let (lead, middle, trail) = trim_punctuation(self.py, word)?;
+        let mut nofollow = self.nofollow;
+        let short_enough = middle.chars().count() <= MAX_URL_LENGTH;
+        let url = if short_enough && SIMPLE_URL_RE.is_match(middle) {
+            smart_urlquote(self.py, &unescape(self.py, middle)?)?
+        } else if short_enough && is_simple_url_2(middle) {
+            let url = format!("http://{}", unescape(self.py, middle)?);
+            smart_urlquote(self.py, &url)?
+        } else if !middle.contains(':') && is_email_simple(middle) {
+            let (local, domain) = middle
+                .rsplit_once('@')
+                .expect("An email address contains an @");
+            let Some(domain) = punycode(self.py, domain)? else {
+                return Ok(word.to_string());
+            };
+            nofollow = false;
+            format!("mailto:{local}@{domain}")
+        } else {
+            return Ok(self.escape(word).into_owned());
+        };
+        let trimmed = trim_url(middle, self.trim_url_limit);
+        let rel = match nofollow {
+            true => r#" rel="nofollow""#,
+            false => "",
+        };
+        Ok(format!(
+            r#"{}<a href="{}"{rel}>{}</a>{}"#,
+            self.escape(lead),
+            encode_quoted_attribute(&url),
+            self.escape(&trimmed),
+            self.escape(trail),
+        ))
+    }
+
+    fn escape<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        match self.autoescape {
+            true => encode_quoted_attribute(text),
+            false => Cow::Borrowed(text),
+        }
+    }
+}
+
+fn is_simple_url_2(middle: &str) -> bool {
+    let starts_with = |prefix: &str| {
+        middle
+            .get(..prefix.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+    };
+    starts_with("www.") || (!starts_with("http") && SIMPLE_URL_2_RE.is_match(middle))
+}
+
+/// Return whether `value` looks like an email address.
+fn is_email_simple(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    if local.is_empty() || domain.is_empty() || domain.contains('@') {
+        return false;
+    }
+    // Max length for domain name labels is 63 characters per RFC 1034.
+    if domain.chars().count() > 63 {
+        return false;
+    }
+    domain.contains('.') && !domain.starts_with('.')
+}
+
+/// Split `word` into leading punctuation, the possible link and trailing
+/// punctuation.
+fn trim_punctuation<'a>(py: Python<'_>, word: &'a str) -> PyResult<(&'a str, &'a str, &'a str)> {
+    let openings: Vec<char> = WRAPPING_PUNCTUATION.iter().map(|(open, _)| *open).collect();
+    let mut middle = word.trim_start_matches(openings.as_slice());
+    let lead = &word[..word.len() - middle.len()];
+
+    // Track bracket counts as the middle shrinks, rather than recounting.
+    let mut counts: Vec<(usize, usize)> = WRAPPING_PUNCTUATION
+        .iter()
+        .map(|(open, close)| {
+            (
+                middle.matches(*open).count(),
+                middle.matches(*close).count(),
+            )
+        })
+        .collect();
+
+    let mut trimmed_something = true;
+    while trimmed_something && !middle.is_empty() {
+        trimmed_something = false;
+        for ((_, closing), (opened, closed)) in WRAPPING_PUNCTUATION.iter().zip(counts.iter_mut()) {
+            if *opened < *closed {
+                let trailing = middle.len() - middle.trim_end_matches(*closing).len();
+                if trailing > 0 {
+                    // The closing brackets are all ASCII, so bytes are chars.
+                    let strip = trailing.min(*closed - *opened);
+                    middle = &middle[..middle.len() - strip];
+                    *closed -= strip;
+                    trimmed_something = true;
+                }
+            }
+        }
+
+        let amp = middle.rfind('&');
+        let rstripped = match amp {
+            None => middle.trim_end_matches(TRAILING_PUNCTUATION),
+            Some(_) => middle.trim_end_matches(TRAILING_PUNCTUATION_NO_SEMICOLON),
+        };
+        if rstripped.len() != middle.len() {
+            middle = rstripped;
+            trimmed_something = true;
+        }
+
+        if middle.ends_with(';') {
+            // Only strip if not part of an HTML entity.
+            let potential_entity = match amp {
+                Some(amp) => &middle[amp..],
+                None => &middle[middle.len() - 1..],
+            };
+            let unescaped = unescape(py, potential_entity)?;
+            if unescaped == potential_entity || unescaped.ends_with(';') {
+                let rstripped = middle.trim_end_matches(TRAILING_PUNCTUATION);
+                let trail_start = rstripped.len();
+                let semicolons = middle.len() - middle.trim_end_matches(';').len();
+                middle = match amp.is_some() && semicolons > 1 {
+                    // Leave up to the most recent semicolon as it might end an entity.
+                    true => {
+                        let recent_semicolon = middle[trail_start..]
+                            .find(';')
+                            .expect("The middle ends with a semicolon");
+                        &middle[..trail_start + recent_semicolon + 1]
+                    }
+                    false => rstripped,
+                };
+                trimmed_something = true;
+            }
+        }
+    }
+
+    let trail = &word[lead.len() + middle.len()..];
+    Ok((lead, middle, trail))
+}
+
+fn trim_url(url: &str, limit: Option<usize>) -> Cow<'_, str> {
+    match limit {
+        Some(limit) if url.chars().count() > limit => {
+            let truncated: String = url.chars().take(limit.saturating_sub(1)).collect();
+            Cow::Owned(truncated + "…")
+        }
+        _ => Cow::Borrowed(url),
+    }
+}
+
+fn unescape<'a>(py: Python<'_>, text: &'a str) -> PyResult<Cow<'a, str>> {
+    if !text.contains('&') {
+        return Ok(Cow::Borrowed(text));
+    }
+    let unescape = UNESCAPE.import(py, "html", "unescape")?;
+    Ok(Cow::Owned(unescape.call1((text,))?.extract()?))
+}
+
+fn smart_urlquote(py: Python<'_>, url: &str) -> PyResult<String> {
+    let smart_urlquote = SMART_URLQUOTE.import(py, "django.utils.html", "smart_urlquote")?;
+    smart_urlquote.call1((url,))?.extract()
+}
+
+/// Encode a domain name like Python's `idna` codec, returning `None` where it
+/// would raise a `UnicodeError`.
+fn punycode(py: Python<'_>, domain: &str) -> PyResult<Option<String>> {
+    if domain.is_ascii() {
+        let mut labels: Vec<&str> = domain.split('.').collect();
+        let last = labels
+            .pop()
+            .expect("split always returns at least one label");
+        let invalid = labels
+            .iter()
+            .any(|label| label.is_empty() || label.len() >= 64)
+            || last.len() >= 64;
+        return Ok(match invalid {
+            true => None,
+            false => Some(domain.to_string()),
+        });
+    }
+    let punycode = PUNYCODE.import(py, "django.utils.encoding", "punycode")?;
+    punycode
+        .call1((domain,))
+        .ok_or_isinstance_of::<PyUnicodeError>(py)?
+        .ok()
+        .map(|domain| domain.extract())
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trim(word: &str) -> (String, String, String) {
+        Python::initialize();
+        Python::attach(|py| {
+            let (lead, middle, trail) = trim_punctuation(py, word).unwrap();
+            (lead.to_string(), middle.to_string(), trail.to_string())
+        })
+    }
+
+    #[test]
+    fn test_trim_punctuation_trailing() {
+        assert_eq!(
+            trim("http://example.com/foo.),"),
+            (
+                String::new(),
+                "http://example.com/foo".to_string(),
+                ".),".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_trim_punctuation_wrapping() {
+        assert_eq!(
+            trim("([example.com])"),
+            (
+                "([".to_string(),
+                "example.com".to_string(),
+                "])".to_string()
+            )
+        );
+        assert_eq!(
+            trim("en.wikipedia.org/wiki/Django_(web_framework))"),
+            (
+                String::new(),
+                "en.wikipedia.org/wiki/Django_(web_framework)".to_string(),
+                ")".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_trim_punctuation_keeps_entities() {
+        assert_eq!(
+            trim("example.com?a=1&amp;;"),
+            (
+                String::new(),
+                "example.com?a=1&amp;".to_string(),
+                ";".to_string()
+            )
+        );
+        assert_eq!(
+            trim("example.com;;"),
+            (String::new(), "example.com".to_string(), ";;".to_string())
+        );
+    }
+
+    #[test]
+    fn test_is_email_simple() {
+        assert!(is_email_simple("me@example.com"));
+        assert!(!is_email_simple("@example.com"));
+        assert!(!is_email_simple("me@"));
+        assert!(!is_email_simple("me@example"));
+        assert!(!is_email_simple("me@.example.com"));
+        assert!(!is_email_simple("me@you@example.com"));
+    }
+
+    #[test]
+    fn test_is_simple_url_2() {
+        assert!(is_simple_url_2("www.example"));
+        assert!(is_simple_url_2("WWW.example"));
+        assert!(is_simple_url_2("djangoproject.org/"));
+        assert!(!is_simple_url_2("djangoproject.org.uk"));
+        assert!(!is_simple_url_2("httpbin.org"));
+        assert!(!is_simple_url_2("me@example.com"));
+    }
+
+    #[test]
+    fn test_trim_url() {
+        assert_eq!(trim_url("http://example.com", None), "http://example.com");
+        assert_eq!(
+            trim_url("http://example.com", Some(18)),
+            "http://example.com"
+        );
+        assert_eq!(trim_url("http://example.com", Some(8)), "http://…");
+        assert_eq!(trim_url("http://example.com", Some(0)), "…");
+    }
+
+    #[test]
+    fn test_punycode_ascii() {
+        Python::initialize();
+        Python::attach(|py| {
+            assert_eq!(
+                punycode(py, "example.com").unwrap(),
+                Some("example.com".to_string())
+            );
+            assert_eq!(punycode(py, "example..com").unwrap(), None);
+        })
+    }
+}
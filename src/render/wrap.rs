@@ -0,0 +1,178 @@
+//! A port of `django.utils.text.wrap`, including the parts of Python's
+//! `textwrap.TextWrapper` it relies on.
+
+use crate::utils::is_python_whitespace;
+
+const TAB_SIZE: usize = 8;
+
+/// Whitespace `textwrap` splits words on. Unlike `str.isspace` this is
+/// ASCII only.
+const WRAP_WHITESPACE: [char; 6] = ['\t', '\n', '\x0b', '\x0c', '\r', ' '];
+
+/// Wrap `text` to lines of at most `width` characters, preserving existing
+/// line breaks and never breaking long words.
+///
+/// `width` must be greater than zero.
+pub fn wrap(text: &str, width: usize) -> String {
+    let mut result = Vec::new();
+    for line in split_lines(text) {
+        let wrapped = wrap_line(line, width);
+        match wrapped.is_empty() {
+            // The line only contained whitespace, which was dropped.
+            true => result.push(line.to_string()),
+            false => result.extend(wrapped),
+        }
+    }
+    if text.ends_with('\n') {
+        result.push(String::new());
+    }
+    result.join("\n")
+}
+
+/// Split `text` on line boundaries like Python's `str.splitlines`.
+fn split_lines(text: &str) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        if !matches!(
+            c,
+            '\n' | '\r'
+                | '\x0b'
+                | '\x0c'
+                | '\x1c'
+                | '\x1d'
+                | '\x1e'
+                | '\u{85}'
+                | '\u{2028}'
+                | '\u{2029}'
+        ) {
+            continue;
+        }
+        lines.push(&text[start..index]);
+        start = index + c.len_utf8();
+        if c == '\r' && chars.next_if(|(_, next)| *next == '\n').is_some() {
+            start += 1;
+        }
+    }
+    if start < text.len() {
+        lines.push(&text[start..]);
+    }
+    lines
+}
+
+/// Expand tabs to spaces like Python's `str.expandtabs`.
+fn expand_tabs(line: &str) -> String {
+    let mut expanded = String::with_capacity(line.len());
+    let mut column = 0;
+    for c in line.chars() {
+        match c {
+            '\t' => {
+                let spaces = TAB_SIZE - column % TAB_SIZE;
+                expanded.extend(std::iter::repeat_n(' ', spaces));
+                column += spaces;
+            }
+            '\n' | '\r' => {
+                expanded.push(c);
+                column = 0;
+            }
+            c => {
+                expanded.push(c);
+                column += 1;
+            }
+        }
+    }
+    expanded
+}
+
+/// Split `text` into alternating runs of words and whitespace.
+fn split_chunks(text: &str) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut in_whitespace = None;
+    for (index, c) in text.char_indices() {
+        let is_whitespace = WRAP_WHITESPACE.contains(&c);
+        if in_whitespace.is_some_and(|in_whitespace| in_whitespace != is_whitespace) {
+            chunks.push(&text[start..index]);
+            start = index;
+        }
+        in_whitespace = Some(is_whitespace);
+    }
+    if start < text.len() {
+        chunks.push(&text[start..]);
+    }
+    chunks
+}
+
+fn is_blank(chunk: &str) -> bool {
+    chunk.chars().all(is_python_whitespace)
+}
+
+/// `TextWrapper.wrap` with `break_long_words`, `break_on_hyphens` and
+/// `replace_whitespace` disabled.
+fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    let expanded = expand_tabs(line);
+    let mut chunks = split_chunks(&expanded);
+    chunks.reverse();
+
+    let mut lines = Vec::new();
+    while !chunks.is_empty() {
+        let mut current_line = Vec::new();
+        let mut current_len = 0;
+
+        if !lines.is_empty() && chunks.last().is_some_and(|chunk| is_blank(chunk)) {
+            chunks.pop();
+        }
+        while let Some(chunk) = chunks.last() {
+            let len = chunk.chars().count();
+            if current_len + len > width {
+                break;
+            }
+            current_line.push(*chunk);
+            current_len += len;
+            chunks.pop();
+        }
+        // Long words are never broken, but get a line to themselves.
+        if current_line.is_empty()
+            && let Some(chunk) = chunks.pop()
+        {
+            current_line.push(chunk);
+        }
+        if current_line.last().is_some_and(|chunk| is_blank(chunk)) {
+            current_line.pop();
+        }
+        if !current_line.is_empty() {
+            lines.push(current_line.concat());
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap() {
+        let text = "this is a long paragraph of text that really needs to be wrapped I'm afraid";
+        assert_eq!(
+            wrap(text, 14),
+            "this is a long\nparagraph of\ntext that\nreally needs\nto be wrapped\nI'm afraid"
+        );
+    }
+
+    #[test]
+    fn test_wrap_preserves_indentation() {
+        let text = "this is a short paragraph of text.\n  But this line should be indented";
+        assert_eq!(
+            wrap(text, 15),
+            "this is a short\nparagraph of\ntext.\n  But this line\nshould be\nindented"
+        );
+    }
+
+    #[test]
+    fn test_wrap_whitespace_lines() {
+        assert_eq!(wrap("a\n   \n\tb\n", 3), "a\n   \nb\n");
+        assert_eq!(wrap("", 3), "");
+    }
+}
@@ -4,22 +4,29 @@ use pyo3::prelude::*;
 pub mod django_rusty_templates {
     use std::collections::HashMap;
     use std::path::PathBuf;
+    use std::sync::Arc;
 
     use encoding_rs::Encoding;
-    use pyo3::exceptions::{PyAttributeError, PyImportError, PyOverflowError, PyValueError};
+    use pyo3::exceptions::{
+        PyAttributeError, PyImportError, PyOverflowError, PyTypeError, PyValueError,
+    };
     use pyo3::import_exception_bound;
     use pyo3::intern;
     use pyo3::prelude::*;
-    use pyo3::types::{PyBool, PyDict, PyString};
+    use pyo3::sync::PyOnceLock;
+    use pyo3::types::{PyBool, PyDict, PyList, PySequence, PyString, PyTuple};
 
     use crate::error::RenderError;
-    use crate::loaders::{AppDirsLoader, CachedLoader, FileSystemLoader, Loader};
+    use crate::loaders::{
+        AppDirsLoader, CachedLoader, ExternalLoader, FileSystemLoader, Loader, LocMemLoader,
+    };
     use crate::parse::{Parser, TokenTree};
     use crate::render::Render;
     use crate::render::types::Context;
     use crate::types::TemplateString;
     use crate::utils::PyResultMethods;
 
+    import_exception_bound!(django.core.cache.backends.base, InvalidCacheBackendError);
     import_exception_bound!(django.core.exceptions, ImproperlyConfigured);
     import_exception_bound!(django.template.base, VariableDoesNotExist);
     import_exception_bound!(django.template.exceptions, TemplateDoesNotExist);
@@ -77,8 +84,27 @@ pub mod django_rusty_templates {
         }
     }
 
+    /// The engine's context processors, imported once and shared by its
+    /// templates.
+    #[derive(Debug, Clone, Default)]
+    pub struct ContextProcessors(Arc<Vec<Py<PyAny>>>);
+
+    impl PartialEq for ContextProcessors {
+        fn eq(&self, other: &Self) -> bool {
+            // Compare identities to avoid needing the `py` token.
+            self.0.len() == other.0.len()
+                && self
+                    .0
+                    .iter()
+                    .zip(other.0.iter())
+                    .all(|(left, right)| left.as_ptr() == right.as_ptr())
+        }
+    }
+
     pub struct EngineData {
         autoescape: bool,
+        string_if_invalid: String,
+        context_processors: ContextProcessors,
         libraries: HashMap<String, Py<PyAny>>,
     }
 
@@ -87,6 +113,8 @@ pub mod django_rusty_templates {
         pub fn empty() -> Self {
             Self {
                 autoescape: false,
+                string_if_invalid: String::new(),
+                context_processors: ContextProcessors::default(),
                 libraries: HashMap::new(),
             }
         }
@@ -131,12 +159,8 @@ pub mod django_rusty_templates {
         #[allow(dead_code)]
         app_dirs: bool,
         #[allow(dead_code)]
-        context_processors: Vec<String>,
-        #[allow(dead_code)]
         debug: bool,
         #[allow(dead_code)]
-        string_if_invalid: String,
-        #[allow(dead_code)]
         encoding: &'static Encoding,
         #[allow(dead_code)]
         builtins: Vec<String>,
@@ -145,13 +169,118 @@ pub mod django_rusty_templates {
     }
 
     impl Engine {
-        #[allow(dead_code)]
-        fn find_template_loader<'py>(
-            _py: Python<'py>,
-            _loader: &str,
-            _args: Option<Bound<'py, PyAny>>,
-        ) -> PyResult<Bound<'py, PyAny>> {
-            todo!()
+        /// Build the loaders configured by Django's `loaders` option.
+        fn get_template_loaders(
+            loaders: &Bound<'_, PyAny>,
+            dirs: &[PathBuf],
+            encoding: &'static Encoding,
+            engine: &Arc<DjangoEngine>,
+        ) -> PyResult<Vec<Loader>> {
+            loaders
+                .try_iter()?
+                .map(|loader| Self::find_template_loader(&loader?, dirs, encoding, engine))
+                .collect()
+        }
+
+        /// Build a loader from a dotted path or a `(path, *args)` sequence,
+        /// using the native loader for Django's own loaders.
+        fn find_template_loader(
+            loader: &Bound<'_, PyAny>,
+            dirs: &[PathBuf],
+            encoding: &'static Encoding,
+            engine: &Arc<DjangoEngine>,
+        ) -> PyResult<Loader> {
+            let py = loader.py();
+            let (path, args) =
+                match loader.is_instance_of::<PyTuple>() || loader.is_instance_of::<PyList>() {
+                    true => {
+                        let loader = loader.cast::<PySequence>()?;
+                        (
+                            loader.get_item(0)?,
+                            loader.get_slice(1, usize::MAX)?.to_tuple()?,
+                        )
+                    }
+                    false => (loader.clone(), PyTuple::empty(py)),
+                };
+            let Ok(path) = path.extract::<String>() else {
+                return Err(ImproperlyConfigured::new_err(format!(
+                    "Invalid value in template loaders configuration: {}",
+                    path.repr()?
+                )));
+            };
+            let required_arg = |name: &str| match args.get_item(0) {
+                Ok(arg) => Ok(arg),
+                Err(_) => Err(PyTypeError::new_err(format!(
+                    "Loader.__init__() missing 1 required positional argument: '{name}'"
+                ))),
+            };
+            Ok(match path.as_str() {
+                "django.template.loaders.filesystem.Loader" => {
+                    let dirs = match args.get_item(0) {
+                        Ok(loader_dirs) if !loader_dirs.is_none() => loader_dirs.extract()?,
+                        _ => dirs.to_vec(),
+                    };
+                    let loader = OriginLoader::django(path, args.unbind(), engine.clone());
+                    Loader::FileSystem(
+                        FileSystemLoader::new(dirs, encoding).with_origin_loader(loader),
+                    )
+                }
+                "django.template.loaders.app_directories.Loader" => {
+                    let loader = OriginLoader::django(path, args.unbind(), engine.clone());
+                    Loader::AppDirs(AppDirsLoader::new(encoding).with_origin_loader(loader))
+                }
+                "django.template.loaders.cached.Loader" => {
+                    let loaders = required_arg("loaders")?;
+                    let loaders = Self::get_template_loaders(&loaders, dirs, encoding, engine)?;
+                    Loader::Cached(CachedLoader::new(loaders))
+                }
+                "django.template.loaders.locmem.Loader" => {
+                    let templates = required_arg("templates_dict")?.extract()?;
+                    let loader = OriginLoader::django(path, args.unbind(), engine.clone());
+                    Loader::LocMem(LocMemLoader::new(templates).with_origin_loader(loader))
+                }
+                _ => {
+                    let loader = import_loader(&path, &args, engine.get(py)?)?;
+                    Loader::External(ExternalLoader::new(loader.unbind()))
+                }
+            })
+        }
+    }
+
+    /// Create the Python loader for `path`, as Django's `Engine` does.
+    fn import_loader<'py>(
+        path: &str,
+        args: &Bound<'py, PyTuple>,
+        engine: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = engine.py();
+        let import_string = py
+            .import(intern!(py, "django.utils.module_loading"))?
+            .getattr(intern!(py, "import_string"))?;
+        let loader_class = import_string.call1((path,))?;
+        let mut loader_args = vec![engine.clone()];
+        loader_args.extend(args);
+        loader_class.call1(PyTuple::new(py, loader_args)?)
+    }
+
+    /// The Django `Engine` given to Python loaders, built from this engine's
+    /// options the first time a loader needs it. Python loaders can't be
+    /// given our `Engine`, which is mutably borrowed while they load.
+    struct DjangoEngine {
+        options: Py<PyDict>,
+        engine: PyOnceLock<Py<PyAny>>,
+    }
+
+    impl DjangoEngine {
+        fn get<'py>(&self, py: Python<'py>) -> PyResult<&Bound<'py, PyAny>> {
+            let engine = self.engine.get_or_try_init(py, || -> PyResult<_> {
+                Ok(py
+                    .import(intern!(py, "django.template.engine"))?
+                    .getattr(intern!(py, "Engine"))?
+                    .call((), Some(self.options.bind(py)))?
+                    .unbind())
+            })?;
+            Ok(engine.bind(py))
         }
     }
 
@@ -161,7 +290,7 @@ pub mod django_rusty_templates {
         #[pyo3(signature = (dirs=None, app_dirs=false, context_processors=None, debug=false, loaders=None, string_if_invalid="".to_string(), file_charset="utf-8".to_string(), libraries=None, builtins=None, autoescape=true))]
         #[allow(clippy::too_many_arguments)] // We're matching Django's Engine __init__ signature
         pub fn new(
-            _py: Python<'_>,
+            py: Python<'_>,
             dirs: Option<Bound<'_, PyAny>>,
             app_dirs: bool,
             context_processors: Option<Bound<'_, PyAny>>,
@@ -170,21 +299,44 @@ pub mod django_rusty_templates {
             string_if_invalid: String,
             file_charset: String,
             libraries: Option<Bound<'_, PyAny>>,
-            #[allow(unused_variables)] builtins: Option<Bound<'_, PyAny>>,
+            builtins: Option<Bound<'_, PyAny>>,
             autoescape: bool,
         ) -> PyResult<Self> {
-            let dirs = match dirs {
-                Some(dirs) => dirs.extract()?,
-                None => Vec::new(),
-            };
-            let context_processors = match context_processors {
-                Some(context_processors) => context_processors.extract()?,
-                None => Vec::new(),
-            };
+            // Django always runs its CSRF context processor first.
+            let mut processors = vec!["django.template.context_processors.csrf".to_string()];
+            if let Some(context_processors) = &context_processors {
+                processors.extend(context_processors.extract::<Vec<String>>()?);
+            }
+            let import_string = py
+                .import(intern!(py, "django.utils.module_loading"))?
+                .getattr(intern!(py, "import_string"))?;
+            let processors = processors
+                .iter()
+                .map(|path| Ok(import_string.call1((path,))?.unbind()))
+                .collect::<PyResult<Vec<_>>>()?;
             let encoding = match Encoding::for_label(file_charset.as_bytes()) {
                 Some(encoding) => encoding,
                 None => todo!(),
             };
+            let options = PyDict::new(py);
+            options.set_item("dirs", &dirs)?;
+            options.set_item("app_dirs", app_dirs)?;
+            options.set_item("context_processors", &context_processors)?;
+            options.set_item("debug", debug)?;
+            options.set_item("loaders", &loaders)?;
+            options.set_item("string_if_invalid", &string_if_invalid)?;
+            options.set_item("file_charset", &file_charset)?;
+            options.set_item("libraries", &libraries)?;
+            options.set_item("builtins", &builtins)?;
+            options.set_item("autoescape", autoescape)?;
+            let engine = Arc::new(DjangoEngine {
+                options: options.unbind(),
+                engine: PyOnceLock::new(),
+            });
+            let dirs: Vec<PathBuf> = match dirs {
+                Some(dirs) => dirs.extract()?,
+                None => Vec::new(),
+            };
             let template_loaders = match loaders {
                 Some(_) if app_dirs => {
                     let err = ImproperlyConfigured::new_err(
@@ -192,16 +344,14 @@ pub mod django_rusty_templates {
                     );
                     return Err(err);
                 }
-                Some(_loaders) => todo!(),
+                Some(loaders) => Self::get_template_loaders(&loaders, &dirs, encoding, &engine)?,
                 None => {
-                    let filesystem_loader =
-                        Loader::FileSystem(FileSystemLoader::new(dirs.clone(), encoding));
-                    let appdirs_loader = Loader::AppDirs(AppDirsLoader::new(encoding));
-                    let loaders = if app_dirs {
-                        vec![filesystem_loader, appdirs_loader]
-                    } else {
-                        vec![filesystem_loader]
-                    };
+                    let mut loaders = vec!["django.template.loaders.filesystem.Loader"];
+                    if app_dirs {
+                        loaders.push("django.template.loaders.app_directories.Loader");
+                    }
+                    let loaders = PyList::new(py, loaders)?;
+                    let loaders = Self::get_template_loaders(&loaders, &dirs, encoding, &engine)?;
                     let cached_loader = Loader::Cached(CachedLoader::new(loaders));
                     vec![cached_loader]
                 }
@@ -213,15 +363,15 @@ pub mod django_rusty_templates {
             let builtins = vec![];
             let data = EngineData {
                 autoescape,
+                string_if_invalid,
+                context_processors: ContextProcessors(Arc::new(processors)),
                 libraries,
             };
             Ok(Self {
                 dirs,
                 app_dirs,
-                context_processors,
                 debug,
                 template_loaders,
-                string_if_invalid,
                 encoding,
                 builtins,
                 data,
@@ -251,13 +401,103 @@ pub mod django_rusty_templates {
         // TODO render_to_string needs implementation.
     }
 
+    enum OriginLoaderSource {
+        Python(Py<PyAny>),
+        Django {
+            path: String,
+            args: Py<PyTuple>,
+            engine: Arc<DjangoEngine>,
+            loader: PyOnceLock<Py<PyAny>>,
+        },
+    }
+
+    /// The Python loader object a template was loaded through, exposed as
+    /// `Origin.loader`. Native loaders only create the matching Django loader
+    /// when an origin needs it.
+    #[derive(Clone)]
+    pub struct OriginLoader(Arc<OriginLoaderSource>);
+
+    impl OriginLoader {
+        pub fn python(loader: Py<PyAny>) -> Self {
+            Self(Arc::new(OriginLoaderSource::Python(loader)))
+        }
+
+        fn django(path: String, args: Py<PyTuple>, engine: Arc<DjangoEngine>) -> Self {
+            Self(Arc::new(OriginLoaderSource::Django {
+                path,
+                args,
+                engine,
+                loader: PyOnceLock::new(),
+            }))
+        }
+
+        pub fn bind<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            match self.0.as_ref() {
+                OriginLoaderSource::Python(loader) => Ok(loader.bind(py).clone()),
+                OriginLoaderSource::Django {
+                    path,
+                    args,
+                    engine,
+                    loader,
+                } => {
+                    let loader = loader.get_or_try_init(py, || -> PyResult<_> {
+                        Ok(import_loader(path, args.bind(py), engine.get(py)?)?.unbind())
+                    })?;
+                    Ok(loader.bind(py).clone())
+                }
+            }
+        }
+    }
+
+    impl std::fmt::Debug for OriginLoader {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("OriginLoader")
+        }
+    }
+
+    impl PartialEq for OriginLoader {
+        fn eq(&self, other: &Self) -> bool {
+            // As with `ExternalFilter`, compare pointers to avoid needing `py`.
+            Arc::ptr_eq(&self.0, &other.0)
+        }
+    }
+
+    /// The Django `Origin` of a template, built on first access and shared
+    /// by clones of the template.
+    #[derive(Clone)]
+    pub struct CachedOrigin(Arc<PyOnceLock<Py<PyAny>>>);
+
+    impl CachedOrigin {
+        fn new() -> Self {
+            Self(Arc::new(PyOnceLock::new()))
+        }
+    }
+
+    impl std::fmt::Debug for CachedOrigin {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("CachedOrigin")
+        }
+    }
+
+    impl PartialEq for CachedOrigin {
+        fn eq(&self, _other: &Self) -> bool {
+            // The origin is built from the other template fields.
+            true
+        }
+    }
+
     #[derive(Debug, Clone, PartialEq)]
     #[pyclass]
     pub struct Template {
         pub filename: Option<PathBuf>,
+        pub template_name: Option<String>,
+        pub loader: Option<OriginLoader>,
         pub template: String,
         pub nodes: Vec<TokenTree>,
         pub autoescape: bool,
+        pub string_if_invalid: String,
+        pub context_processors: ContextProcessors,
+        origin: CachedOrigin,
     }
 
     impl Template {
@@ -265,6 +505,7 @@ pub mod django_rusty_templates {
             py: Python<'_>,
             template: &str,
             filename: PathBuf,
+            template_name: &str,
             engine_data: &EngineData,
         ) -> PyResult<Self> {
             let mut parser = Parser::new(py, TemplateString(template), &engine_data.libraries);
@@ -280,8 +521,13 @@ pub mod django_rusty_templates {
             Ok(Self {
                 template: template.to_string(),
                 filename: Some(filename),
+                template_name: Some(template_name.to_string()),
+                loader: None,
                 nodes,
                 autoescape: engine_data.autoescape,
+                string_if_invalid: engine_data.string_if_invalid.clone(),
+                context_processors: engine_data.context_processors.clone(),
+                origin: CachedOrigin::new(),
             })
         }
 
@@ -301,11 +547,101 @@ pub mod django_rusty_templates {
             Ok(Self {
                 template,
                 filename: None,
+                template_name: None,
+                loader: None,
                 nodes,
                 autoescape: engine_data.autoescape,
+                string_if_invalid: engine_data.string_if_invalid.clone(),
+                context_processors: engine_data.context_processors.clone(),
+                origin: CachedOrigin::new(),
             })
         }
 
+        /// Run the context processors for `request` and merge their output
+        /// in order, like Django's `RequestContext.bind_template`.
+        fn context_processor_updates<'py>(
+            &self,
+            request: &Bound<'py, PyAny>,
+            extra_processors: Vec<Bound<'py, PyAny>>,
+        ) -> PyResult<HashMap<String, Py<PyAny>>> {
+            let py = request.py();
+            let mut processors: Vec<_> = self
+                .context_processors
+                .0
+                .iter()
+                .map(|processor| processor.bind(py).clone())
+                .collect();
+            processors.extend(extra_processors);
+            let updates = PyDict::new(py);
+            for processor in processors {
+                let context = processor.call1((request,))?;
+                if let Err(error) = updates
+                    .call_method1(intern!(py, "update"), (context,))
+                    .ok_or_isinstance_of::<PyTypeError>(py)?
+                {
+                    let name = processor.getattr(intern!(py, "__qualname__"))?;
+                    let new_error = PyTypeError::new_err(format!(
+                        "Context processor {name} didn't return a dictionary."
+                    ));
+                    new_error.set_cause(py, Some(error));
+                    return Err(new_error);
+                }
+            }
+            updates.extract()
+        }
+
+        /// Build the render context from a Django `Context` or `RequestContext`,
+        /// layering its dicts like `Context.flatten` and running the context
+        /// processors in place of the `RequestContext` placeholder.
+        fn context_from_django(
+            &self,
+            context: &Bound<'_, PyAny>,
+            request: Option<Bound<'_, PyAny>>,
+            mut base_context: HashMap<String, Py<PyAny>>,
+        ) -> PyResult<Context> {
+            let py = context.py();
+            let django_context = py
+                .import(intern!(py, "django.template.context"))?
+                .getattr(intern!(py, "Context"))?;
+            if !context.is_instance(&django_context)? {
+                let name = context.get_type().name()?;
+                return Err(PyTypeError::new_err(format!(
+                    "context must be a dict rather than {name}."
+                )));
+            }
+            let processors_index = context.getattr_opt(intern!(py, "_processors_index"))?;
+            let (request, processors_index) = match processors_index {
+                Some(index) => (
+                    Some(context.getattr(intern!(py, "request"))?),
+                    Some(index.extract::<usize>()?),
+                ),
+                None => (request, None),
+            };
+            for (index, dict) in context
+                .getattr(intern!(py, "dicts"))?
+                .try_iter()?
+                .enumerate()
+            {
+                match (processors_index == Some(index), &request) {
+                    (true, Some(request)) => {
+                        let extra_processors = context
+                            .getattr(intern!(py, "_processors"))?
+                            .try_iter()?
+                            .collect::<PyResult<_>>()?;
+                        base_context
+                            .extend(self.context_processor_updates(request, extra_processors)?);
+                    }
+                    _ => base_context.extend(dict?.extract::<HashMap<_, _>>()?),
+                }
+            }
+            let request = request.map(|request| request.unbind());
+            let autoescape = context.getattr(intern!(py, "autoescape"))?.is_truthy()?;
+            let mut render_context = Context::new(base_context, request, autoescape);
+            render_context.use_l10n = context.getattr(intern!(py, "use_l10n"))?.extract()?;
+            render_context.use_tz = context.getattr(intern!(py, "use_tz"))?.extract()?;
+            Ok(render_context)
+        }
+
         fn _render(&self, py: Python<'_>, context: &mut Context) -> PyResult<String> {
             let mut rendered = String::with_capacity(self.template.len());
             let template = TemplateString(&self.template);
@@ -355,7 +691,7 @@ pub mod django_rusty_templates {
         pub fn render(
             &self,
             py: Python<'_>,
-            context: Option<Bound<'_, PyDict>>,
+            context: Option<Bound<'_, PyAny>>,
             request: Option<Bound<'_, PyAny>>,
         ) -> PyResult<String> {
             let mut base_context = HashMap::from([
@@ -366,14 +702,56 @@ pub mod django_rusty_templates {
                     PyBool::new(py, false).to_owned().into(),
                 ),
             ]);
-            if let Some(context) = context {
-                let new_context: HashMap<_, _> = context.extract()?;
-                base_context.extend(new_context);
+            let mut context = match context.map(|context| context.cast_into::<PyDict>()) {
+                Some(Err(error)) => {
+                    self.context_from_django(&error.into_inner(), request, base_context)?
+                }
+                context => {
+                    if let Some(request) = &request {
+                        base_context.extend(self.context_processor_updates(request, Vec::new())?);
+                    }
+                    if let Some(Ok(context)) = context {
+                        let new_context: HashMap<_, _> = context.extract()?;
+                        base_context.extend(new_context);
+                    };
+                    let request = request.map(|request| request.unbind());
+                    Context::new(base_context, request, self.autoescape)
+                }
             };
-            let request = request.map(|request| request.unbind());
-            let mut context = Context::new(base_context, request, self.autoescape);
+            context.string_if_invalid = self.string_if_invalid.clone();
             self._render(py, &mut context)
         }
+
+        /// A Django `Origin` describing where this template was loaded from.
+        #[getter]
+        pub fn origin<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+            let origin = self.origin.0.get_or_try_init(py, || -> PyResult<_> {
+                let base = py.import(intern!(py, "django.template.base"))?;
+                let name = match &self.filename {
+                    Some(filename) => filename.to_string_lossy().into_pyobject(py)?.into_any(),
+                    None => base.getattr(intern!(py, "UNKNOWN_SOURCE"))?,
+                };
+                let loader = match &self.loader {
+                    Some(loader) => Some(loader.bind(py)?),
+                    None => None,
+                };
+                Ok(base
+                    .getattr(intern!(py, "Origin"))?
+                    .call1((name, &self.template_name, loader))?
+                    .unbind())
+            })?;
+            Ok(origin.bind(py).clone())
+        }
+
+        #[getter]
+        pub fn source(&self) -> &str {
+            &self.template
+        }
+
+        #[getter]
+        pub fn name(&self) -> Option<&str> {
+            self.template_name.as_deref()
+        }
     }
 }
 
@@ -408,7 +786,8 @@ mod tests {
             let engine = EngineData::empty();
             let template_string = std::fs::read_to_string(&filename).unwrap();
             let error = temp_env::with_var("NO_COLOR", Some("1"), || {
-                Template::new(py, &template_string, filename, &engine).unwrap_err()
+                Template::new(py, &template_string, filename, "parse_error.txt", &engine)
+                    .unwrap_err()
             });
 
             let error_string = format!("{error}");
@@ -450,7 +829,10 @@ mod tests {
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
             let context = PyDict::new(py);
 
-            assert_eq!(template.render(py, Some(context), None).unwrap(), "");
+            assert_eq!(
+                template.render(py, Some(context.into_any()), None).unwrap(),
+                ""
+            );
         })
     }
 
@@ -466,7 +848,7 @@ mod tests {
             context.set_item("user", "Lily").unwrap();
 
             assert_eq!(
-                template.render(py, Some(context), None).unwrap(),
+                template.render(py, Some(context.into_any()), None).unwrap(),
                 "Hello Lily!"
             );
         })
@@ -482,7 +864,10 @@ mod tests {
             let template = Template::new_from_string(py, template_string, &engine).unwrap();
             let context = PyDict::new(py);
 
-            assert_eq!(template.render(py, Some(context), None).unwrap(), "Hello !");
+            assert_eq!(
+                template.render(py, Some(context.into_any()), None).unwrap(),
+                "Hello !"
+            );
         })
     }
 
@@ -512,7 +897,7 @@ user = User(["Lily"])
             context.set_item("user", user.into_any()).unwrap();
 
             assert_eq!(
-                template.render(py, Some(context), None).unwrap(),
+                template.render(py, Some(context.into_any()), None).unwrap(),
                 "Hello Lily!"
             );
         })
@@ -541,7 +926,10 @@ user = User(["Lily"])
             let template = engine.from_string(template_string).unwrap();
             let context = PyDict::new(py);
 
-            assert_eq!(template.render(py, Some(context), None).unwrap(), "Hello !");
+            assert_eq!(
+                template.render(py, Some(context.into_any()), None).unwrap(),
+                "Hello !"
+            );
         })
     }
 
@@ -19,3 +19,28 @@ impl<T> PyResultMethods<T> for PyResult<T> {
         }
     }
 }
+
+/// Whether `c` is whitespace according to Python's `str.isspace`, which also
+/// includes the ASCII information separators.
+pub fn is_python_whitespace(c: char) -> bool {
+    c.is_whitespace() || ('\x1c'..='\x1f').contains(&c)
+}
+
+/// Configure Django with the test settings, for tests that need them.
+#[cfg(test)]
+pub fn setup_django(py: Python<'_>) {
+    // Import the os module and set the DJANGO_SETTINGS_MODULE environment variable
+    let os_module = PyModule::import(py, "os").unwrap();
+    let environ = os_module.getattr("environ").unwrap();
+    environ
+        .call_method(
+            "setdefault",
+            ("DJANGO_SETTINGS_MODULE", "tests.settings"),
+            None,
+        )
+        .unwrap();
+
+    // Import the django module and call django.setup()
+    let django_module = PyModule::import(py, "django").unwrap();
+    django_module.call_method0("setup").unwrap();
+}
@@ -0,0 +1,10 @@
+def greeting(request):
+    return {"greeting": "Hello", "name": "processor"}
+
+
+def path(request):
+    return [("path", request.path)]
+
+
+def not_a_dict(request):
+    return None
@@ -178,3 +178,22 @@ def test_add_bool():
 
     assert django_template.render({}) == "101"
     assert rust_template.render({}) == "101"
+
+
+def test_add_tuples(assert_render):
+    template = "{{ foo|add:bar }}"
+    assert_render(template, {"foo": (1,), "bar": (2, 3)}, "(1, 2, 3)")
+
+
+def test_add_list_and_tuple(assert_render):
+    template = "{{ foo|add:bar }}"
+    assert_render(template, {"foo": [1], "bar": (2,)}, "")
+
+
+def test_add_no_variable_string(assert_render):
+    assert_render("{{ foo|add:'bar' }}", {}, "bar")
+
+
+def test_add_incompatible_chained(assert_render):
+    template = "{{ foo|add:bar|default:'none' }}"
+    assert_render(template, {"foo": [1], "bar": 2}, "none")
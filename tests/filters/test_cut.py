@@ -0,0 +1,81 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_cut.py
+"""
+
+import pytest
+from django.utils.safestring import mark_safe
+
+
+def test_cut01(assert_render):
+    template = '{% autoescape off %}{{ a|cut:"x" }} {{ b|cut:"x" }}{% endautoescape %}'
+    context = {"a": "x&y", "b": mark_safe("x&amp;y")}
+    assert_render(template, context, "&y &amp;y")
+
+
+def test_cut02(assert_render):
+    template = '{{ a|cut:"x" }} {{ b|cut:"x" }}'
+    context = {"a": "x&y", "b": mark_safe("x&amp;y")}
+    assert_render(template, context, "&amp;y &amp;y")
+
+
+def test_cut03(assert_render):
+    template = '{% autoescape off %}{{ a|cut:"&" }} {{ b|cut:"&" }}{% endautoescape %}'
+    context = {"a": "x&y", "b": mark_safe("x&amp;y")}
+    assert_render(template, context, "xy xamp;y")
+
+
+def test_cut04(assert_render):
+    template = '{{ a|cut:"&" }} {{ b|cut:"&" }}'
+    context = {"a": "x&y", "b": mark_safe("x&amp;y")}
+    assert_render(template, context, "xy xamp;y")
+
+
+# Passing ';' to cut can break existing HTML entities, so those strings
+# are auto-escaped.
+def test_cut05(assert_render):
+    template = '{% autoescape off %}{{ a|cut:";" }} {{ b|cut:";" }}{% endautoescape %}'
+    context = {"a": "x&y", "b": mark_safe("x&amp;y")}
+    assert_render(template, context, "x&y x&ampy")
+
+
+def test_cut06(assert_render):
+    template = '{{ a|cut:";" }} {{ b|cut:";" }}'
+    context = {"a": "x&y", "b": mark_safe("x&amp;y")}
+    assert_render(template, context, "x&amp;y x&amp;ampy")
+
+
+def test_character(assert_render):
+    template = '{{ a|cut:"a" }}'
+    assert_render(template, {"a": "a string to be mangled"}, " string to be mngled")
+
+
+def test_characters(assert_render):
+    template = '{{ a|cut:"ng" }}'
+    assert_render(template, {"a": "a string to be mangled"}, "a stri to be maled")
+
+
+def test_non_matching_string(assert_render):
+    template = '{{ a|cut:"strings" }}'
+    context = {"a": "a string to be mangled"}
+    assert_render(template, context, "a string to be mangled")
+
+
+def test_non_string_input(assert_render):
+    assert_render('{{ a|cut:"2" }}', {"a": 123}, "13")
+
+
+def test_missing(assert_render):
+    assert_render('{{ a|cut:"2" }}', {}, "")
+
+
+def test_non_string_argument(rusty, django_template):
+    template = "{{ a|cut:2 }}"
+
+    with pytest.raises(TypeError) as django_error:
+        django_template(template).render({"a": "123"})
+
+    with pytest.raises(TypeError) as rust_error:
+        rusty(template).render({"a": "123"})
+
+    assert str(rust_error.value) == str(django_error.value)
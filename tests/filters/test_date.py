@@ -0,0 +1,152 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_date.py
+"""
+
+from datetime import date, datetime, time, timedelta, timezone
+
+import pytest
+from django.utils import translation
+
+
+def test_date_default_format(assert_render):
+    template = "{{ d|date }}"
+    context = {"d": datetime(2008, 1, 1, 12, 30)}
+    assert_render(template, context, "Jan. 1, 2008")
+
+
+def test_date_format(assert_render):
+    template = '{{ d|date:"Y-m-d H:i:s u" }}'
+    context = {"d": datetime(2008, 1, 3, 9, 5, 7, 42)}
+    assert_render(template, context, "2008-01-03 09:05:07 000042")
+
+
+def test_date_named_format(assert_render):
+    template = '{{ d|date:"SHORT_DATE_FORMAT" }}'
+    context = {"d": date(2008, 1, 1)}
+    assert_render(template, context, "01/01/2008")
+
+
+def test_date_names(assert_render):
+    template = '{{ d|date:"D l b M F N E" }}'
+    context = {"d": date(2003, 9, 5)}
+    assert_render(template, context, "Fri Friday sep Sep September Sept. September")
+
+
+def test_date_translated_names(assert_render):
+    template = '{{ d|date:"l j F" }}'
+    context = {"d": date(2003, 9, 5)}
+    with translation.override("fr"):
+        assert_render(template, context, "vendredi 5 septembre")
+
+
+def test_date_numbers(assert_render):
+    template = '{{ d|date:"d j n m y Y L t w z S" }}'
+    context = {"d": date(2004, 2, 29)}
+    assert_render(template, context, "29 29 2 02 04 2004 True 29 0 60 th")
+
+
+def test_date_iso_week(assert_render):
+    template = '{{ d|date:"o-W" }}'
+    context = {"d": date(2005, 1, 1)}
+    assert_render(template, context, "2004-53")
+
+
+def test_date_time_characters(assert_render):
+    template = '{{ d|date:"a A f g G h H i P s" }}'
+    context = {"d": datetime(2008, 1, 1, 13, 5, 9)}
+    assert_render(template, context, "p.m. PM 1:05 1 13 01 13 05 1:05 p.m. 09")
+
+
+def test_date_midnight_and_noon(assert_render):
+    template = '{{ midnight|date:"P" }} {{ noon|date:"P" }}'
+    context = {"midnight": time(0, 0), "noon": time(12, 0)}
+    assert_render(template, context, "midnight noon")
+
+
+def test_date_escaped_characters(assert_render):
+    template = r'{{ d|date:"\Y\e\s: Y" }}'
+    context = {"d": date(2008, 1, 1)}
+    assert_render(template, context, "Yes: 2008")
+
+
+def test_date_aware(assert_render, settings):
+    settings.USE_TZ = True
+    settings.TIME_ZONE = "Europe/London"
+    template = '{{ d|date:"Y-m-d H:i O e" }}'
+    tz = timezone(timedelta(hours=2))
+    context = {"d": datetime(2008, 7, 1, 12, 0, tzinfo=tz)}
+    assert_render(template, context, "2008-07-01 11:00 +0100 BST")
+
+
+def test_date_naive_timezone(assert_render, settings):
+    settings.USE_TZ = False
+    settings.TIME_ZONE = "America/Chicago"
+    template = '{{ d|date:"T" }}'
+    context = {"d": datetime(2008, 1, 1)}
+    assert_render(template, context, "CST")
+
+
+def test_date_iso_format(assert_render):
+    template = '{{ d|date:"c" }}'
+    context = {"d": datetime(2008, 1, 2, 10, 30, 0, 123)}
+    assert_render(template, context, "2008-01-02T10:30:00.000123")
+
+
+def test_date_autoescape(assert_render):
+    template = '{{ d|date:"<b>Y</b>" }}'
+    context = {"d": date(2008, 1, 1)}
+    assert_render(template, context, "&lt;b&gt;2008&lt;/b&gt;")
+
+
+def test_date_autoescape_off(assert_render):
+    template = '{% autoescape off %}{{ d|date:"<b>Y</b>" }}{% endautoescape %}'
+    context = {"d": date(2008, 1, 1)}
+    assert_render(template, context, "<b>2008</b>")
+
+
+def test_date_variable_format(assert_render):
+    template = "{{ d|date:format }}"
+    context = {"d": date(2008, 1, 1), "format": "j/n"}
+    assert_render(template, context, "1/1")
+
+
+def test_date_time_object(assert_render):
+    template = '{{ t|date:"H:i" }}'
+    context = {"t": time(9, 5)}
+    assert_render(template, context, "09:05")
+
+
+def test_date_time_object_date_format(assert_render):
+    template = '{{ t|date:"Y" }}'
+    context = {"t": time(9, 5)}
+    assert_render(template, context, "")
+
+
+@pytest.mark.parametrize("value", [None, "", "not a date", 42])
+def test_date_invalid_value(assert_render, value):
+    template = '{{ d|date:"Y" }}'
+    assert_render(template, {"d": value}, "")
+
+
+def test_date_missing_value(assert_render):
+    template = '{{ d|date:"Y" }}'
+    assert_render(template, {}, "")
+
+
+def test_date_time_specifier_on_date(rusty, django_template):
+    template = '{{ d|date:"H" }}'
+    context = {"d": date(2008, 1, 1)}
+    msg = (
+        "The format for date objects may not contain time-related format "
+        "specifiers (found 'H')."
+    )
+
+    with pytest.raises(TypeError) as django_error:
+        django_template(template).render(context)
+    assert str(django_error.value) == msg
+
+    with pytest.raises(TypeError) as rust_error:
+        rusty(template).render(context)
+    assert str(rust_error.value) == msg
+
@@ -0,0 +1,66 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_default.py
+"""
+
+import pytest
+from django.template import engines
+from django.template.exceptions import TemplateSyntaxError
+
+
+def test_default_if_none01(assert_render):
+    template = '{{ a|default_if_none:"x<" }}'
+    assert_render(template, {"a": None}, "x<")
+
+
+def test_default_if_none02(assert_render):
+    template = '{% autoescape off %}{{ a|default_if_none:"x<" }}{% endautoescape %}'
+    assert_render(template, {"a": None}, "x<")
+
+
+def test_value(assert_render):
+    assert_render("{{ a|default_if_none:'default' }}", {"a": "val"}, "val")
+
+
+def test_none(assert_render):
+    assert_render("{{ a|default_if_none:'default' }}", {"a": None}, "default")
+
+
+def test_empty_string(assert_render):
+    assert_render("{{ a|default_if_none:'default' }}", {"a": ""}, "")
+
+
+def test_falsy(assert_render):
+    template = "{{ a|default_if_none:'default' }} {{ b|default_if_none:'default' }}"
+    assert_render(template, {"a": 0, "b": []}, "0 []")
+
+
+def test_missing_value(assert_render):
+    assert_render("{{ a|default_if_none:'default' }}", {}, "")
+
+
+def test_unsafe_default(assert_render):
+    template = "{{ a|default_if_none:b }}"
+    assert_render(template, {"a": None, "b": "<b>"}, "&lt;b&gt;")
+
+
+def test_default_if_none_missing_argument():
+    template = "{{ a|default_if_none }}"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "default_if_none requires 2 arguments, 1 provided"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × Expected an argument
+   ╭────
+ 1 │ {{ a|default_if_none }}
+   ·      ───────┬───────
+   ·             ╰── here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
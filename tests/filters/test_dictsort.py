@@ -0,0 +1,108 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_dictsort.py
+"""
+
+import pytest
+
+
+class User:
+    password = "abc"
+
+    _private = "private"
+
+    @property
+    def test_property(self):
+        return "cde"
+
+    def test_method(self):
+        """This is just a test method."""
+
+
+PEOPLE = [
+    {"age": 23, "name": "Barbara-Ann"},
+    {"age": 63, "name": "Ra Ra Rasputin"},
+    {"name": "Jonny B Goode", "age": 18},
+]
+
+
+def test_sort(assert_render):
+    template = "{% for p in people|dictsort:'age' %}{{ p.name }}|{% endfor %}"
+    expected = "Jonny B Goode|Barbara-Ann|Ra Ra Rasputin|"
+    assert_render(template, {"people": PEOPLE}, expected)
+
+
+def test_sort_variable_argument(assert_render):
+    template = "{% for p in people|dictsort:key %}{{ p.age }}|{% endfor %}"
+    assert_render(template, {"people": PEOPLE, "key": "name"}, "23|18|63|")
+
+
+def test_dictsort_complex_sorting_key(assert_render):
+    template = "{% for d in data|dictsort:'foo.baz' %}{{ d.foo.bar }}{% endfor %}"
+    data = [
+        {"foo": {"bar": 1, "baz": "c"}},
+        {"foo": {"bar": 2, "baz": "b"}},
+        {"foo": {"bar": 3, "baz": "a"}},
+    ]
+    assert_render(template, {"data": data}, "321")
+
+
+def test_sort_list_of_tuples(assert_render):
+    template = "{% for a, b in data|dictsort:0 %}{{ a }}{{ b }} {% endfor %}"
+    data = [("a", "42"), ("c", "string"), ("b", "foo")]
+    assert_render(template, {"data": data}, "a42 bfoo cstring ")
+
+
+def test_sort_list_of_tuple_like_dicts(assert_render):
+    template = "{% for d in data|dictsort:'0' %}{{ d.1 }} {% endfor %}"
+    data = [
+        {"0": "a", "1": "42"},
+        {"0": "c", "1": "string"},
+        {"0": "b", "1": "foo"},
+    ]
+    assert_render(template, {"data": data}, "42 foo string ")
+
+
+def test_attribute_lookup(assert_render):
+    template = "{% for d in data|dictsort:'user.password' %}{{ d.n }}{% endfor %}"
+    first = User()
+    first.password = "b"
+    second = User()
+    data = [{"n": 1, "user": first}, {"n": 2, "user": second}]
+    assert_render(template, {"data": data}, "21")
+
+
+def test_stable(assert_render):
+    template = "{% for d in data|dictsort:'a' %}{{ d.b }}{% endfor %}"
+    data = [{"a": 1, "b": 1}, {"a": 0, "b": 2}, {"a": 1, "b": 3}]
+    assert_render(template, {"data": data}, "213")
+
+
+@pytest.mark.parametrize(
+    "value",
+    [[1, 2, 3], "Hello!", {"a": 1}, 1],
+)
+def test_invalid_values(assert_render, value):
+    assert_render("{{ value|dictsort:'age' }}", {"value": value}, "")
+
+
+@pytest.mark.parametrize(
+    "value,argument",
+    [
+        ([{}], "._private"),
+        ([{"_private": "test"}], "_private"),
+        ([{"nested": {"_private": "test"}}], "nested._private"),
+    ],
+)
+def test_invalid_args(assert_render, value, argument):
+    template = "{{ value|dictsort:argument }}"
+    assert_render(template, {"value": value, "argument": argument}, "")
+
+
+def test_incomparable_values(assert_render):
+    template = "{{ value|dictsort:'a' }}"
+    assert_render(template, {"value": [{"a": 1}, {"a": "x"}]}, "")
+
+
+def test_missing(assert_render):
+    assert_render("{{ value|dictsort:'a' }}", {}, "[]")
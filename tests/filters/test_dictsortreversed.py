@@ -0,0 +1,42 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_dictsortreversed.py
+"""
+
+import pytest
+
+
+def test_sort(assert_render):
+    template = "{% for p in people|dictsortreversed:'age' %}{{ p.name }}|{% endfor %}"
+    people = [
+        {"age": 23, "name": "Barbara-Ann"},
+        {"age": 63, "name": "Ra Ra Rasputin"},
+        {"name": "Jonny B Goode", "age": 18},
+    ]
+    expected = "Ra Ra Rasputin|Barbara-Ann|Jonny B Goode|"
+    assert_render(template, {"people": people}, expected)
+
+
+def test_sort_list_of_tuples(assert_render):
+    template = "{% for a, b in data|dictsortreversed:0 %}{{ a }}{{ b }} {% endfor %}"
+    data = [("a", "42"), ("c", "string"), ("b", "foo")]
+    assert_render(template, {"data": data}, "cstring bfoo a42 ")
+
+
+def test_stable(assert_render):
+    template = "{% for d in data|dictsortreversed:'a' %}{{ d.b }}{% endfor %}"
+    data = [{"a": 1, "b": 1}, {"a": 0, "b": 2}, {"a": 1, "b": 3}]
+    assert_render(template, {"data": data}, "132")
+
+
+@pytest.mark.parametrize(
+    "value",
+    [[1, 2, 3], "Hello!", {"a": 1}, 1],
+)
+def test_invalid_values(assert_render, value):
+    assert_render("{{ value|dictsortreversed:'age' }}", {"value": value}, "")
+
+
+def test_invalid_args(assert_render):
+    template = "{{ value|dictsortreversed:'_private' }}"
+    assert_render(template, {"value": [{"_private": "test"}]}, "")
@@ -0,0 +1,93 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_divisibleby.py
+"""
+
+import pytest
+from django.template import engines
+from django.template.exceptions import TemplateSyntaxError
+
+
+def test_true(assert_render):
+    assert_render("{{ value|divisibleby:2 }}", {"value": 4}, "True")
+
+
+def test_false(assert_render):
+    assert_render("{{ value|divisibleby:3 }}", {"value": 4}, "False")
+
+
+def test_strings(assert_render):
+    assert_render("{{ value|divisibleby:'3' }}", {"value": " 9 "}, "True")
+
+
+def test_float(assert_render):
+    assert_render("{{ value|divisibleby:2 }}", {"value": 4.9}, "True")
+
+
+def test_negative(assert_render):
+    assert_render("{{ value|divisibleby:3 }}", {"value": -7}, "False")
+
+
+def test_if_tag(assert_render):
+    template = "{% if value|divisibleby:3 %}yes{% else %}no{% endif %}"
+    assert_render(template, {"value": 9}, "yes")
+
+
+def test_invalid_value(rusty, django_template):
+    template = "{{ value|divisibleby:2 }}"
+
+    with pytest.raises(ValueError) as exc_info:
+        django_template(template).render({"value": "abc"})
+
+    assert str(exc_info.value) == "invalid literal for int() with base 10: 'abc'"
+
+    with pytest.raises(ValueError) as exc_info:
+        rusty(template).render({"value": "abc"})
+
+    assert str(exc_info.value) == "invalid literal for int() with base 10: 'abc'"
+
+
+def test_missing_value(rusty, django_template):
+    template = "{{ value|divisibleby:2 }}"
+
+    with pytest.raises(ValueError) as exc_info:
+        django_template(template).render({})
+
+    assert str(exc_info.value) == "invalid literal for int() with base 10: ''"
+
+    with pytest.raises(ValueError) as exc_info:
+        rusty(template).render({})
+
+    assert str(exc_info.value) == "invalid literal for int() with base 10: ''"
+
+
+def test_zero(rusty, django_template):
+    template = "{{ value|divisibleby:0 }}"
+
+    with pytest.raises(ZeroDivisionError):
+        django_template(template).render({"value": 4})
+
+    with pytest.raises(ZeroDivisionError):
+        rusty(template).render({"value": 4})
+
+
+def test_divisibleby_missing_argument():
+    template = "{{ value|divisibleby }}"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "divisibleby requires 2 arguments, 1 provided"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × Expected an argument
+   ╭────
+ 1 │ {{ value|divisibleby }}
+   ·          ─────┬─────
+   ·               ╰── here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
@@ -0,0 +1,60 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_escapejs.py
+"""
+
+
+def test_escapejs01(assert_render):
+    template = "{{ a|escapejs }}"
+    context = {"a": "testing\r\njavascript 'string\" <b>escaping</b>"}
+    expected = (
+        "testing\\u000D\\u000Ajavascript \\u0027string\\u0022 "
+        "\\u003Cb\\u003Eescaping\\u003C/b\\u003E"
+    )
+    assert_render(template, context, expected)
+
+
+def test_escapejs02(assert_render):
+    template = "{% autoescape off %}{{ a|escapejs }}{% endautoescape %}"
+    context = {"a": "testing\r\njavascript 'string\" <b>escaping</b>"}
+    expected = (
+        "testing\\u000D\\u000Ajavascript \\u0027string\\u0022 "
+        "\\u003Cb\\u003Eescaping\\u003C/b\\u003E"
+    )
+    assert_render(template, context, expected)
+
+
+def test_whitespace(assert_render):
+    template = "{{ a|escapejs }}"
+    context = {"a": "and lots of whitespace: \r\n\t\v\f\b"}
+    expected = "and lots of whitespace: \\u000D\\u000A\\u0009\\u000B\\u000C\\u0008"
+    assert_render(template, context, expected)
+
+
+def test_script(assert_render):
+    template = "{{ a|escapejs }}"
+    context = {"a": r"<script>and this</script>"}
+    expected = "\\u003Cscript\\u003Eand this\\u003C/script\\u003E"
+    assert_render(template, context, expected)
+
+
+def test_paragraph_separator(assert_render):
+    template = "{{ a|escapejs }}"
+    context = {"a": "paragraph separator:\u2029and line separator:\u2028"}
+    expected = "paragraph separator:\\u2029and line separator:\\u2028"
+    assert_render(template, context, expected)
+
+
+def test_other_characters(assert_render):
+    template = "{{ a|escapejs }}"
+    context = {"a": "\\=-;`&"}
+    expected = "\\u005C\\u003D\\u002D\\u003B\\u0060\\u0026"
+    assert_render(template, context, expected)
+
+
+def test_non_string_input(assert_render):
+    assert_render("{{ a|escapejs }}", {"a": 123}, "123")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|escapejs }}", {}, "")
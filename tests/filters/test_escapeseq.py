@@ -0,0 +1,54 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_escapeseq.py
+"""
+
+import pytest
+from django.utils.safestring import mark_safe
+
+
+def test_basic(assert_render):
+    template = '{{ a|escapeseq|join:", " }} -- {{ b|escapeseq|join:", " }}'
+    context = {"a": ["x&y", "<p>"], "b": [mark_safe("x&y"), mark_safe("<p>")]}
+    assert_render(template, context, "x&amp;y, &lt;p&gt; -- x&y, <p>")
+
+
+def test_autoescape_off(assert_render):
+    template = (
+        '{% autoescape off %}{{ a|escapeseq|join:", " }} -- '
+        '{{ b|escapeseq|join:", "}}{% endautoescape %}'
+    )
+    context = {"a": ["x&y", "<p>"], "b": [mark_safe("x&y"), mark_safe("<p>")]}
+    assert_render(template, context, "x&amp;y, &lt;p&gt; -- x&y, <p>")
+
+
+def test_join(assert_render):
+    template = '{{ a|escapeseq|join:"<br/>" }}'
+    context = {"a": ["x&y", "<p>"]}
+    assert_render(template, context, "x&amp;y<br/>&lt;p&gt;")
+
+
+def test_string(assert_render):
+    template = '{{ a|escapeseq|join:"," }}'
+    assert_render(template, {"a": "<&>"}, "&lt;,&amp;,&gt;")
+
+
+def test_non_string_items(assert_render):
+    template = '{{ a|escapeseq|join:"," }}'
+    assert_render(template, {"a": [1, None]}, "1,None")
+
+
+def test_missing(assert_render):
+    assert_render('{{ a|escapeseq|join:"," }}', {}, "")
+
+
+def test_not_iterable(rusty, django_template):
+    template = "{{ a|escapeseq }}"
+
+    with pytest.raises(TypeError) as django_error:
+        django_template(template).render({"a": 1})
+
+    with pytest.raises(TypeError) as rust_error:
+        rusty(template).render({"a": 1})
+
+    assert str(rust_error.value) == str(django_error.value)
@@ -1,6 +1,9 @@
+from datetime import datetime, timezone
+
 import pytest
 from django.template import engines
 from django.template.base import VariableDoesNotExist
+from django.utils.safestring import mark_safe
 
 
 def test_load_and_render_filters():
@@ -103,3 +106,30 @@ def test_filter_error_with_argument():
 
     with pytest.raises(ZeroDivisionError):
         rust_template.render({"num": 1})
+
+
+def test_is_safe_filter(assert_render):
+    template = (
+        "{% load exclaim from custom_filters %}"
+        "{{ safe|exclaim }} {{ unsafe|exclaim }} {{ unsafe|safe|exclaim }}"
+    )
+    context = {"safe": mark_safe("<b>hi</b>"), "unsafe": "<b>hi</b>"}
+    expected = "<b>hi</b>! &lt;b&gt;hi&lt;/b&gt;! <b>hi</b>!"
+    assert_render(template, context, expected)
+
+
+def test_needs_autoescape_filter(assert_render):
+    template = (
+        "{% load bold from custom_filters %}{{ text|bold }} "
+        "{% autoescape off %}{{ text|bold }}{% endautoescape %}"
+    )
+    assert_render(template, {"text": "<i>"}, "<b>&lt;i&gt;</b> <b><i></b>")
+
+
+def test_expects_localtime_filter(assert_render):
+    template = (
+        "{% load tz %}{% load hour from custom_filters %}{{ value|hour }} "
+        "{% localtime off %}{{ value|hour }}{% endlocaltime %}"
+    )
+    value = datetime(2020, 1, 1, 12, tzinfo=timezone.utc)
+    assert_render(template, {"value": value}, "6 12")
@@ -0,0 +1,126 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_filesizeformat.py
+"""
+
+import pytest
+from django.template import engines
+from django.template.exceptions import TemplateSyntaxError
+from django.utils import translation
+
+KB = 1024
+MB = KB * 1024
+GB = MB * 1024
+TB = GB * 1024
+PB = TB * 1024
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (0, "0\xa0bytes"),
+        (1, "1\xa0byte"),
+        (1023, "1023\xa0bytes"),
+        (KB, "1.0\xa0KB"),
+        (10 * KB, "10.0\xa0KB"),
+        (MB - 1, "1024.0\xa0KB"),
+        (MB, "1.0\xa0MB"),
+        (MB * 50, "50.0\xa0MB"),
+        (GB - 1, "1024.0\xa0MB"),
+        (GB, "1.0\xa0GB"),
+        (TB, "1.0\xa0TB"),
+        (PB, "1.0\xa0PB"),
+        (PB * 2000, "2000.0\xa0PB"),
+        (complex(1, -1), "0\xa0bytes"),
+        ("", "0\xa0bytes"),
+        ("\N{GREEK SMALL LETTER ALPHA}", "0\xa0bytes"),
+    ],
+)
+def test_formats(assert_render, value, expected):
+    assert_render("{{ value|filesizeformat }}", {"value": value}, expected)
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (0, "0\xa0Bytes"),
+        (1, "1\xa0Byte"),
+        (1023, "1023\xa0Bytes"),
+        (KB, "1,0\xa0KB"),
+        (10 * KB, "10,0\xa0KB"),
+        (MB - 1, "1024,0\xa0KB"),
+        (MB, "1,0\xa0MB"),
+        (MB * 50, "50,0\xa0MB"),
+        (GB - 1, "1024,0\xa0MB"),
+        (GB, "1,0\xa0GB"),
+        (TB, "1,0\xa0TB"),
+        (PB, "1,0\xa0PB"),
+        (PB * 2000, "2000,0\xa0PB"),
+        (complex(1, -1), "0\xa0Bytes"),
+        ("", "0\xa0Bytes"),
+        ("\N{GREEK SMALL LETTER ALPHA}", "0\xa0Bytes"),
+    ],
+)
+def test_localized_formats(assert_render, value, expected):
+    with translation.override("de"):
+        assert_render("{{ value|filesizeformat }}", {"value": value}, expected)
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (-1, "-1\xa0byte"),
+        (-100, "-100\xa0bytes"),
+        (-MB * 50, "-50.0\xa0MB"),
+    ],
+)
+def test_negative_numbers(assert_render, value, expected):
+    assert_render("{{ value|filesizeformat }}", {"value": value}, expected)
+
+
+def test_rounds_half_to_even(assert_render):
+    template = "{{ a|filesizeformat }} {{ b|filesizeformat }}"
+    context = {"a": 1280, "b": 1382}
+    assert_render(template, context, "1.2\xa0KB 1.3\xa0KB")
+
+
+def test_thousand_separator(assert_render, settings):
+    settings.USE_THOUSAND_SEPARATOR = True
+    template = "{{ value|filesizeformat }}"
+    with translation.override("de"):
+        assert_render(template, {"value": PB * 2000}, "2.000,0\xa0PB")
+
+
+def test_huge_value(assert_render):
+    template = "{{ value|filesizeformat }}"
+    assert_render(template, {"value": PB * 10**200}, "1.0e+200\xa0PB")
+
+
+def test_float_string(assert_render):
+    assert_render("{{ value|filesizeformat }}", {"value": "1024.5"}, "0\xa0bytes")
+
+
+def test_missing_variable(assert_render):
+    assert_render("{{ value|filesizeformat }}", {}, "0\xa0bytes")
+
+
+def test_filesizeformat_with_argument():
+    template = "{{ value|filesizeformat:1 }}"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "filesizeformat requires 1 arguments, 2 provided"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × filesizeformat filter does not take an argument
+   ╭────
+ 1 │ {{ value|filesizeformat:1 }}
+   ·                         ┬
+   ·                         ╰── unexpected argument
+   ╰────
+"""
+    assert str(exc_info.value) == expected
@@ -0,0 +1,46 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_first.py
+"""
+
+from django.utils.safestring import mark_safe
+
+
+def test_first01(assert_render):
+    template = "{{ a|first }} {{ b|first }}"
+    context = {"a": ["a&b", "x"], "b": [mark_safe("a&b"), "x"]}
+    assert_render(template, context, "a&amp;b a&b")
+
+
+def test_first02(assert_render):
+    template = "{% autoescape off %}{{ a|first }} {{ b|first }}{% endautoescape %}"
+    context = {"a": ["a&b", "x"], "b": [mark_safe("a&b"), "x"]}
+    assert_render(template, context, "a&b a&b")
+
+
+def test_list(assert_render):
+    assert_render("{{ a|first }}", {"a": [0, 1, 2]}, "0")
+
+
+def test_empty_string(assert_render):
+    assert_render("{{ a|first }}", {"a": ""}, "")
+
+
+def test_empty_list(assert_render):
+    assert_render("{{ a|first }}", {"a": []}, "")
+
+
+def test_string(assert_render):
+    assert_render("{{ a|first }}", {"a": "test"}, "t")
+
+
+def test_safe_string(assert_render):
+    assert_render("{{ a|first }}", {"a": mark_safe("&amp;")}, "&amp;")
+
+
+def test_filter_output(assert_render):
+    assert_render('{{ a|default:"<b>"|first }}', {}, "&lt;")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|first }}", {}, "")
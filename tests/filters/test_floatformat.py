@@ -0,0 +1,214 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_floatformat.py
+"""
+
+from decimal import Decimal, localcontext
+
+import pytest
+from django.utils import translation
+from django.utils.safestring import mark_safe
+
+
+def test_floatformat01(assert_render):
+    template = (
+        "{% autoescape off %}{{ a|floatformat }} {{ b|floatformat }}{% endautoescape %}"
+    )
+    context = {"a": "1.42", "b": mark_safe("1.42")}
+    assert_render(template, context, "1.4 1.4")
+
+
+def test_floatformat02(assert_render):
+    template = "{{ a|floatformat }} {{ b|floatformat }}"
+    context = {"a": "1.42", "b": mark_safe("1.42")}
+    assert_render(template, context, "1.4 1.4")
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (7.7, "7.7"),
+        (7.0, "7"),
+        (0.7, "0.7"),
+        (0.07, "0.1"),
+        (0.007, "0.0"),
+        (0.0, "0"),
+        (7.7, "7.7"),
+        (-7.7, "-7.7"),
+        (-0.07, "-0.1"),
+        (-0.007, "0.0"),
+        (34.5, "34.5"),
+        (34.55, "34.6"),
+        (100.0, "100"),
+        (Decimal("1.5"), "1.5"),
+        (Decimal("1.55"), "1.6"),
+        (Decimal("1.0"), "1"),
+        ("foo", ""),
+        (None, ""),
+        (True, "1"),
+        (False, "0"),
+    ],
+)
+def test_floatformat_default(assert_render, value, expected):
+    assert_render("{{ value|floatformat }}", {"value": value}, expected)
+
+
+@pytest.mark.parametrize(
+    "value,arg,expected",
+    [
+        (7.7, 3, "7.700"),
+        (6.000000, 3, "6.000"),
+        (6.200000, 3, "6.200"),
+        (6.200000, -3, "6.200"),
+        (13.1031, -3, "13.103"),
+        (11.1197, -2, "11.12"),
+        (11.0000, -2, "11"),
+        (11.000001, -2, "11.00"),
+        (8.2798, 3, "8.280"),
+        (5555.555, 2, "5555.56"),
+        (1.3000, 2, "1.30"),
+        (0.12345, 2, "0.12"),
+        (Decimal("555.555"), 2, "555.56"),
+        (Decimal("09.000"), 0, "9"),
+        ("-0.0", 0, "0"),
+        (-0.01, -1, "0.0"),
+        (-0.01, 1, "0.0"),
+        (0.01, 1, "0.0"),
+        (-0.5, 0, "-1"),
+        (0.5, 0, "1"),
+        (1.5, 0, "2"),
+        (18.125, 2, "18.13"),
+        (None, 1, ""),
+        (13.1031, "bar", "13.1031"),
+        (18.125, "2", "18.13"),
+        (66666.666, "2", "66666.67"),
+        (1, 1, "1.0"),
+        (100, 0, "100"),
+    ],
+)
+def test_floatformat_argument(assert_render, value, arg, expected):
+    template = "{{ value|floatformat:arg }}"
+    assert_render(template, {"value": value, "arg": arg}, expected)
+
+
+@pytest.mark.parametrize(
+    "value,arg,expected",
+    [
+        (66666.666, "2g", "66,666.67"),
+        (66666.666, "-2g", "66,666.67"),
+        (66666.0, "-2g", "66,666"),
+        (10000, "g", "10,000"),
+        (10000, "3u", "10000.000"),
+        (66666.666, "2gu", "66666.67"),
+        (66666.666, "2ug", "66666.67"),
+        (66666.666, "u", "66666.7"),
+    ],
+)
+def test_floatformat_suffixes(assert_render, value, arg, expected):
+    template = "{{ value|floatformat:arg }}"
+    assert_render(template, {"value": value, "arg": arg}, expected)
+
+
+def test_floatformat_literal_argument(assert_render):
+    template = '{{ value|floatformat:"3g" }} {{ value|floatformat:2 }}'
+    assert_render(template, {"value": 1234.5678}, "1,234.568 1234.57")
+
+
+def test_floatformat_localized(assert_render, settings):
+    settings.USE_THOUSAND_SEPARATOR = True
+    template = "{{ a|floatformat:2 }} {{ a|floatformat:'2u' }}"
+    context = {"a": 66666.666}
+    with translation.override("de"):
+        assert_render(template, context, "66.666,67 66666.67")
+
+
+def test_floatformat_force_grouping_localized(assert_render):
+    template = "{{ a|floatformat:'2g' }}"
+    context = {"a": 66666.666}
+    with translation.override("de"):
+        assert_render(template, context, "66.666,67")
+
+
+def test_floatformat_decimal_precision(assert_render):
+    template = "{{ a|floatformat:2 }}"
+    with localcontext() as ctx:
+        ctx.prec = 2
+        assert_render(template, {"a": Decimal("1.2345")}, "1.23")
+        assert_render(template, {"a": Decimal("15.2042")}, "15.20")
+
+
+def test_floatformat_large_decimal(assert_render):
+    template = "{{ a|floatformat:2 }}"
+    value = Decimal("123456.123456789012345678901")
+    assert_render(template, {"a": value}, "123456.12")
+
+
+def test_floatformat_no_float_round_trip(assert_render):
+    template = "{{ a|floatformat:20 }}"
+    value = Decimal("0.12345678901234567890")
+    assert_render(template, {"a": value}, "0.12345678901234567890")
+
+
+@pytest.mark.parametrize(
+    "value",
+    [float("inf"), float("-inf"), float("nan"), Decimal("Infinity"), Decimal("NaN")],
+)
+def test_floatformat_infinity(assert_render, value):
+    template = "{{ a|floatformat }}"
+    assert_render(template, {"a": value}, str(value))
+
+
+def test_floatformat_too_many_digits(assert_render):
+    template = "{{ a|floatformat:2 }}"
+    value = "1e200"
+    assert_render(template, {"a": value}, value)
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        ("1e199", "1" + "0" * 199 + ".00"),
+        ("1" * 200, "1" * 200 + ".00"),
+        ("1" * 201, "1" * 201),
+        ("0." + "1" * 100, "0.11"),
+        ("0." + "1" * 101, "0." + "1" * 101),
+    ],
+)
+def test_floatformat_digit_limit(assert_render, value, expected):
+    template = "{{ a|floatformat:2 }}"
+    assert_render(template, {"a": value}, expected)
+
+
+@pytest.mark.parametrize("value", ["1.5", "", "missing"])
+def test_floatformat_empty_argument(rusty, django_template, value):
+    template = '{{ a|floatformat:"" }}'
+    context = {"a": value} if value != "missing" else {}
+
+    with pytest.raises(IndexError) as exc_info:
+        django_template(template).render(context)
+
+    assert str(exc_info.value) == "string index out of range"
+
+    with pytest.raises(IndexError) as exc_info:
+        rusty(template).render(context)
+
+    assert str(exc_info.value) == "string index out of range"
+
+
+def test_floatformat_float_with_exponent(assert_render):
+    template = "{{ a|floatformat:2 }}"
+    assert_render(template, {"a": 1.5e20}, "150000000000000000000.00")
+
+
+def test_floatformat_missing(assert_render):
+    assert_render("{{ a|floatformat }}", {}, "")
+
+
+
+def test_floatformat_unlocalized_grouping(assert_render, settings):
+    settings.USE_THOUSAND_SEPARATOR = True
+    settings.NUMBER_GROUPING = 3
+    settings.THOUSAND_SEPARATOR = "!"
+    template = "{{ a|floatformat:'2gu' }} {{ a|floatformat:'2ug' }}"
+    with translation.override("de"):
+        assert_render(template, {"a": 66666.666}, "66!666.67 66!666.67")
@@ -0,0 +1,66 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_force_escape.py
+"""
+
+from django.utils.safestring import mark_safe
+
+
+def test_force_escape01(assert_render):
+    template = "{% autoescape off %}{{ a|force_escape }}{% endautoescape %}"
+    assert_render(template, {"a": "x&y"}, "x&amp;y")
+
+
+def test_force_escape02(assert_render):
+    assert_render("{{ a|force_escape }}", {"a": "x&y"}, "x&amp;y")
+
+
+def test_force_escape03(assert_render):
+    template = (
+        "{% autoescape off %}{{ a|force_escape|force_escape }}{% endautoescape %}"
+    )
+    assert_render(template, {"a": "x&y"}, "x&amp;amp;y")
+
+
+def test_force_escape04(assert_render):
+    assert_render("{{ a|force_escape|force_escape }}", {"a": "x&y"}, "x&amp;amp;y")
+
+
+def test_force_escape05(assert_render):
+    template = "{% autoescape off %}{{ a|force_escape|escape }}{% endautoescape %}"
+    assert_render(template, {"a": "x&y"}, "x&amp;y")
+
+
+def test_force_escape06(assert_render):
+    assert_render("{{ a|force_escape|escape }}", {"a": "x&y"}, "x&amp;y")
+
+
+def test_force_escape07(assert_render):
+    template = "{% autoescape off %}{{ a|escape|force_escape }}{% endautoescape %}"
+    assert_render(template, {"a": "x&y"}, "x&amp;amp;y")
+
+
+def test_force_escape08(assert_render):
+    assert_render("{{ a|escape|force_escape }}", {"a": "x&y"}, "x&amp;amp;y")
+
+
+def test_safe_input(assert_render):
+    template = "{{ a|force_escape }}"
+    context = {"a": mark_safe("<some html & 'special' characters > here")}
+    expected = "&lt;some html &amp; &#x27;special&#x27; characters &gt; here"
+    assert_render(template, context, expected)
+
+
+def test_unicode(assert_render):
+    template = "{{ a|force_escape }}"
+    context = {"a": "<some html & special characters > here ĐÅ€£"}
+    expected = "&lt;some html &amp; special characters &gt; here ĐÅ€£"
+    assert_render(template, context, expected)
+
+
+def test_non_string_input(assert_render):
+    assert_render("{{ a|force_escape }}", {"a": 123}, "123")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|force_escape }}", {}, "")
@@ -0,0 +1,80 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_get_digit.py
+"""
+
+import pytest
+from django.template import engines
+from django.template.exceptions import TemplateSyntaxError
+from django.utils.safestring import mark_safe
+
+
+@pytest.mark.parametrize(
+    "digit,expected",
+    [(1, "3"), (2, "2"), (3, "1"), (4, "0"), (0, "123"), (-1, "123")],
+)
+def test_values(assert_render, digit, expected):
+    template = "{{ value|get_digit:digit }}"
+    assert_render(template, {"value": 123, "digit": digit}, expected)
+
+
+def test_string(assert_render):
+    assert_render("{{ value|get_digit:0 }}", {"value": "xyz"}, "xyz")
+
+
+def test_integer_string(assert_render):
+    assert_render("{{ value|get_digit:'2' }}", {"value": "987"}, "8")
+
+
+def test_invalid_argument(assert_render):
+    template = "{{ value|get_digit:'a' }}"
+    assert_render(template, {"value": "<b>123</b>"}, "&lt;b&gt;123&lt;/b&gt;")
+
+
+def test_invalid_safe_value(assert_render):
+    template = "{{ value|get_digit:1 }}"
+    assert_render(template, {"value": mark_safe("<b>123</b>")}, "<b>123</b>")
+
+
+def test_missing_value(assert_render):
+    assert_render("{{ value|get_digit:1 }}", {}, "")
+
+
+def test_negative_value(assert_render):
+    assert_render("{{ value|get_digit:3 }}", {"value": -123}, "1")
+
+
+def test_negative_value_sign(rusty, django_template):
+    template = "{{ value|get_digit:4 }}"
+
+    with pytest.raises(ValueError) as exc_info:
+        django_template(template).render({"value": -123})
+
+    assert str(exc_info.value) == "invalid literal for int() with base 10: '-'"
+
+    with pytest.raises(ValueError) as exc_info:
+        rusty(template).render({"value": -123})
+
+    assert str(exc_info.value) == "invalid literal for int() with base 10: '-'"
+
+
+def test_get_digit_missing_argument():
+    template = "{{ value|get_digit }}"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "get_digit requires 2 arguments, 1 provided"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × Expected an argument
+   ╭────
+ 1 │ {{ value|get_digit }}
+   ·          ────┬────
+   ·              ╰── here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
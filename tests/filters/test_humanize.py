@@ -0,0 +1,294 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/humanize_tests/tests.py
+"""
+
+from datetime import date, datetime, timedelta, timezone
+from decimal import Decimal
+
+import pytest
+from django.template import engines
+from django.template.exceptions import TemplateSyntaxError
+from django.utils import translation
+from django.utils.safestring import mark_safe
+
+
+def render_filter(assert_render, filter, value, expected):
+    template = f"{{% load humanize %}}{{{{ value|{filter} }}}}"
+    assert_render(template, {"value": value}, expected)
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        ("1", "1st"),
+        ("2", "2nd"),
+        ("3", "3rd"),
+        ("4", "4th"),
+        ("11", "11th"),
+        ("12", "12th"),
+        ("13", "13th"),
+        ("101", "101st"),
+        ("102", "102nd"),
+        ("103", "103rd"),
+        ("111", "111th"),
+        (21, "21st"),
+        (True, "1st"),
+        (-1, "-1"),
+        ("something else", "something else"),
+        (None, "None"),
+    ],
+)
+def test_ordinal(assert_render, value, expected):
+    render_filter(assert_render, "ordinal", value, expected)
+
+
+def test_ordinal_missing_variable(assert_render):
+    assert_render("{% load humanize %}{{ value|ordinal }}", {}, "")
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (100, "100"),
+        (-100, "-100"),
+        (1000, "1,000"),
+        (-1000, "-1,000"),
+        (10123, "10,123"),
+        (-10123, "-10,123"),
+        (1000000, "1,000,000"),
+        (1234567.25, "1,234,567.25"),
+        (-1234567.25, "-1,234,567.25"),
+        (1e20, "100,000,000,000,000,000,000"),
+        (True, "1"),
+        ("100", "100"),
+        ("-100", "-100"),
+        ("100.1", "100.1"),
+        ("-100.1", "-100.1"),
+        ("1000", "1,000"),
+        ("10123", "10,123"),
+        ("-1000000", "-1,000,000"),
+        ("1234567.1234567", "1,234,567.1234567"),
+        ("-1234567.1234567", "-1,234,567.1234567"),
+        (Decimal("1234567.1234567"), "1,234,567.1234567"),
+        (Decimal("-1234567.1234567"), "-1,234,567.1234567"),
+        ("the quick brown fox", "the quick brown fox"),
+        (None, "None"),
+    ],
+)
+def test_intcomma(assert_render, value, expected):
+    render_filter(assert_render, "intcomma", value, expected)
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (1000, "1,000"),
+        (-1000, "-1,000"),
+        (1234567.25, "1,234,567.25"),
+        ("1234567.1234567", "1,234,567.1234567"),
+        (Decimal("1234567.1234567"), "1,234,567.1234567"),
+        (None, "None"),
+    ],
+)
+def test_intcomma_without_localization(assert_render, value, expected):
+    template = "{% load humanize %}{{ value|intcomma:False }}"
+    assert_render(template, {"value": value}, expected)
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (100, "100"),
+        (1000, "1.000"),
+        (10123, "10.123"),
+        (-10123, "-10.123"),
+        (1234567.25, "1.234.567,25"),
+        ("1000000", "1.000.000"),
+        ("1234567.1234567", "1,234,567.1234567"),
+        (Decimal("1234567.1234567"), "1.234.567,1234567"),
+    ],
+)
+def test_intcomma_localized(assert_render, value, expected):
+    with translation.override("de"):
+        render_filter(assert_render, "intcomma", value, expected)
+
+
+def test_intcomma_keeps_safe_strings(assert_render):
+    template = "{% load humanize %}{{ safe|intcomma }} {{ unsafe|intcomma }}"
+    context = {"safe": mark_safe("<b>1000</b>"), "unsafe": "<b>1000</b>"}
+    assert_render(template, context, "<b>1000</b> &lt;b&gt;1000&lt;/b&gt;")
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        ("100", "100"),
+        ("1000000", "1.0 million"),
+        ("1200000", "1.2 million"),
+        ("1290000", "1.3 million"),
+        ("1000000000", "1.0 billion"),
+        ("2000000000", "2.0 billion"),
+        ("6000000000000", "6.0 trillion"),
+        ("1300000000000000", "1.3 quadrillion"),
+        ("3500000000000000000000", "3.5 sextillion"),
+        ("8100000000000000000000000000000000", "8.1 decillion"),
+        ("1" + "0" * 100, "1.0 googol"),
+        ("1" + "0" * 104, "1" + "0" * 104),
+        (None, "None"),
+        ("-100", "-100"),
+        ("-1000000", "-1.0 million"),
+        ("-1200000", "-1.2 million"),
+        ("-1290000", "-1.3 million"),
+        ("-1000000000", "-1.0 billion"),
+        ("-6000000000000", "-6.0 trillion"),
+        ("-1300000000000000", "-1.3 quadrillion"),
+        (999_950_000, "1000.0 million"),
+        (1.9e7, "19.0 million"),
+        ("not a number", "not a number"),
+    ],
+)
+def test_intword(assert_render, value, expected):
+    render_filter(assert_render, "intword", value, expected)
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        ("100", "100"),
+        ("1000000", "1,0 Million"),
+        ("1200000", "1,2 Millionen"),
+        ("1290000", "1,3 Millionen"),
+        ("1000000000", "1,0 Milliarde"),
+        ("2000000000", "2,0 Milliarden"),
+        ("6000000000000", "6,0 Billionen"),
+    ],
+)
+def test_intword_localized(assert_render, value, expected):
+    with translation.override("de"):
+        render_filter(assert_render, "intword", value, expected)
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (0, "0"),
+        (1, "one"),
+        (2, "two"),
+        (3, "three"),
+        (4, "four"),
+        (5, "five"),
+        (6, "six"),
+        (7, "seven"),
+        (8, "eight"),
+        (9, "nine"),
+        (10, "10"),
+        ("5", "five"),
+        ("not a number", "not a number"),
+        (None, "None"),
+    ],
+)
+def test_apnumber(assert_render, value, expected):
+    render_filter(assert_render, "apnumber", value, expected)
+
+
+def test_apnumber_localized(assert_render):
+    with translation.override("de"):
+        render_filter(assert_render, "apnumber", 3, "drei")
+
+
+def test_naturalday(assert_render):
+    today = date.today()
+    template = (
+        "{% load humanize %}"
+        "{{ today|naturalday }} {{ yesterday|naturalday }} "
+        "{{ tomorrow|naturalday }} {{ someday|naturalday }} "
+        "{{ someday|naturalday:'Y-m-d' }} {{ text|naturalday }}"
+    )
+    context = {
+        "today": today,
+        "yesterday": today - timedelta(days=1),
+        "tomorrow": today + timedelta(days=1),
+        "someday": date(1982, 1, 9),
+        "text": "not a date",
+    }
+    expected = "today yesterday tomorrow Jan. 9, 1982 1982-01-09 not a date"
+    assert_render(template, context, expected)
+
+
+def test_naturalday_aware_datetime(assert_render):
+    template = "{% load humanize %}{{ value|naturalday }}"
+    assert_render(template, {"value": datetime.now(timezone.utc)}, "today")
+
+
+def test_naturalday_localized(assert_render):
+    with translation.override("de"):
+        render_filter(assert_render, "naturalday", date.today(), "heute")
+
+
+@pytest.mark.parametrize(
+    "delta,expected",
+    [
+        (timedelta(minutes=-5, seconds=-30), "5\xa0minutes ago"),
+        (timedelta(minutes=-1, seconds=-30), "a minute ago"),
+        (timedelta(hours=-2, minutes=-30), "2\xa0hours ago"),
+        (timedelta(hours=-1, minutes=-30), "an hour ago"),
+        (timedelta(days=-3, seconds=-30), "3\xa0days ago"),
+        (timedelta(days=-400, seconds=-30), "1\xa0year, 1\xa0month ago"),
+        (timedelta(minutes=5, seconds=30), "5\xa0minutes from now"),
+        (timedelta(hours=1, minutes=30), "an hour from now"),
+        (timedelta(days=2, hours=1, seconds=30), "2\xa0days, 1\xa0hour from now"),
+    ],
+)
+def test_naturaltime(assert_render, delta, expected):
+    render_filter(assert_render, "naturaltime", datetime.now() + delta, expected)
+
+
+def test_naturaltime_aware_datetime(assert_render):
+    value = datetime.now(timezone(timedelta(hours=5))) - timedelta(days=3, seconds=30)
+    render_filter(assert_render, "naturaltime", value, "3\xa0days ago")
+
+
+@pytest.mark.parametrize("value", ["test", None])
+def test_naturaltime_not_a_date(assert_render, value):
+    render_filter(assert_render, "naturaltime", value, str(value))
+
+
+@pytest.mark.parametrize(
+    "delta,expected",
+    [
+        (timedelta(days=-2, seconds=-30), "před 2\xa0dny"),
+        (timedelta(days=2, seconds=30), "za 2\xa0dny"),
+    ],
+)
+def test_naturaltime_localized(assert_render, delta, expected):
+    value = datetime.now() + delta
+    with translation.override("cs"):
+        render_filter(assert_render, "naturaltime", value, expected)
+
+
+def test_load_from_humanize(assert_render):
+    template = "{% load intcomma from humanize %}{{ value|intcomma }}"
+    assert_render(template, {"value": 1000}, "1,000")
+
+
+def test_ordinal_with_argument():
+    template = "{% load humanize %}{{ value|ordinal:1 }}"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "ordinal requires 1 arguments, 2 provided"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × ordinal filter does not take an argument
+   ╭────
+ 1 │ {% load humanize %}{{ value|ordinal:1 }}
+   ·                                     ┬
+   ·                                     ╰── unexpected argument
+   ╰────
+"""
+    assert str(exc_info.value) == expected
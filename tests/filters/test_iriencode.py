@@ -0,0 +1,66 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_iriencode.py
+"""
+
+import pytest
+from django.template import engines
+from django.template.exceptions import TemplateSyntaxError
+from django.utils.safestring import mark_safe
+
+
+def test_iriencode01(assert_render):
+    template = "{{ url|iriencode }}"
+    assert_render(template, {"url": "?test=1&me=2"}, "?test=1&amp;me=2")
+
+
+def test_iriencode02(assert_render):
+    template = "{% autoescape off %}{{ url|iriencode }}{% endautoescape %}"
+    assert_render(template, {"url": "?test=1&me=2"}, "?test=1&me=2")
+
+
+def test_iriencode03(assert_render):
+    template = "{{ url|iriencode }}"
+    assert_render(template, {"url": mark_safe("?test=1&me=2")}, "?test=1&me=2")
+
+
+def test_iriencode04(assert_render):
+    template = "{% autoescape off %}{{ url|iriencode }}{% endautoescape %}"
+    assert_render(template, {"url": mark_safe("?test=1&me=2")}, "?test=1&me=2")
+
+
+def test_unicode(assert_render):
+    template = "{{ value|iriencode }}"
+    assert_render(template, {"value": "S\xf8r-R\xf8d"}, "S%C3%B8r-R%C3%B8d")
+
+
+def test_urlencoded(assert_render):
+    template = "{{ value|urlencode|iriencode }}"
+    context = {"value": "fran\xe7ois & jill"}
+    assert_render(template, context, "fran%C3%A7ois%20%26%20jill")
+
+
+def test_missing_value(assert_render):
+    assert_render("{{ value|iriencode }}", {}, "")
+
+
+def test_iriencode_with_argument():
+    template = "{{ url|iriencode:1 }}"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "iriencode requires 1 arguments, 2 provided"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × iriencode filter does not take an argument
+   ╭────
+ 1 │ {{ url|iriencode:1 }}
+   ·                  ┬
+   ·                  ╰── unexpected argument
+   ╰────
+"""
+    assert str(exc_info.value) == expected
@@ -0,0 +1,120 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_join.py
+"""
+
+import pytest
+from django.template import engines
+from django.template.exceptions import TemplateSyntaxError
+from django.utils.safestring import mark_safe
+
+
+def test_join01(assert_render):
+    template = '{{ a|join:", " }}'
+    assert_render(template, {"a": ["alpha", "beta & me"]}, "alpha, beta &amp; me")
+
+
+def test_join02(assert_render):
+    template = '{% autoescape off %}{{ a|join:", " }}{% endautoescape %}'
+    assert_render(template, {"a": ["alpha", "beta & me"]}, "alpha, beta & me")
+
+
+def test_join03(assert_render):
+    template = '{{ a|join:" &amp; " }}'
+    context = {"a": ["alpha", "beta & me"]}
+    assert_render(template, context, "alpha &amp; beta &amp; me")
+
+
+def test_join04(assert_render):
+    template = '{% autoescape off %}{{ a|join:" &amp; " }}{% endautoescape %}'
+    context = {"a": ["alpha", "beta & me"]}
+    assert_render(template, context, "alpha &amp; beta & me")
+
+
+def test_join05(assert_render):
+    template = "{{ a|join:var }}"
+    context = {"a": ["alpha", "beta & me"], "var": " & "}
+    assert_render(template, context, "alpha &amp; beta &amp; me")
+
+
+def test_join06(assert_render):
+    template = "{% autoescape off %}{{ a|join:var }}{% endautoescape %}"
+    context = {"a": ["alpha", "beta & me"], "var": " & "}
+    assert_render(template, context, "alpha & beta & me")
+
+
+def test_join07(assert_render):
+    template = "{{ a|join:var|lower }}"
+    context = {"a": ["Alpha", "Beta & me"], "var": mark_safe(" & ")}
+    assert_render(template, context, "alpha & beta &amp; me")
+
+
+def test_join08(assert_render):
+    template = "{% autoescape off %}{{ a|join:var|lower }}{% endautoescape %}"
+    context = {"a": ["Alpha", "Beta & me"], "var": mark_safe(" & ")}
+    assert_render(template, context, "alpha & beta & me")
+
+
+def test_autoescape(assert_render):
+    template = "{{ a|join:var }}"
+    context = {"a": ["<a>", "<img>", "</a>"], "var": "<br>"}
+    expected = "&lt;a&gt;&lt;br&gt;&lt;img&gt;&lt;br&gt;&lt;/a&gt;"
+    assert_render(template, context, expected)
+
+
+def test_autoescape_off(assert_render):
+    template = "{% autoescape off %}{{ var_list|join:var_joiner }}{% endautoescape %}"
+    context = {
+        "var_list": ["<p>Hello World!</p>", "beta & me", "<script>Hi!</script>"],
+        "var_joiner": "<br/>",
+    }
+    expected = "<p>Hello World!</p><br/>beta & me<br/><script>Hi!</script>"
+    assert_render(template, context, expected)
+
+
+def test_safe_items(assert_render):
+    template = '{{ a|join:", " }}'
+    context = {"a": [mark_safe("<b>"), "<i>"]}
+    assert_render(template, context, "<b>, &lt;i&gt;")
+
+
+def test_non_string_items(assert_render):
+    assert_render('{{ a|join:"-" }}', {"a": [1, 2, 3]}, "1-2-3")
+
+
+def test_string(assert_render):
+    assert_render('{{ a|join:"-" }}', {"a": "a&b"}, "a-&amp;-b")
+
+
+def test_noniterable_arg(assert_render):
+    assert_render('{{ a|join:"<br>" }}', {"a": 123}, "123")
+
+
+def test_noniterable_arg_autoescape_off(assert_render):
+    template = '{% autoescape off %}{{ a|join:"<br>" }}{% endautoescape %}'
+    assert_render(template, {"a": 123}, "123")
+
+
+def test_missing(assert_render):
+    assert_render('{{ a|join:"," }}', {}, "")
+
+
+def test_join_no_argument():
+    template = "{{ foo|join }}"
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "join requires 2 arguments, 1 provided"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × Expected an argument
+   ╭────
+ 1 │ {{ foo|join }}
+   ·        ──┬─
+   ·          ╰── here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
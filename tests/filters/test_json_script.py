@@ -0,0 +1,78 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_json_script.py
+"""
+
+import datetime
+from decimal import Decimal
+
+from django.utils.safestring import mark_safe
+
+
+def test_basic(assert_render):
+    template = '{{ value|json_script:"test_id" }}'
+    context = {"value": {"a": "testing\r\njson 'string\" <b>escaping</b>"}}
+    expected = (
+        '<script id="test_id" type="application/json">'
+        '{"a": "testing\\r\\njson \'string\\" '
+        '\\u003Cb\\u003Eescaping\\u003C/b\\u003E"}'
+        "</script>"
+    )
+    assert_render(template, context, expected)
+
+
+def test_without_id(assert_render):
+    template = "{{ value|json_script }}"
+    expected = '<script type="application/json">{}</script>'
+    assert_render(template, {"value": {}}, expected)
+
+
+def test_autoescape_off(assert_render):
+    template = (
+        "{% autoescape off %}{{ value|json_script:'test_id' }}{% endautoescape %}"
+    )
+    expected = '<script id="test_id" type="application/json">["\\u0026"]</script>'
+    assert_render(template, {"value": ["&"]}, expected)
+
+
+def test_element_id_escaped(assert_render):
+    template = "{{ value|json_script:element_id }}"
+    context = {"value": 1, "element_id": '"><b>'}
+    expected = (
+        '<script id="&quot;&gt;&lt;b&gt;" type="application/json">1</script>'
+    )
+    assert_render(template, context, expected)
+
+
+def test_element_id_safe(assert_render):
+    template = "{{ value|json_script:element_id }}"
+    context = {"value": 1, "element_id": mark_safe("a&amp;b")}
+    expected = '<script id="a&amp;b" type="application/json">1</script>'
+    assert_render(template, context, expected)
+
+
+def test_empty_element_id(assert_render):
+    template = '{{ value|json_script:"" }}'
+    expected = '<script type="application/json">1</script>'
+    assert_render(template, {"value": 1}, expected)
+
+
+def test_django_json_encoder(assert_render):
+    template = "{{ value|json_script }}"
+    context = {
+        "value": {
+            "date": datetime.date(2024, 2, 29),
+            "decimal": Decimal("1.50"),
+        }
+    }
+    expected = (
+        '<script type="application/json">'
+        '{"date": "2024-02-29", "decimal": "1.50"}'
+        "</script>"
+    )
+    assert_render(template, context, expected)
+
+
+def test_missing(assert_render):
+    template = "{{ value|json_script }}"
+    assert_render(template, {}, '<script type="application/json">""</script>')
@@ -0,0 +1,34 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_last.py
+"""
+
+from django.utils.safestring import mark_safe
+
+
+def test_last01(assert_render):
+    template = "{{ a|last }} {{ b|last }}"
+    context = {"a": ["x", "a&b"], "b": ["x", mark_safe("a&b")]}
+    assert_render(template, context, "a&amp;b a&b")
+
+
+def test_last02(assert_render):
+    template = "{% autoescape off %}{{ a|last }} {{ b|last }}{% endautoescape %}"
+    context = {"a": ["x", "a&b"], "b": ["x", mark_safe("a&b")]}
+    assert_render(template, context, "a&b a&b")
+
+
+def test_empty_list(assert_render):
+    assert_render("{{ a|last }}", {"a": []}, "")
+
+
+def test_string(assert_render):
+    assert_render("{{ a|last }}", {"a": "test"}, "t")
+
+
+def test_tuple(assert_render):
+    assert_render("{{ a|last }}", {"a": (1, 2, 3)}, "3")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|last }}", {}, "")
@@ -0,0 +1,51 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_length.py
+"""
+
+from django.utils.safestring import mark_safe
+
+
+def test_length01(assert_render):
+    assert_render("{{ list|length }}", {"list": ["4", None, True, {}]}, "4")
+
+
+def test_length02(assert_render):
+    assert_render("{{ list|length }}", {"list": []}, "0")
+
+
+def test_length03(assert_render):
+    assert_render("{{ string|length }}", {"string": ""}, "0")
+
+
+def test_length04(assert_render):
+    assert_render("{{ string|length }}", {"string": "django"}, "6")
+
+
+def test_length05(assert_render):
+    template = "{% if string|length == 6 %}Pass{% endif %}"
+    assert_render(template, {"string": mark_safe("django")}, "Pass")
+
+
+def test_length06(assert_render):
+    assert_render("{{ int|length }}", {"int": 7}, "0")
+
+
+def test_length07(assert_render):
+    assert_render("{{ None|length }}", {"None": None}, "0")
+
+
+def test_dict(assert_render):
+    assert_render("{{ a|length }}", {"a": {"a": 1, "b": 2}}, "2")
+
+
+def test_unicode(assert_render):
+    assert_render("{{ a|length }}", {"a": "Ångström"}, "8")
+
+
+def test_filter_output(assert_render):
+    assert_render('{{ a|default:"abc"|length }}', {}, "3")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|length }}", {}, "0")
@@ -0,0 +1,57 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_linebreaks.py
+"""
+
+from django.utils.safestring import mark_safe
+
+
+def test_linebreaks01(assert_render):
+    template = "{{ a|linebreaks }} {{ b|linebreaks }}"
+    context = {"a": "x&\ny", "b": mark_safe("x&\ny")}
+    assert_render(template, context, "<p>x&amp;<br>y</p> <p>x&<br>y</p>")
+
+
+def test_linebreaks02(assert_render):
+    template = (
+        "{% autoescape off %}{{ a|linebreaks }} {{ b|linebreaks }}{% endautoescape %}"
+    )
+    context = {"a": "x&\ny", "b": mark_safe("x&\ny")}
+    assert_render(template, context, "<p>x&<br>y</p> <p>x&<br>y</p>")
+
+
+def test_line(assert_render):
+    assert_render("{{ a|linebreaks }}", {"a": "line 1"}, "<p>line 1</p>")
+
+
+def test_newline(assert_render):
+    assert_render("{{ a|linebreaks }}", {"a": "line 1\nline 2"}, "<p>line 1<br>line 2</p>")
+
+
+def test_carriagereturn(assert_render):
+    assert_render("{{ a|linebreaks }}", {"a": "line 1\rline 2"}, "<p>line 1<br>line 2</p>")
+
+
+def test_carriagereturn_newline(assert_render):
+    template = "{{ a|linebreaks }}"
+    assert_render(template, {"a": "line 1\r\nline 2"}, "<p>line 1<br>line 2</p>")
+
+
+def test_paragraphs(assert_render):
+    template = "{{ a|linebreaks }}"
+    context = {"a": "line 1\n\n\nline 2\r\n\r\nline 3"}
+    expected = "<p>line 1</p>\n\n<p>line 2</p>\n\n<p>line 3</p>"
+    assert_render(template, context, expected)
+
+
+def test_non_string_input(assert_render):
+    assert_render("{{ a|linebreaks }}", {"a": 123}, "<p>123</p>")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|linebreaks }}", {}, "<p></p>")
+
+
+def test_output_is_safe(assert_render):
+    template = "{{ a|linebreaks|escape }}"
+    assert_render(template, {"a": "<b>"}, "<p>&lt;b&gt;</p>")
@@ -0,0 +1,42 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_linebreaksbr.py
+"""
+
+from django.utils.safestring import mark_safe
+
+
+def test_linebreaksbr01(assert_render):
+    template = "{{ a|linebreaksbr }} {{ b|linebreaksbr }}"
+    context = {"a": "x&\ny", "b": mark_safe("x&\ny")}
+    assert_render(template, context, "x&amp;<br>y x&<br>y")
+
+
+def test_linebreaksbr02(assert_render):
+    template = (
+        "{% autoescape off %}{{ a|linebreaksbr }} {{ b|linebreaksbr }}"
+        "{% endautoescape %}"
+    )
+    context = {"a": "x&\ny", "b": mark_safe("x&\ny")}
+    assert_render(template, context, "x&<br>y x&<br>y")
+
+
+def test_newline(assert_render):
+    assert_render("{{ a|linebreaksbr }}", {"a": "line 1\nline 2"}, "line 1<br>line 2")
+
+
+def test_carriagereturn(assert_render):
+    assert_render("{{ a|linebreaksbr }}", {"a": "line 1\rline 2"}, "line 1<br>line 2")
+
+
+def test_carriagereturn_newline(assert_render):
+    template = "{{ a|linebreaksbr }}"
+    assert_render(template, {"a": "line 1\r\nline 2"}, "line 1<br>line 2")
+
+
+def test_non_string_input(assert_render):
+    assert_render("{{ a|linebreaksbr }}", {"a": 123}, "123")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|linebreaksbr }}", {}, "")
@@ -0,0 +1,38 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_linenumbers.py
+"""
+
+from django.utils.safestring import mark_safe
+
+
+def test_linenumbers(assert_render):
+    template = "{{ a|linenumbers }} {{ b|linenumbers }}"
+    context = {"a": "one\n<two>\nthree", "b": mark_safe("one\n&lt;two&gt;\nthree")}
+    expected = "1. one\n2. &lt;two&gt;\n3. three 1. one\n2. &lt;two&gt;\n3. three"
+    assert_render(template, context, expected)
+
+
+def test_linenumbers2(assert_render):
+    template = (
+        "{% autoescape off %}{{ a|linenumbers }} {{ b|linenumbers }}"
+        "{% endautoescape %}"
+    )
+    context = {"a": "one\n<two>\nthree", "b": mark_safe("one\n&lt;two&gt;\nthree")}
+    expected = "1. one\n2. <two>\n3. three 1. one\n2. &lt;two&gt;\n3. three"
+    assert_render(template, context, expected)
+
+
+def test_linenumbers_padding(assert_render):
+    template = "{{ a|linenumbers }}"
+    context = {"a": "x\n" * 9 + "x"}
+    expected = "\n".join(f"{n:02}. x" for n in range(1, 11))
+    assert_render(template, context, expected)
+
+
+def test_non_string_input(assert_render):
+    assert_render("{{ a|linenumbers }}", {"a": 123}, "1. 123")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|linenumbers }}", {}, "1. ")
@@ -0,0 +1,51 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_ljust.py
+"""
+
+import pytest
+from django.utils.safestring import mark_safe
+
+
+def test_ljust01(assert_render):
+    template = (
+        '{% autoescape off %}.{{ a|ljust:"5" }}. .{{ b|ljust:"5" }}.{% endautoescape %}'
+    )
+    context = {"a": "a&b", "b": mark_safe("a&b")}
+    assert_render(template, context, ".a&b  . .a&b  .")
+
+
+def test_ljust02(assert_render):
+    template = '.{{ a|ljust:"5" }}. .{{ b|ljust:"5" }}.'
+    context = {"a": "a&b", "b": mark_safe("a&b")}
+    assert_render(template, context, ".a&amp;b  . .a&b  .")
+
+
+def test_ljust(assert_render):
+    assert_render("{{ a|ljust:10 }}.", {"a": "test"}, "test      .")
+
+
+def test_less_than_string_length(assert_render):
+    assert_render("{{ a|ljust:3 }}.", {"a": "test"}, "test.")
+
+
+def test_negative(assert_render):
+    assert_render("{{ a|ljust:-1 }}.", {"a": "test"}, "test.")
+
+
+def test_non_string_input(assert_render):
+    assert_render("{{ a|ljust:4 }}.", {"a": 123}, "123 .")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|ljust:3 }}.", {}, "   .")
+
+
+def test_too_wide(rusty, django_template):
+    template = '{{ a|ljust:"99999999999999999" }}'
+
+    with pytest.raises(MemoryError):
+        django_template(template).render({"a": "test"})
+
+    with pytest.raises(MemoryError):
+        rusty(template).render({"a": "test"})
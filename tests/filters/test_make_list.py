@@ -0,0 +1,31 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_make_list.py
+"""
+
+from django.utils.safestring import mark_safe
+
+
+def test_make_list01(assert_render):
+    template = "{% autoescape off %}{{ a|make_list }}{% endautoescape %}"
+    assert_render(template, {"a": mark_safe("&")}, "['&']")
+
+
+def test_make_list02(assert_render):
+    assert_render("{{ a|make_list }}", {"a": mark_safe("&")}, "[&#x27;&amp;&#x27;]")
+
+
+def test_string(assert_render):
+    assert_render('{{ a|make_list|join:"," }}', {"a": "abc"}, "a,b,c")
+
+
+def test_integer(assert_render):
+    assert_render('{{ a|make_list|join:"," }}', {"a": 1234}, "1,2,3,4")
+
+
+def test_length(assert_render):
+    assert_render("{{ a|make_list|length }}", {"a": "Ångström"}, "8")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|make_list }}", {}, "[]")
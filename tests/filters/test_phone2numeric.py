@@ -0,0 +1,52 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_phone2numeric.py
+"""
+
+import pytest
+from django.utils.safestring import mark_safe
+
+
+def test_phone2numeric01(assert_render):
+    template = "{{ a|phone2numeric }} {{ b|phone2numeric }}"
+    context = {"a": "<1-800-call-me>", "b": mark_safe("<1-800-call-me>")}
+    assert_render(template, context, "&lt;1-800-2255-63&gt; <1-800-2255-63>")
+
+
+def test_phone2numeric02(assert_render):
+    template = (
+        "{% autoescape off %}"
+        "{{ a|phone2numeric }} {{ b|phone2numeric }}"
+        "{% endautoescape %}"
+    )
+    context = {"a": "<1-800-call-me>", "b": mark_safe("<1-800-call-me>")}
+    assert_render(template, context, "<1-800-2255-63> <1-800-2255-63>")
+
+
+def test_phone2numeric03(assert_render):
+    template = "{{ a|phone2numeric }}"
+    context = {"a": "How razorback-jumping frogs can level six piqued gymnasts!"}
+    expected = "469 729672225-5867464 37647 226 53835 749 747833 49662787!"
+    assert_render(template, context, expected)
+
+
+def test_phone2numeric(assert_render):
+    assert_render("{{ a|phone2numeric }}", {"a": "0800 flowers"}, "0800 3569377")
+
+
+def test_missing_value(assert_render):
+    assert_render("{{ a|phone2numeric }}", {}, "")
+
+
+def test_non_string(rusty, django_template):
+    template = "{{ a|phone2numeric }}"
+
+    with pytest.raises(AttributeError) as exc_info:
+        django_template(template).render({"a": 1})
+
+    assert str(exc_info.value) == "'int' object has no attribute 'lower'"
+
+    with pytest.raises(AttributeError) as exc_info:
+        rusty(template).render({"a": 1})
+
+    assert str(exc_info.value) == "'int' object has no attribute 'lower'"
@@ -0,0 +1,91 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_pluralize.py
+"""
+
+from decimal import Decimal
+
+import pytest
+
+
+@pytest.mark.parametrize(
+    "value,expected", [("0", "votes"), ("1", "vote"), ("2", "votes")]
+)
+def test_no_arguments(assert_render, value, expected):
+    assert_render("vote{{ value|pluralize }}", {"value": value}, expected)
+
+
+@pytest.mark.parametrize(
+    "value,expected", [("0", "classes"), ("1", "class"), ("2", "classes")]
+)
+def test_suffix(assert_render, value, expected):
+    assert_render('class{{ value|pluralize:"es" }}', {"value": value}, expected)
+
+
+@pytest.mark.parametrize(
+    "value,expected", [("0", "candies"), ("1", "candy"), ("2", "candies")]
+)
+def test_singular_and_plural_suffix(assert_render, value, expected):
+    template = 'cand{{ value|pluralize:"y,ies" }}'
+    assert_render(template, {"value": value}, expected)
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (1, ""),
+        (0, "s"),
+        (2, "s"),
+        (0.5, "s"),
+        (1.5, "s"),
+        (1.0, ""),
+        (True, ""),
+        (Decimal(1), ""),
+        (Decimal(0), "s"),
+        (Decimal(2), "s"),
+        ([1], ""),
+        ([], "s"),
+        ([1, 2, 3], "s"),
+        ("1.0", ""),
+        (" 1 ", ""),
+        ("one", ""),
+    ],
+)
+def test_values(assert_render, value, expected):
+    assert_render("{{ value|pluralize }}", {"value": value}, expected)
+
+
+@pytest.mark.parametrize(
+    "value,argument,expected",
+    [
+        (1, "es", ""),
+        (0, "es", "es"),
+        (2, "es", "es"),
+        (1, "y,ies", "y"),
+        (0, "y,ies", "ies"),
+        (2, "y,ies", "ies"),
+        (0, "y,ies,error", ""),
+    ],
+)
+def test_suffixes(assert_render, value, argument, expected):
+    template = "{{ value|pluralize:argument }}"
+    assert_render(template, {"value": value, "argument": argument}, expected)
+
+
+def test_no_len_type(assert_render):
+    template = '{{ value|pluralize:"y,es" }}{{ value|pluralize:"es" }}'
+    assert_render(template, {"value": object()}, "")
+
+
+def test_value_error(assert_render):
+    template = '{{ value|pluralize:"y,es" }}{{ value|pluralize:"es" }}'
+    assert_render(template, {"value": ""}, "")
+
+
+def test_escaped_suffix(assert_render):
+    template = '{{ value|pluralize:"<b>" }}'
+    assert_render(template, {"value": 2}, "&lt;b&gt;")
+
+
+def test_missing(assert_render):
+    assert_render("{{ value|pluralize }}", {}, "")
@@ -0,0 +1,32 @@
+from django.utils.safestring import mark_safe
+
+
+def test_pprint(assert_render):
+    template = "{{ a|pprint }}"
+    context = {"a": {"b": [1, "<c>"]}}
+    assert_render(template, context, "{&#x27;b&#x27;: [1, &#x27;&lt;c&gt;&#x27;]}")
+
+
+def test_pprint_autoescape_off(assert_render):
+    template = "{% autoescape off %}{{ a|pprint }}{% endautoescape %}"
+    context = {"a": {"b": [1, "<c>"]}}
+    assert_render(template, context, "{'b': [1, '<c>']}")
+
+
+def test_pprint_safe(assert_render):
+    template = "{{ a|pprint }}"
+    assert_render(template, {"a": mark_safe("<b>")}, "'<b>'")
+
+
+def test_pprint_missing_value(assert_render):
+    assert_render("{{ a|pprint }}", {}, "&#x27;&#x27;")
+
+
+def test_pprint_error(assert_render):
+    class BrokenRepr:
+        def __repr__(self):
+            raise ValueError("broken")
+
+    template = "{{ a|pprint }}"
+    context = {"a": BrokenRepr()}
+    assert_render(template, context, "Error in formatting: ValueError: broken")
@@ -0,0 +1,39 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_random.py
+"""
+
+from django.utils.safestring import mark_safe
+
+
+def test_random01(assert_render):
+    template = "{{ a|random }} {{ b|random }}"
+    context = {"a": ["a&b", "a&b"], "b": [mark_safe("a&b"), mark_safe("a&b")]}
+    assert_render(template, context, "a&amp;b a&b")
+
+
+def test_random02(assert_render):
+    template = "{% autoescape off %}{{ a|random }} {{ b|random }}{% endautoescape %}"
+    context = {"a": ["a&b", "a&b"], "b": [mark_safe("a&b"), mark_safe("a&b")]}
+    assert_render(template, context, "a&b a&b")
+
+
+def test_empty_list(assert_render):
+    assert_render("{{ a|random }}", {"a": []}, "")
+
+
+def test_string(assert_render):
+    assert_render("{{ a|random }}", {"a": "aaa"}, "a")
+
+
+def test_safe_string(assert_render):
+    assert_render("{{ a|random }}", {"a": mark_safe("&&&")}, "&")
+
+
+def test_choice(rusty):
+    template = rusty("{{ a|random }}")
+    assert template.render({"a": [1, 2, 3]}) in {"1", "2", "3"}
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|random }}", {}, "")
@@ -0,0 +1,51 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_rjust.py
+"""
+
+import pytest
+from django.utils.safestring import mark_safe
+
+
+def test_rjust01(assert_render):
+    template = (
+        '{% autoescape off %}.{{ a|rjust:"5" }}. .{{ b|rjust:"5" }}.{% endautoescape %}'
+    )
+    context = {"a": "a&b", "b": mark_safe("a&b")}
+    assert_render(template, context, ".  a&b. .  a&b.")
+
+
+def test_rjust02(assert_render):
+    template = '.{{ a|rjust:"5" }}. .{{ b|rjust:"5" }}.'
+    context = {"a": "a&b", "b": mark_safe("a&b")}
+    assert_render(template, context, ".  a&amp;b. .  a&b.")
+
+
+def test_rjust(assert_render):
+    assert_render("{{ a|rjust:10 }}.", {"a": "test"}, "      test.")
+
+
+def test_less_than_string_length(assert_render):
+    assert_render("{{ a|rjust:3 }}.", {"a": "test"}, "test.")
+
+
+def test_negative(assert_render):
+    assert_render("{{ a|rjust:-1 }}.", {"a": "test"}, "test.")
+
+
+def test_non_string_input(assert_render):
+    assert_render("{{ a|rjust:4 }}.", {"a": 123}, " 123.")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|rjust:3 }}.", {}, "   .")
+
+
+def test_too_wide(rusty, django_template):
+    template = '{{ a|rjust:"99999999999999999" }}'
+
+    with pytest.raises(MemoryError):
+        django_template(template).render({"a": "test"})
+
+    with pytest.raises(MemoryError):
+        rusty(template).render({"a": "test"})
@@ -0,0 +1,29 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_safeseq.py
+"""
+
+
+def test_safeseq01(assert_render):
+    template = '{{ a|join:", " }} -- {{ a|safeseq|join:", " }}'
+    assert_render(template, {"a": ["&", "<"]}, "&amp;, &lt; -- &, <")
+
+
+def test_safeseq02(assert_render):
+    template = (
+        '{% autoescape off %}{{ a|join:", " }} -- {{ a|safeseq|join:", " }}'
+        "{% endautoescape %}"
+    )
+    assert_render(template, {"a": ["&", "<"]}, "&, < -- &, <")
+
+
+def test_string(assert_render):
+    assert_render('{{ a|safeseq|join:"," }}', {"a": "<&>"}, "<,&,>")
+
+
+def test_first(assert_render):
+    assert_render("{{ a|safeseq|first }}", {"a": ["<b>", "<i>"]}, "<b>")
+
+
+def test_missing(assert_render):
+    assert_render('{{ a|safeseq|join:"," }}', {}, "")
@@ -0,0 +1,102 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_slice.py
+"""
+
+import pytest
+from django.utils.safestring import mark_safe
+
+
+def test_slice01(assert_render):
+    template = '{{ a|slice:"1:3" }} {{ b|slice:"1:3" }}'
+    context = {"a": "a&b", "b": mark_safe("a&b")}
+    assert_render(template, context, "&amp;b &b")
+
+
+def test_slice02(assert_render):
+    template = (
+        '{% autoescape off %}{{ a|slice:"1:3" }} {{ b|slice:"1:3" }}{% endautoescape %}'
+    )
+    context = {"a": "a&b", "b": mark_safe("a&b")}
+    assert_render(template, context, "&b &b")
+
+
+@pytest.mark.parametrize(
+    "argument,expected",
+    [
+        ('"0"', ""),
+        ('"1"', "a"),
+        ("1", "a"),
+        ('"-1"', "abcdef"),
+        ('"1:2"', "b"),
+        ('"1:3"', "bc"),
+        ('"0::2"', "aceg"),
+        ('"::-1"', "gfedcba"),
+        ('"-2:"', "fg"),
+        ('"::0"', "abcdefg"),
+        ('"a"', "abcdefg"),
+        ('"1:2:3:4"', "abcdefg"),
+    ],
+)
+def test_string(assert_render, argument, expected):
+    template = f"{{{{ a|slice:{argument} }}}}"
+    assert_render(template, {"a": "abcdefg"}, expected)
+
+
+@pytest.mark.parametrize(
+    "argument,expected",
+    [
+        ('"0"', ""),
+        ('"1"', "a"),
+        ('"-1"', "abcdef"),
+        ('"1:3"', "bc"),
+        ('"0::2"', "aceg"),
+    ],
+)
+def test_filter_output(assert_render, argument, expected):
+    template = f'{{{{ a|default:"abcdefg"|slice:{argument} }}}}'
+    assert_render(template, {}, expected)
+
+
+def test_list(assert_render):
+    assert_render('{{ a|slice:":2" }}', {"a": [1, 2, 3]}, "[1, 2]")
+
+
+def test_list_step(assert_render):
+    assert_render('{{ a|slice:"1:-1:2" }}', {"a": [1, 2, 3, 4, 5]}, "[2, 4]")
+
+
+def test_fail_silently(assert_render):
+    class Obj:
+        def __str__(self):
+            return "obj"
+
+    assert_render('{{ a|slice:"0::2" }}', {"a": Obj()}, "obj")
+
+
+def test_empty_dict(assert_render):
+    assert_render('{{ a|slice:"1" }}', {"a": {}}, "{}")
+
+
+def test_float_argument(assert_render):
+    assert_render("{{ a|slice:1.0 }}", {"a": "abc"}, "abc")
+
+
+def test_missing(assert_render):
+    assert_render('{{ a|slice:"1" }}', {}, "")
+
+
+@pytest.mark.parametrize(
+    "argument,expected",
+    [
+        ("2::9223372036854775807", "c"),
+        ("::-9223372036854775808", "c"),
+        ("1:99999999999999999999", "bc"),
+        ("-99999999999999999999:2", "ab"),
+        ("::99999999999999999999", "a"),
+    ],
+)
+def test_large_bounds(assert_render, argument, expected):
+    template = f'{{{{ a|slice:"{argument}" }}}} {{{{ b|slice:"{argument}"|join:"" }}}}'
+    context = {"a": "abc", "b": list("abc")}
+    assert_render(template, context, f"{expected} {expected}")
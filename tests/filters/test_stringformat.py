@@ -0,0 +1,114 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_stringformat.py
+"""
+
+import pytest
+from django.template import engines
+from django.template.exceptions import TemplateSyntaxError
+from django.utils.safestring import mark_safe
+
+
+def test_stringformat01(assert_render):
+    template = (
+        "{% autoescape off %}"
+        '.{{ a|stringformat:"5s" }}. .{{ b|stringformat:"5s" }}.'
+        "{% endautoescape %}"
+    )
+    context = {"a": "a<b", "b": mark_safe("a<b")}
+    assert_render(template, context, ".  a<b. .  a<b.")
+
+
+def test_stringformat02(assert_render):
+    template = '.{{ a|stringformat:"5s" }}. .{{ b|stringformat:"5s" }}.'
+    context = {"a": "a<b", "b": mark_safe("a<b")}
+    assert_render(template, context, ".  a&lt;b. .  a<b.")
+
+
+@pytest.mark.parametrize(
+    "value,spec,expected",
+    [
+        (1, "03d", "001"),
+        ([1, None], "s", "[1, None]"),
+        ((1, 2, 3), "s", "(1, 2, 3)"),
+        ((1,), "s", "(1,)"),
+        ({1, 2}, "s", "{1, 2}"),
+        ({1: 2, 2: 3}, "s", "{1: 2, 2: 3}"),
+    ],
+)
+def test_format(assert_render, value, spec, expected):
+    template = "{{ value|stringformat:spec }}"
+    assert_render(template, {"value": value, "spec": spec}, expected)
+
+
+@pytest.mark.parametrize(
+    "value,spec",
+    [(1, "z"), (object(), "d"), (None, "d"), ((1, 2, 3), "d")],
+)
+def test_invalid(assert_render, value, spec):
+    template = "{{ value|stringformat:spec }}"
+    assert_render(template, {"value": value, "spec": spec}, "")
+
+
+@pytest.mark.parametrize(
+    "value,spec,expected",
+    [
+        (42, "+06d", "+00042"),
+        (-42, "-6d", "-42   "),
+        (True, "d", "1"),
+        (2.9, "d", "2"),
+        (255, "#X", "0XFF"),
+        (8, "#o", "0o10"),
+        (3.14159, ".2f", "3.14"),
+        (0.125, ".2f", "0.12"),
+        (float("nan"), "F", "NAN"),
+        ("abc", ".2s", "ab"),
+        ("abc", "x", ""),
+        (1.5, "s", "1.5"),
+        (1e20, "s", "1e+20"),
+        (1234.5, "e", "1.234500e+03"),
+        (1234.5, ".3g", "1.23e+03"),
+        ("a", "r", "&#x27;a&#x27;"),
+        (65, "c", "A"),
+        (5, "5d%%", "    5%"),
+    ],
+)
+def test_conversions(assert_render, value, spec, expected):
+    template = "{{ value|stringformat:spec }}"
+    assert_render(template, {"value": value, "spec": spec}, expected)
+
+
+def test_integer_argument(assert_render):
+    assert_render("{{ value|stringformat:5 }}", {"value": 1}, "")
+
+
+def test_missing_value(assert_render):
+    template = "{{ value|stringformat:'s' }}|{{ value|stringformat:'d' }}"
+    assert_render(template, {}, "|")
+
+
+def test_safe_value(assert_render):
+    template = "{{ value|safe|stringformat:'5s' }}"
+    assert_render(template, {"value": "<b>"}, "  <b>")
+
+
+def test_stringformat_missing_argument():
+    template = "{{ a|stringformat }}"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "stringformat requires 2 arguments, 1 provided"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × Expected an argument
+   ╭────
+ 1 │ {{ a|stringformat }}
+   ·      ──────┬─────
+   ·            ╰── here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
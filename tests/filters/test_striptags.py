@@ -0,0 +1,71 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_striptags.py
+"""
+
+from django.utils.safestring import mark_safe
+
+
+def test_strip01(assert_render):
+    template = "{{ a|striptags }} {{ b|striptags }}"
+    context = {
+        "a": "<a>x</a> <p><b>y</b></p>",
+        "b": mark_safe("<a>x</a> <p><b>y</b></p>"),
+    }
+    assert_render(template, context, "x y x y")
+
+
+def test_strip02(assert_render):
+    template = (
+        "{% autoescape off %}{{ a|striptags }} {{ b|striptags }}{% endautoescape %}"
+    )
+    context = {
+        "a": "<a>x</a> <p><b>y</b></p>",
+        "b": mark_safe("<a>x</a> <p><b>y</b></p>"),
+    }
+    assert_render(template, context, "x y x y")
+
+
+def test_strip(assert_render):
+    template = "{% autoescape off %}{{ a|striptags }}{% endautoescape %}"
+    context = {
+        "a": 'some <b>html</b> with <script>alert("You smell")</script> '
+        "disallowed <img /> tags"
+    }
+    expected = 'some html with alert("You smell") disallowed  tags'
+    assert_render(template, context, expected)
+
+
+def test_strip_autoescape(assert_render):
+    template = "{{ a|striptags }}"
+    context = {"a": '<p onclick="x">a &amp; b</p> "quoted"'}
+    assert_render(template, context, "a &amp;amp; b &quot;quoted&quot;")
+
+
+def test_strip_safe(assert_render):
+    template = "{{ a|striptags }}"
+    context = {"a": mark_safe('<p onclick="x">a &amp; b</p> "quoted"')}
+    assert_render(template, context, 'a &amp; b "quoted"')
+
+
+def test_nested_tags(assert_render):
+    template = "{% autoescape off %}{{ a|striptags }}{% endautoescape %}"
+    context = {"a": "<sc<!-- -->ript>test<<!-- -->/script>"}
+    assert_render(template, context, "ript>test")
+
+
+def test_malformed(assert_render):
+    template = (
+        "{% autoescape off %}{{ a|striptags }} {{ b|striptags }} {{ c|striptags }}"
+        "{% endautoescape %}"
+    )
+    context = {"a": "a4<a5 right?", "b": "b7>b2!", "c": "&amp <f x"}
+    assert_render(template, context, "a4<a5 right? b7>b2! &amp <f x")
+
+
+def test_non_string_input(assert_render):
+    assert_render("{{ a|striptags }}", {"a": 123}, "123")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|striptags }}", {}, "")
@@ -0,0 +1,69 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_time.py
+"""
+
+from datetime import date, datetime, time, timedelta, timezone
+
+import pytest
+
+
+def test_time_default_format(assert_render):
+    template = "{{ t|time }}"
+    context = {"t": time(16, 25)}
+    assert_render(template, context, "4:25 p.m.")
+
+
+def test_time_format(assert_render):
+    template = '{{ t|time:"H:i:s" }}'
+    context = {"t": time(16, 25, 3)}
+    assert_render(template, context, "16:25:03")
+
+
+def test_time_named_format(assert_render):
+    template = '{{ t|time:"TIME_FORMAT" }}'
+    context = {"t": datetime(2008, 1, 1, 0, 0)}
+    assert_render(template, context, "midnight")
+
+
+def test_time_datetime(assert_render):
+    template = '{{ dt|time:"P" }}'
+    context = {"dt": datetime(2008, 1, 1, 12, 30)}
+    assert_render(template, context, "12:30 p.m.")
+
+
+def test_time_date_characters(assert_render):
+    template = '{{ dt|time:"Y" }}'
+    context = {"dt": datetime(2008, 1, 1, 12, 30)}
+    assert_render(template, context, "")
+
+
+def test_time_date_object(assert_render):
+    template = '{{ d|time:"H:i" }}'
+    context = {"d": date(2008, 1, 1)}
+    assert_render(template, context, "")
+
+
+def test_time_timezone_on_time_object(assert_render):
+    template = '{{ t|time:"H:i e O T Z" }}'
+    context = {"t": time(9, 5, tzinfo=timezone(timedelta(hours=2)))}
+    assert_render(template, context, "09:05    ")
+
+
+def test_time_aware(assert_render, settings):
+    settings.USE_TZ = True
+    settings.TIME_ZONE = "Asia/Tokyo"
+    template = '{{ dt|time:"H:i e" }}'
+    context = {"dt": datetime(2008, 1, 1, 0, 0, tzinfo=timezone.utc)}
+    assert_render(template, context, "09:00 JST")
+
+
+@pytest.mark.parametrize("value", [None, "", "not a time"])
+def test_time_invalid_value(assert_render, value):
+    template = '{{ t|time:"H:i" }}'
+    assert_render(template, {"t": value}, "")
+
+
+def test_time_missing_value(assert_render):
+    template = '{{ t|time:"H:i" }}'
+    assert_render(template, {}, "")
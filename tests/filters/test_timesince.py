@@ -0,0 +1,104 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_timesince.py
+"""
+
+from datetime import date, datetime, timedelta, timezone
+
+import pytest
+
+
+def test_timesince_minute(assert_render):
+    template = "{{ a|timesince }}"
+    context = {"a": datetime.now() - timedelta(minutes=1, seconds=10)}
+    assert_render(template, context, "1\xa0minute")
+
+
+def test_timesince_future(assert_render):
+    template = "{{ a|timesince }}"
+    context = {"a": datetime.now() + timedelta(hours=1, minutes=10, seconds=10)}
+    assert_render(template, context, "0\xa0minutes")
+
+
+def test_timesince_argument(assert_render):
+    template = "{{ a|timesince:b }}"
+    context = {"a": datetime(2007, 8, 14, 13, 46), "b": datetime(2008, 10, 1, 15, 0)}
+    assert_render(template, context, "1\xa0year, 1\xa0month")
+
+
+def test_timesince_depth(assert_render):
+    template = "{{ a|timesince:b }}"
+    context = {"a": datetime(2008, 1, 1, 0, 0), "b": datetime(2008, 1, 15, 3, 5)}
+    assert_render(template, context, "2\xa0weeks")
+
+
+def test_timesince_stops_at_zero_unit(assert_render):
+    template = "{{ a|timesince:b }}"
+    context = {"a": datetime(2008, 1, 1, 0, 0), "b": datetime(2008, 1, 2, 0, 5)}
+    assert_render(template, context, "1\xa0day")
+
+
+def test_timesince_same_time(assert_render):
+    template = "{{ a|timesince:b }}"
+    now = datetime(2008, 1, 1, 12, 0)
+    assert_render(template, {"a": now, "b": now}, "0\xa0minutes")
+
+
+def test_timesince_dates(assert_render):
+    template = "{{ a|timesince:b }}"
+    context = {"a": date(2007, 1, 1), "b": date(2008, 3, 1)}
+    assert_render(template, context, "1\xa0year, 2\xa0months")
+
+
+def test_timesince_aware(assert_render):
+    template = "{{ a|timesince:b }}"
+    tz = timezone(timedelta(hours=2))
+    context = {
+        "a": datetime(2008, 1, 1, 12, 0, tzinfo=tz),
+        "b": datetime(2008, 1, 1, 15, 30, tzinfo=tz),
+    }
+    assert_render(template, context, "3\xa0hours, 30\xa0minutes")
+
+
+def test_timesince_different_timezones(assert_render):
+    template = "{{ a|timesince:b }}"
+    context = {
+        "a": datetime(2008, 1, 1, 12, 0, tzinfo=timezone(timedelta(hours=2))),
+        "b": datetime(2008, 1, 1, 12, 0, tzinfo=timezone.utc),
+    }
+    assert_render(template, context, "2\xa0hours")
+
+
+def test_timesince_naive_and_aware(assert_render):
+    template = "{{ a|timesince:b }}"
+    context = {
+        "a": datetime(2008, 1, 1, 12, 0),
+        "b": datetime(2008, 1, 1, 13, 0, tzinfo=timezone.utc),
+    }
+    assert_render(template, context, "1\xa0hour")
+
+
+def test_timesince_leap_year(assert_render):
+    template = "{{ a|timesince:b }}"
+    context = {"a": date(2016, 2, 29), "b": date(2017, 2, 28)}
+    assert_render(template, context, "11\xa0months, 4\xa0weeks")
+
+
+def test_timesince_translated(assert_render):
+    from django.utils import translation
+
+    template = "{{ a|timesince:b }}"
+    context = {"a": datetime(2008, 1, 1, 0, 0), "b": datetime(2008, 1, 3, 1, 0)}
+    with translation.override("fr"):
+        assert_render(template, context, "2\xa0jours, 1\xa0heure")
+
+
+@pytest.mark.parametrize("value", [None, "", 0])
+def test_timesince_falsy(assert_render, value):
+    template = "{{ a|timesince }}"
+    assert_render(template, {"a": value}, "")
+
+
+def test_timesince_missing(assert_render):
+    template = "{{ a|timesince }}"
+    assert_render(template, {}, "")
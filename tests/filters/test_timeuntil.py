@@ -0,0 +1,47 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_timeuntil.py
+"""
+
+from datetime import date, datetime, timedelta, timezone
+
+
+def test_timeuntil_future(assert_render):
+    template = "{{ a|timeuntil }}"
+    context = {"a": datetime.now() + timedelta(minutes=2, seconds=10)}
+    assert_render(template, context, "2\xa0minutes")
+
+
+def test_timeuntil_past(assert_render):
+    template = "{{ a|timeuntil }}"
+    context = {"a": datetime.now() - timedelta(minutes=2, seconds=10)}
+    assert_render(template, context, "0\xa0minutes")
+
+
+def test_timeuntil_argument(assert_render):
+    template = "{{ a|timeuntil:b }}"
+    context = {"a": datetime(2008, 10, 1, 15, 0), "b": datetime(2007, 8, 14, 13, 46)}
+    assert_render(template, context, "1\xa0year, 1\xa0month")
+
+
+def test_timeuntil_argument_past(assert_render):
+    template = "{{ a|timeuntil:b }}"
+    context = {"a": datetime(2007, 8, 14, 13, 46), "b": datetime(2008, 10, 1, 15, 0)}
+    assert_render(template, context, "0\xa0minutes")
+
+
+def test_timeuntil_dates(assert_render):
+    template = "{{ a|timeuntil:b }}"
+    context = {"a": date(2008, 1, 8), "b": date(2008, 1, 1)}
+    assert_render(template, context, "1\xa0week")
+
+
+def test_timeuntil_aware(assert_render):
+    template = "{{ a|timeuntil }}"
+    context = {"a": datetime.now(timezone.utc) + timedelta(hours=3, minutes=1)}
+    assert_render(template, context, "3\xa0hours")
+
+
+def test_timeuntil_missing(assert_render):
+    template = "{{ a|timeuntil }}"
+    assert_render(template, {}, "")
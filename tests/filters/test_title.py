@@ -0,0 +1,39 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_title.py
+"""
+
+from django.utils.safestring import mark_safe
+
+
+def test_title1(assert_render):
+    assert_render("{{ a|title }}", {"a": "JOE'S CRAB SHACK"}, "Joe&#x27;s Crab Shack")
+
+
+def test_title2(assert_render):
+    assert_render("{{ a|title }}", {"a": "555 WEST 53RD STREET"}, "555 West 53rd Street")
+
+
+def test_title(assert_render):
+    template = "{% autoescape off %}{{ a|title }}{% endautoescape %}"
+    assert_render(template, {"a": "a nice title, isn't it?"}, "A Nice Title, Isn't It?")
+
+
+def test_unicode(assert_render):
+    assert_render("{{ a|title }}", {"a": "discoth\xe8que"}, "Discoth\xe8que")
+
+
+def test_unicode_special_casing(assert_render):
+    assert_render("{{ a|title }}", {"a": "ǆemal straße ﬁsh"}, "ǅemal Straße Fish")
+
+
+def test_safe(assert_render):
+    assert_render("{{ a|title }}", {"a": mark_safe("<b>bold</b>")}, "<B>Bold</B>")
+
+
+def test_non_string_input(assert_render):
+    assert_render("{{ a|title }}", {"a": 123}, "123")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|title }}", {}, "")
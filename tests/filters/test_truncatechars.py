@@ -0,0 +1,75 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_truncatechars.py
+"""
+
+import pytest
+from django.template import engines
+from django.template.exceptions import TemplateSyntaxError
+from django.utils.safestring import mark_safe
+
+
+def test_truncatechars01(assert_render):
+    template = "{{ a|truncatechars:3 }}"
+    assert_render(template, {"a": "Testing, testing"}, "Te…")
+
+
+def test_truncatechars02(assert_render):
+    template = "{{ a|truncatechars:7 }}"
+    assert_render(template, {"a": "Testing"}, "Testing")
+
+
+def test_fail_silently_incorrect_arg(assert_render):
+    template = "{{ a|truncatechars:'e' }}"
+    assert_render(template, {"a": "Testing, testing"}, "Testing, testing")
+
+
+def test_truncatechars04(assert_render):
+    template = "{{ a|truncatechars:3 }}"
+    assert_render(template, {"a": "abc"}, "abc")
+
+
+def test_truncatechars_zero(assert_render):
+    template = "{{ a|truncatechars:0 }}"
+    assert_render(template, {"a": "abc"}, "")
+
+
+def test_truncatechars_string_argument(assert_render):
+    template = "{{ a|truncatechars:'5' }}"
+    assert_render(template, {"a": "Testing, testing"}, "Test…")
+
+
+def test_truncatechars_combining_characters(assert_render):
+    template = "{{ a|truncatechars:3 }}"
+    assert_render(template, {"a": "öööö"}, "\xf6\xf6…")
+
+
+def test_truncatechars_autoescape(assert_render):
+    template = "{{ a|truncatechars:4 }} {{ b|truncatechars:4 }}"
+    context = {"a": "<p>Testing</p>", "b": mark_safe("<p>Testing</p>")}
+    assert_render(template, context, "&lt;p&gt;T… <p>T…")
+
+
+def test_truncatechars_missing(assert_render):
+    assert_render("{{ a|truncatechars:3 }}", {}, "")
+
+
+def test_truncatechars_no_argument():
+    template = "{{ foo|truncatechars }}"
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "truncatechars requires 2 arguments, 1 provided"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × Expected an argument
+   ╭────
+ 1 │ {{ foo|truncatechars }}
+   ·        ──────┬──────
+   ·              ╰── here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
@@ -0,0 +1,48 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_truncatechars_html.py
+"""
+
+from django.utils.safestring import mark_safe
+
+HTML = '<p>one <a href="#">two - three <br>four</a> five</p>'
+
+
+def render_safe(assert_render, value, length, expected):
+    template = "{{ value|truncatechars_html:length }}"
+    context = {"value": mark_safe(value), "length": length}
+    assert_render(template, context, expected)
+
+
+def test_truncate_zero(assert_render):
+    render_safe(assert_render, HTML, 0, "")
+
+
+def test_truncate(assert_render):
+    render_safe(assert_render, HTML, 4, "<p>one…</p>")
+
+
+def test_truncate2(assert_render):
+    render_safe(assert_render, HTML, 9, '<p>one <a href="#">two …</a></p>')
+
+
+def test_truncate3(assert_render):
+    render_safe(assert_render, HTML, 100, HTML)
+
+
+def test_truncate_unicode(assert_render):
+    render_safe(assert_render, "<b>\xc5ngstr\xf6m</b> was here", 3, "<b>\xc5n…</b>")
+
+
+def test_truncate_something(assert_render):
+    render_safe(assert_render, "a<b>b</b>c", 3, "a<b>b</b>c")
+
+
+def test_invalid_arg(assert_render):
+    render_safe(assert_render, "<p>string</p>", "a", "<p>string</p>")
+
+
+def test_unsafe_input_is_escaped(assert_render):
+    template = "{{ value|truncatechars_html:4 }}"
+    expected = "&lt;p&gt;one…&lt;/p&gt;"
+    assert_render(template, {"value": HTML}, expected)
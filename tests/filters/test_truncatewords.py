@@ -0,0 +1,54 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_truncatewords.py
+"""
+
+from django.utils.safestring import mark_safe
+
+
+def test_truncatewords01(assert_render):
+    template = (
+        '{% autoescape off %}{{ a|truncatewords:"2" }} {{ b|truncatewords:"2"}}'
+        "{% endautoescape %}"
+    )
+    context = {"a": "alpha & bravo", "b": mark_safe("alpha &amp; bravo")}
+    assert_render(template, context, "alpha & … alpha &amp; …")
+
+
+def test_truncatewords02(assert_render):
+    template = '{{ a|truncatewords:"2" }} {{ b|truncatewords:"2"}}'
+    context = {"a": "alpha & bravo", "b": mark_safe("alpha &amp; bravo")}
+    assert_render(template, context, "alpha &amp; … alpha &amp; …")
+
+
+def test_truncate(assert_render):
+    template = "{{ a|truncatewords:1 }}"
+    assert_render(template, {"a": "A sentence with a few words in it"}, "A …")
+
+
+def test_truncate2(assert_render):
+    template = "{{ a|truncatewords:5 }}"
+    context = {"a": "A sentence with a few words in it"}
+    assert_render(template, context, "A sentence with a few …")
+
+
+def test_overtruncate(assert_render):
+    template = "{{ a|truncatewords:100 }}"
+    context = {"a": "A sentence with a few words in it"}
+    assert_render(template, context, "A sentence with a few words in it")
+
+
+def test_invalid_number(assert_render):
+    template = "{{ a|truncatewords:'not a number' }}"
+    context = {"a": "A sentence with a few words in it"}
+    assert_render(template, context, "A sentence with a few words in it")
+
+
+def test_collapses_whitespace(assert_render):
+    template = "{{ a|truncatewords:100 }}"
+    context = {"a": "  A sentence\n with   spaces "}
+    assert_render(template, context, "A sentence with spaces")
+
+
+def test_truncatewords_missing(assert_render):
+    assert_render("{{ a|truncatewords:3 }}", {}, "")
@@ -0,0 +1,61 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_truncatewords_html.py
+"""
+
+from django.utils.safestring import mark_safe
+
+HTML = '<p>one <a href="#">two - three <br>four</a> five</p>'
+
+
+def render_safe(assert_render, value, length, expected):
+    template = "{{ value|truncatewords_html:length }}"
+    context = {"value": mark_safe(value), "length": length}
+    assert_render(template, context, expected)
+
+
+def test_truncate_zero(assert_render):
+    render_safe(assert_render, HTML, 0, "")
+
+
+def test_truncate(assert_render):
+    render_safe(assert_render, HTML, 2, '<p>one <a href="#">two …</a></p>')
+
+
+def test_truncate2(assert_render):
+    render_safe(
+        assert_render, HTML, 4, '<p>one <a href="#">two - three <br> …</a></p>'
+    )
+
+
+def test_truncate3(assert_render):
+    render_safe(
+        assert_render, HTML, 5, '<p>one <a href="#">two - three <br>four</a> …</p>'
+    )
+
+
+def test_truncate4(assert_render):
+    render_safe(assert_render, HTML, 100, HTML)
+
+
+def test_truncate_unicode(assert_render):
+    render_safe(assert_render, "\xc5ngstr\xf6m was here", 1, "\xc5ngstr\xf6m …")
+
+
+def test_truncate_complex(assert_render):
+    render_safe(
+        assert_render,
+        "<i>Buenos d&iacute;as! &#x00bf;C&oacute;mo est&aacute;?</i>",
+        3,
+        "<i>Buenos días! ¿Cómo …</i>",
+    )
+
+
+def test_invalid_arg(assert_render):
+    render_safe(assert_render, "<p>string</p>", "a", "<p>string</p>")
+
+
+def test_unclosed_tags_are_closed(assert_render):
+    render_safe(
+        assert_render, "<div><p>one two three", 2, "<div><p>one two …</p></div>"
+    )
@@ -0,0 +1,156 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_unordered_list.py
+"""
+
+import pytest
+from django.utils.safestring import mark_safe
+from django.utils.translation import gettext_lazy
+
+
+class ULItem:
+    def __init__(self, title):
+        self.title = title
+
+    def __str__(self):
+        return "ulitem-%s" % str(self.title)
+
+
+def test_unordered_list01(assert_render):
+    template = "{{ a|unordered_list }}"
+    expected = "\t<li>x&gt;\n\t<ul>\n\t\t<li>&lt;y</li>\n\t</ul>\n\t</li>"
+    assert_render(template, {"a": ["x>", ["<y"]]}, expected)
+
+
+def test_unordered_list02(assert_render):
+    template = "{% autoescape off %}{{ a|unordered_list }}{% endautoescape %}"
+    expected = "\t<li>x>\n\t<ul>\n\t\t<li><y</li>\n\t</ul>\n\t</li>"
+    assert_render(template, {"a": ["x>", ["<y"]]}, expected)
+
+
+def test_unordered_list03(assert_render):
+    template = "{{ a|unordered_list }}"
+    expected = "\t<li>x&gt;\n\t<ul>\n\t\t<li><y</li>\n\t</ul>\n\t</li>"
+    assert_render(template, {"a": ["x>", [mark_safe("<y")]]}, expected)
+
+
+def test_unordered_list04(assert_render):
+    template = "{% autoescape off %}{{ a|unordered_list }}{% endautoescape %}"
+    expected = "\t<li>x>\n\t<ul>\n\t\t<li><y</li>\n\t</ul>\n\t</li>"
+    assert_render(template, {"a": ["x>", [mark_safe("<y")]]}, expected)
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (["item 1", "item 2"], "\t<li>item 1</li>\n\t<li>item 2</li>"),
+        (["item 1", gettext_lazy("item 2")], "\t<li>item 1</li>\n\t<li>item 2</li>"),
+        (
+            ["item 1", ["item 1.1"]],
+            "\t<li>item 1\n\t<ul>\n\t\t<li>item 1.1</li>\n\t</ul>\n\t</li>",
+        ),
+        (
+            ["item 1", ["item 1.1", "item1.2"], "item 2"],
+            "\t<li>item 1\n\t<ul>\n\t\t<li>item 1.1</li>\n\t\t<li>item1.2</li>\n"
+            "\t</ul>\n\t</li>\n\t<li>item 2</li>",
+        ),
+        (
+            ["item 1", "item 2", ["item 2.1"]],
+            "\t<li>item 1</li>\n\t<li>item 2\n\t<ul>\n\t\t<li>item 2.1</li>\n"
+            "\t</ul>\n\t</li>",
+        ),
+        (
+            ["item 1", ["item 1.1", ["item 1.1.1", ["item 1.1.1.1"]]]],
+            "\t<li>item 1\n\t<ul>\n\t\t<li>item 1.1\n\t\t<ul>\n\t\t\t<li>item 1.1.1\n"
+            "\t\t\t<ul>\n\t\t\t\t<li>item 1.1.1.1</li>\n\t\t\t</ul>\n\t\t\t</li>\n"
+            "\t\t</ul>\n\t\t</li>\n\t</ul>\n\t</li>",
+        ),
+        (
+            ["States", ["Kansas", ["Lawrence", "Topeka"], "Illinois"]],
+            "\t<li>States\n\t<ul>\n\t\t<li>Kansas\n\t\t<ul>\n\t\t\t<li>Lawrence</li>\n"
+            "\t\t\t<li>Topeka</li>\n\t\t</ul>\n\t\t</li>\n\t\t<li>Illinois</li>\n"
+            "\t</ul>\n\t</li>",
+        ),
+        (
+            ["<a>item 1</a>", "item 2"],
+            "\t<li>&lt;a&gt;item 1&lt;/a&gt;</li>\n\t<li>item 2</li>",
+        ),
+        (["item 1", [], "item 2"], "\t<li>item 1</li>\n\t<li>item 2</li>"),
+        ("ab", "\t<li>a</li>\n\t<li>b</li>"),
+    ],
+)
+def test_list(assert_render, value, expected):
+    assert_render("{{ a|unordered_list }}", {"a": value}, expected)
+
+
+def test_autoescape_off(assert_render):
+    template = "{% autoescape off %}{{ a|unordered_list }}{% endautoescape %}"
+    expected = "\t<li><a>item 1</a></li>\n\t<li>item 2</li>"
+    assert_render(template, {"a": ["<a>item 1</a>", "item 2"]}, expected)
+
+
+def test_ulitem(assert_render):
+    a = ULItem("a")
+    b = ULItem("b")
+    c = ULItem("<a>c</a>")
+    expected = (
+        "\t<li>ulitem-a</li>\n\t<li>ulitem-b</li>\n"
+        "\t<li>ulitem-&lt;a&gt;c&lt;/a&gt;</li>"
+    )
+    assert_render("{{ a|unordered_list }}", {"a": [a, b, c]}, expected)
+
+
+def test_ulitem_generator(rusty, django_template):
+    def item_generator():
+        yield from (ULItem("a"), ULItem("b"), ULItem("<a>c</a>"))
+
+    template = "{{ a|unordered_list }}"
+    expected = (
+        "\t<li>ulitem-a</li>\n\t<li>ulitem-b</li>\n"
+        "\t<li>ulitem-&lt;a&gt;c&lt;/a&gt;</li>"
+    )
+    assert django_template(template).render({"a": item_generator()}) == expected
+    assert rusty(template).render({"a": item_generator()}) == expected
+
+
+def test_nested_generators(rusty, django_template):
+    def inner_generator():
+        yield from ("B", "C")
+
+    def item_generator():
+        yield "A"
+        yield inner_generator()
+        yield "D"
+
+    template = "{{ a|unordered_list }}"
+    expected = (
+        "\t<li>A\n\t<ul>\n\t\t<li>B</li>\n\t\t<li>C</li>\n\t</ul>\n\t</li>\n"
+        "\t<li>D</li>"
+    )
+    assert django_template(template).render({"a": item_generator()}) == expected
+    assert rusty(template).render({"a": item_generator()}) == expected
+
+
+def test_ulitem_autoescape_off(assert_render):
+    template = "{% autoescape off %}{{ a|unordered_list }}{% endautoescape %}"
+    context = {"a": [ULItem("a"), ULItem("b"), ULItem("<a>c</a>")]}
+    expected = "\t<li>ulitem-a</li>\n\t<li>ulitem-b</li>\n\t<li>ulitem-<a>c</a></li>"
+    assert_render(template, context, expected)
+
+
+def test_missing_value(assert_render):
+    assert_render("{{ a|unordered_list }}", {}, "")
+
+
+def test_not_iterable(rusty, django_template):
+    template = "{{ a|unordered_list }}"
+
+    with pytest.raises(TypeError) as exc_info:
+        django_template(template).render({"a": 1})
+
+    assert str(exc_info.value) == "'int' object is not iterable"
+
+    with pytest.raises(TypeError) as exc_info:
+        rusty(template).render({"a": 1})
+
+    assert str(exc_info.value) == "'int' object is not iterable"
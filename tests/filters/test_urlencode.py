@@ -0,0 +1,65 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_urlencode.py
+"""
+
+import pytest
+
+
+def test_urlencode01(assert_render):
+    template = "{{ url|urlencode }}"
+    assert_render(template, {"url": '/test&"/me?/'}, "/test%26%22/me%3F/")
+
+
+def test_urlencode02(assert_render):
+    template = '/test/{{ urlbit|urlencode:"" }}/'
+    assert_render(template, {"urlbit": "escape/slash"}, "/test/escape%2Fslash/")
+
+
+def test_urlencode(assert_render):
+    template = "{{ value|urlencode }}"
+    context = {"value": "fran\xe7ois & jill"}
+    assert_render(template, context, "fran%C3%A7ois%20%26%20jill")
+
+
+def test_non_string_input(assert_render):
+    assert_render("{{ value|urlencode }}", {"value": 1}, "1")
+
+
+def test_missing_value(assert_render):
+    assert_render("{{ value|urlencode }}", {}, "")
+
+
+def test_safe_characters(assert_render):
+    template = "{{ value|urlencode:'&?é' }}"
+    context = {"value": "a=1&b=é?"}
+    assert_render(template, context, "a%3D1&amp;b%3D%C3%A9?")
+
+
+def test_safe_characters_autoescape_off(assert_render):
+    template = "{% autoescape off %}{{ value|urlencode:'&=' }}{% endautoescape %}"
+    assert_render(template, {"value": "a=1&b=2"}, "a=1&b=2")
+
+
+def test_safe_none(assert_render):
+    template = "{{ value|urlencode:safe }}"
+    assert_render(template, {"value": "a b/c", "safe": None}, "a%20b/c")
+
+
+def test_safe_bytes(assert_render):
+    template = "{{ value|urlencode:safe }}"
+    assert_render(template, {"value": "a b/c", "safe": b" "}, "a b%2Fc")
+
+
+def test_safe_integer(rusty, django_template):
+    template = "{{ value|urlencode:1 }}"
+
+    with pytest.raises(TypeError) as exc_info:
+        django_template(template).render({"value": "a b"})
+
+    assert str(exc_info.value) == "'int' object is not iterable"
+
+    with pytest.raises(TypeError) as exc_info:
+        rusty(template).render({"value": "a b"})
+
+    assert str(exc_info.value) == "'int' object is not iterable"
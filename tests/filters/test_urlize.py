@@ -0,0 +1,184 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_urlize.py
+"""
+
+import pytest
+from django.utils.safestring import mark_safe
+
+
+def test_urlize01(assert_render):
+    template = "{% autoescape off %}{{ a|urlize }} {{ b|urlize }}{% endautoescape %}"
+    context = {
+        "a": "http://example.com/?x=&y=",
+        "b": mark_safe("http://example.com?x=&amp;y=&lt;2&gt;"),
+    }
+    expected = (
+        '<a href="http://example.com/?x=&amp;y=" rel="nofollow">'
+        "http://example.com/?x=&y=</a> "
+        '<a href="http://example.com?x=&amp;y=%3C2%3E" rel="nofollow">'
+        "http://example.com?x=&amp;y=&lt;2&gt;</a>"
+    )
+    assert_render(template, context, expected)
+
+
+def test_urlize02(assert_render):
+    template = "{{ a|urlize }} {{ b|urlize }}"
+    context = {
+        "a": "http://example.com/?x=&y=",
+        "b": mark_safe("http://example.com?x=&amp;y="),
+    }
+    expected = (
+        '<a href="http://example.com/?x=&amp;y=" rel="nofollow">'
+        "http://example.com/?x=&amp;y=</a> "
+        '<a href="http://example.com?x=&amp;y=" rel="nofollow">'
+        "http://example.com?x=&amp;y=</a>"
+    )
+    assert_render(template, context, expected)
+
+
+def test_urlize03(assert_render):
+    template = "{% autoescape off %}{{ a|urlize }}{% endautoescape %}"
+    assert_render(template, {"a": mark_safe("a &amp; b")}, "a &amp; b")
+
+
+def test_urlize04(assert_render):
+    assert_render("{{ a|urlize }}", {"a": mark_safe("a &amp; b")}, "a &amp; b")
+
+
+def test_urlize05(assert_render):
+    template = "{% autoescape off %}{{ a|urlize }}{% endautoescape %}"
+    context = {"a": "<script>alert('foo')</script>"}
+    assert_render(template, context, "<script>alert('foo')</script>")
+
+
+def test_urlize06(assert_render):
+    template = "{{ a|urlize }}"
+    context = {"a": "<script>alert('foo')</script>"}
+    expected = "&lt;script&gt;alert(&#x27;foo&#x27;)&lt;/script&gt;"
+    assert_render(template, context, expected)
+
+
+def test_urlize07(assert_render):
+    template = "{{ a|urlize }}"
+    context = {"a": "Email me at me@example.com"}
+    expected = 'Email me at <a href="mailto:me@example.com">me@example.com</a>'
+    assert_render(template, context, expected)
+
+
+def test_urlize08(assert_render):
+    template = "{{ a|urlize }}"
+    context = {"a": "Email me at <me@example.com>"}
+    expected = 'Email me at &lt;<a href="mailto:me@example.com">me@example.com</a>&gt;'
+    assert_render(template, context, expected)
+
+
+def test_urlize09(assert_render):
+    template = "{% autoescape off %}{{ a|urlize }}{% endautoescape %}"
+    context = {"a": "http://example.com/?x=&amp;y=&lt;2&gt;"}
+    expected = (
+        '<a href="http://example.com/?x=&amp;y=%3C2%3E" rel="nofollow">'
+        "http://example.com/?x=&amp;y=&lt;2&gt;</a>"
+    )
+    assert_render(template, context, expected)
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (
+            "http://google.com",
+            '<a href="http://google.com" rel="nofollow">http://google.com</a>',
+        ),
+        (
+            "http://google.com/",
+            '<a href="http://google.com/" rel="nofollow">http://google.com/</a>',
+        ),
+        (
+            "www.google.com",
+            '<a href="http://www.google.com" rel="nofollow">www.google.com</a>',
+        ),
+        (
+            "djangoproject.org",
+            '<a href="http://djangoproject.org" rel="nofollow">djangoproject.org</a>',
+        ),
+        (
+            "djangoproject.org/",
+            '<a href="http://djangoproject.org/" rel="nofollow">djangoproject.org/</a>',
+        ),
+        (
+            "HTTPS://www.example.com",
+            '<a href="https://www.example.com" rel="nofollow">'
+            "HTTPS://www.example.com</a>",
+        ),
+        ("djangoproject.org.uk", "djangoproject.org.uk"),
+    ],
+)
+def test_urls(assert_render, value, expected):
+    assert_render("{{ a|urlize }}", {"a": value}, expected)
+
+
+def test_email(assert_render):
+    template = "{{ a|urlize }}"
+    context = {"a": "info@djangoproject.org"}
+    expected = '<a href="mailto:info@djangoproject.org">info@djangoproject.org</a>'
+    assert_render(template, context, expected)
+
+
+def test_invalid_email(assert_render):
+    assert_render("{{ a|urlize }}", {"a": "foo@bar..com"}, "foo@bar..com")
+
+
+def test_split_chars(assert_render):
+    template = "{{ a|urlize }}"
+    context = {"a": 'www.server.com"abc'}
+    expected = (
+        '<a href="http://www.server.com" rel="nofollow">www.server.com</a>&quot;abc'
+    )
+    assert_render(template, context, expected)
+
+
+def test_trailing_period(assert_render):
+    template = "{{ a|urlize }}"
+    context = {"a": "(Go to http://www.example.com/foo.)"}
+    expected = (
+        '(Go to <a href="http://www.example.com/foo" rel="nofollow">'
+        "http://www.example.com/foo</a>.)"
+    )
+    assert_render(template, context, expected)
+
+
+def test_brackets(assert_render):
+    template = "{{ a|urlize }}"
+    context = {"a": "[see www.example.com]"}
+    expected = (
+        '[see <a href="http://www.example.com" rel="nofollow">www.example.com</a>]'
+    )
+    assert_render(template, context, expected)
+
+
+def test_parenthesis(assert_render):
+    template = "{{ a|urlize }}"
+    context = {"a": "(see https://en.wikipedia.org/wiki/Django_(web_framework))"}
+    expected = (
+        '(see <a href="https://en.wikipedia.org/wiki/Django_(web_framework)" '
+        'rel="nofollow">https://en.wikipedia.org/wiki/Django_(web_framework)</a>)'
+    )
+    assert_render(template, context, expected)
+
+
+def test_quoting(assert_render):
+    template = "{{ a|urlize }}"
+    context = {"a": "http://example.com/a b"}
+    expected = (
+        '<a href="http://example.com/a" rel="nofollow">http://example.com/a</a> b'
+    )
+    assert_render(template, context, expected)
+
+
+def test_non_string_input(assert_render):
+    assert_render("{{ a|urlize }}", {"a": 123}, "123")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|urlize }}", {}, "")
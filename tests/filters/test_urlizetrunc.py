@@ -0,0 +1,100 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_urlizetrunc.py
+"""
+
+import pytest
+from django.template import engines
+from django.template.exceptions import TemplateSyntaxError
+from django.utils.safestring import mark_safe
+
+
+def test_urlizetrunc01(assert_render):
+    template = (
+        '{% autoescape off %}{{ a|urlizetrunc:"8" }} {{ b|urlizetrunc:"8" }}'
+        "{% endautoescape %}"
+    )
+    context = {
+        "a": '"Unsafe" http://example.com/x=&y=',
+        "b": mark_safe("&quot;Safe&quot; http://example.com?x=&amp;y="),
+    }
+    expected = (
+        '"Unsafe" <a href="http://example.com/x=&amp;y=" rel="nofollow">http://…</a> '
+        '&quot;Safe&quot; <a href="http://example.com?x=&amp;y=" rel="nofollow">'
+        "http://…</a>"
+    )
+    assert_render(template, context, expected)
+
+
+def test_urlizetrunc02(assert_render):
+    template = '{{ a|urlizetrunc:"8" }} {{ b|urlizetrunc:"8" }}'
+    context = {
+        "a": '"Unsafe" http://example.com/x=&y=',
+        "b": mark_safe("&quot;Safe&quot; http://example.com?x=&amp;y="),
+    }
+    expected = (
+        '&quot;Unsafe&quot; <a href="http://example.com/x=&amp;y=" rel="nofollow">'
+        "http://…</a> "
+        '&quot;Safe&quot; <a href="http://example.com?x=&amp;y=" rel="nofollow">'
+        "http://…</a>"
+    )
+    assert_render(template, context, expected)
+
+
+def test_truncate(assert_render):
+    template = "{{ a|urlizetrunc:limit }}"
+    uri = "http://31characteruri.com/test/"
+    link = f'<a href="{uri}" rel="nofollow">'
+
+    assert_render(template, {"a": uri, "limit": 31}, f"{link}{uri}</a>")
+    assert_render(
+        template, {"a": uri, "limit": 30}, f"{link}http://31characteruri.com/tes…</a>"
+    )
+    assert_render(template, {"a": uri, "limit": 1}, f"{link}…</a>")
+
+
+def test_query_string(assert_render):
+    template = "{{ a|urlizetrunc:20 }}"
+    context = {
+        "a": "http://www.google.co.uk/search?hl=en&q=some+long+url&btnG=Search&meta="
+    }
+    expected = (
+        '<a href="http://www.google.co.uk/search?hl=en&amp;q=some+long+url&amp;'
+        'btnG=Search&amp;meta=" rel="nofollow">http://www.google.c…</a>'
+    )
+    assert_render(template, context, expected)
+
+
+def test_non_string_input(assert_render):
+    assert_render("{{ a|urlizetrunc:2 }}", {"a": 123}, "123")
+
+
+def test_autoescape(assert_render):
+    template = "{{ a|urlizetrunc:10 }}"
+    context = {"a": 'foo<a href=" google.com ">bar</a>buz'}
+    expected = (
+        'foo&lt;a href=&quot; <a href="http://google.com" rel="nofollow">'
+        "google.com</a> &quot;&gt;bar&lt;/a&gt;buz"
+    )
+    assert_render(template, context, expected)
+
+
+def test_urlizetrunc_no_argument():
+    template = "{{ foo|urlizetrunc }}"
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "urlizetrunc requires 2 arguments, 1 provided"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × Expected an argument
+   ╭────
+ 1 │ {{ foo|urlizetrunc }}
+   ·        ─────┬─────
+   ·             ╰── here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
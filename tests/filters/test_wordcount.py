@@ -0,0 +1,44 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_wordcount.py
+"""
+
+from django.utils.safestring import mark_safe
+
+
+def test_wordcount01(assert_render):
+    template = (
+        "{% autoescape off %}{{ a|wordcount }} {{ b|wordcount }}{% endautoescape %}"
+    )
+    context = {"a": "a & b", "b": mark_safe("a &amp; b")}
+    assert_render(template, context, "3 3")
+
+
+def test_wordcount02(assert_render):
+    template = "{{ a|wordcount }} {{ b|wordcount }}"
+    context = {"a": "a & b", "b": mark_safe("a &amp; b")}
+    assert_render(template, context, "3 3")
+
+
+def test_empty_string(assert_render):
+    assert_render("{{ a|wordcount }}", {"a": ""}, "0")
+
+
+def test_count_one(assert_render):
+    assert_render("{{ a|wordcount }}", {"a": "oneword"}, "1")
+
+
+def test_count_multiple(assert_render):
+    assert_render("{{ a|wordcount }}", {"a": "lots of words"}, "3")
+
+
+def test_unicode_whitespace(assert_render):
+    assert_render("{{ a|wordcount }}", {"a": "a　b\x1cc\xa0d"}, "4")
+
+
+def test_non_string_input(assert_render):
+    assert_render("{{ a|wordcount }}", {"a": 123}, "1")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|wordcount }}", {}, "0")
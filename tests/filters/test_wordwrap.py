@@ -0,0 +1,95 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_wordwrap.py
+"""
+
+import pytest
+from django.utils.safestring import mark_safe
+
+
+def test_wordwrap01(assert_render):
+    template = (
+        "{% autoescape off %}{{ a|wordwrap:3 }} {{ b|wordwrap:3 }}{% endautoescape %}"
+    )
+    context = {"a": "a & b", "b": mark_safe("a & b")}
+    assert_render(template, context, "a &\nb a &\nb")
+
+
+def test_wordwrap02(assert_render):
+    template = "{{ a|wordwrap:3 }} {{ b|wordwrap:3 }}"
+    context = {"a": "a & b", "b": mark_safe("a & b")}
+    assert_render(template, context, "a &amp;\nb a &\nb")
+
+
+def test_wrap(assert_render):
+    template = "{% autoescape off %}{{ a|wordwrap:14 }}{% endautoescape %}"
+    context = {
+        "a": "this is a long paragraph of text that really needs to be wrapped I'm afraid"
+    }
+    expected = (
+        "this is a long\nparagraph of\ntext that\nreally needs\nto be wrapped\n"
+        "I'm afraid"
+    )
+    assert_render(template, context, expected)
+
+
+def test_indent(assert_render):
+    template = "{{ a|wordwrap:14 }}"
+    context = {"a": "this is a short paragraph of text.\n  But this line should be indented"}
+    expected = (
+        "this is a\nshort\nparagraph of\ntext.\n  But this\nline should be\nindented"
+    )
+    assert_render(template, context, expected)
+
+
+def test_indent2(assert_render):
+    template = "{{ a|wordwrap:15 }}"
+    context = {"a": "this is a short paragraph of text.\n  But this line should be indented"}
+    expected = (
+        "this is a short\nparagraph of\ntext.\n  But this line\nshould be\nindented"
+    )
+    assert_render(template, context, expected)
+
+
+def test_trailing_newline(assert_render):
+    template = "{{ a|wordwrap:3 }}"
+    assert_render(template, {"a": "abc def\n\n"}, "abc\ndef\n\n")
+
+
+def test_long_text(rusty, django_template):
+    template = "{{ a|wordwrap:12 }}"
+    context = {"a": "this is a long paragraph of text " * 1000}
+    assert rusty(template).render(context) == django_template(template).render(context)
+
+
+def test_non_string_input(assert_render):
+    assert_render("{{ a|wordwrap:2 }}", {"a": 123}, "123")
+
+
+def test_missing(assert_render):
+    assert_render("{{ a|wordwrap:2 }}", {}, "")
+
+
+def test_zero_width(rusty, django_template):
+    template = "{{ a|wordwrap:0 }}"
+
+    with pytest.raises(ValueError) as django_error:
+        django_template(template).render({"a": "abc"})
+
+    with pytest.raises(ValueError) as rust_error:
+        rusty(template).render({"a": "abc"})
+
+    assert str(rust_error.value) == str(django_error.value)
+
+
+def test_negative_width(rusty, django_template):
+    template = "{{ a|wordwrap:-3 }}"
+
+    with pytest.raises(ValueError) as django_error:
+        django_template(template).render({"a": "abc"})
+
+    with pytest.raises(ValueError) as rust_error:
+        rusty(template).render({"a": "abc"})
+
+    assert str(django_error.value) == "invalid width -3 (must be > 0)"
+    assert str(rust_error.value) == str(django_error.value)
@@ -0,0 +1,47 @@
+"""
+Adapted from
+https://github.com/django/django/blob/5.1/tests/template_tests/filter_tests/test_yesno.py
+"""
+
+import pytest
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [(True, "certainly"), (False, "get out of town"), (None, "perhaps")],
+)
+def test_arguments(assert_render, value, expected):
+    template = '{{ var|yesno:"certainly,get out of town,perhaps" }}'
+    assert_render(template, {"var": value}, expected)
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [(True, "yes"), (False, "no"), (None, "maybe"), ([], "no"), ("x", "yes")],
+)
+def test_no_arguments(assert_render, value, expected):
+    assert_render("{{ var|yesno }}", {"var": value}, expected)
+
+
+def test_invalid_value(assert_render):
+    assert_render('{{ var|yesno:"certainly" }}', {"var": True}, "True")
+
+
+def test_none_two_arguments(assert_render):
+    template = '{{ var|yesno:"certainly,get out of town" }}'
+    assert_render(template, {"var": None}, "get out of town")
+
+
+def test_extra_arguments(assert_render):
+    template = '{{ var|yesno:"yes,no,maybe,extra" }}'
+    assert_render(template, {"var": None}, "no")
+
+
+def test_escaped(assert_render):
+    template = "{{ var|yesno:choices }}"
+    context = {"var": True, "choices": "<b>,<i>"}
+    assert_render(template, context, "&lt;b&gt;")
+
+
+def test_missing(assert_render):
+    assert_render("{{ var|yesno }}", {}, "no")
@@ -0,0 +1,27 @@
+from django.template import Origin, TemplateDoesNotExist
+from django.template.loaders.base import Loader
+
+
+class DictLoader(Loader):
+    def __init__(self, engine, templates):
+        super().__init__(engine)
+        self.templates = templates
+
+    def get_template_sources(self, template_name):
+        yield Origin(
+            name=f"dict:{template_name}", template_name=template_name, loader=self
+        )
+
+    def get_contents(self, origin):
+        try:
+            return self.templates[origin.template_name]
+        except KeyError:
+            raise TemplateDoesNotExist(origin)
+
+
+class BrokenLoader(Loader):
+    def get_template_sources(self, template_name):
+        yield Origin(name=template_name, template_name=template_name, loader=self)
+
+    def get_contents(self, origin):
+        raise ValueError("Storage is unavailable")
@@ -3,6 +3,7 @@ import os
 BASE_DIR = os.path.dirname(os.path.abspath(__file__))
 
 INSTALLED_APPS = [
+    "django.contrib.humanize",
     "tests.apps.DummyAppConfig",
 ]
 
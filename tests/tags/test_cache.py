@@ -0,0 +1,132 @@
+import pytest
+from django.core.cache import cache
+from django.core.cache.utils import make_template_fragment_key
+from django.template import engines
+from django.template.exceptions import TemplateSyntaxError
+
+
+@pytest.fixture(autouse=True)
+def clear_cache():
+    cache.clear()
+    yield
+    cache.clear()
+
+
+@pytest.mark.parametrize("engine", ["django", "rusty"])
+def test_cache_hit_skips_render(engine):
+    template = engines[engine].from_string(
+        "{% load cache %}{% cache 500 sidebar %}{{ value }}{% endcache %}"
+    )
+
+    assert template.render({"value": 1}) == "1"
+    assert template.render({"value": 2}) == "1"
+
+
+@pytest.mark.parametrize("engine", ["django", "rusty"])
+def test_cache_vary_on(engine):
+    template = engines[engine].from_string(
+        "{% load cache %}{% cache 500 sidebar user.id %}{{ value }}{% endcache %}"
+    )
+
+    assert template.render({"value": 1, "user": {"id": 1}}) == "1"
+    assert template.render({"value": 2, "user": {"id": 2}}) == "2"
+    assert template.render({"value": 3, "user": {"id": 1}}) == "1"
+
+
+@pytest.mark.parametrize("engine", ["django", "rusty"])
+def test_cache_fragment_key(engine):
+    template = engines[engine].from_string(
+        "{% load cache %}{% cache 500 sidebar a b %}{{ value }}{% endcache %}"
+    )
+
+    assert template.render({"value": "<p>", "a": 1, "b": "x:y"}) == "&lt;p&gt;"
+    key = make_template_fragment_key("sidebar", [1, "x:y"])
+    assert cache.get(key) == "&lt;p&gt;"
+
+
+@pytest.mark.parametrize("engine", ["django", "rusty"])
+def test_cache_using(engine):
+    template = engines[engine].from_string(
+        '{% load cache %}{% cache None sidebar using="default" %}{{ value }}{% endcache %}'
+    )
+
+    assert template.render({"value": 1}) == "1"
+    assert template.render({"value": 2}) == "1"
+
+
+@pytest.mark.parametrize("engine", ["django", "rusty"])
+def test_cache_timeout_variable(engine):
+    template = engines[engine].from_string(
+        "{% load cache %}{% cache timeout sidebar %}{{ value }}{% endcache %}"
+    )
+
+    assert template.render({"value": 1, "timeout": "60"}) == "1"
+    assert template.render({"value": 2, "timeout": "60"}) == "1"
+
+
+def test_cache_invalid_timeout():
+    template = "{% load cache %}{% cache timeout sidebar %}{% endcache %}"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template).render({"timeout": "soon"})
+
+    expected = "\"cache\" tag got a non-integer timeout value: 'soon'"
+    assert str(exc_info.value) == expected
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template).render({"timeout": "soon"})
+
+    expected = """\
+  × "cache" tag got a non-integer timeout value: 'soon'
+   ╭────
+ 1 │ {% load cache %}{% cache timeout sidebar %}{% endcache %}
+   ·                          ───┬───
+   ·                             ╰── here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
+
+
+def test_cache_invalid_cache_name():
+    template = '{% load cache %}{% cache 500 sidebar using="missing" %}{% endcache %}'
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template).render({})
+
+    expected = "Invalid cache name specified for cache tag: 'missing'"
+    assert str(exc_info.value) == expected
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template).render({})
+
+    expected = """\
+  × Invalid cache name specified for cache tag: 'missing'
+   ╭────
+ 1 │ {% load cache %}{% cache 500 sidebar using="missing" %}{% endcache %}
+   ·                                            ────┬────
+   ·                                                ╰── here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
+
+
+def test_cache_missing_arguments():
+    template = "{% load cache %}{% cache 500 %}{% endcache %}"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "''cache'' tag requires at least 2 arguments."
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × 'cache' tag requires at least 2 arguments.
+   ╭────
+ 1 │ {% load cache %}{% cache 500 %}{% endcache %}
+   ·                 ───────┬───────
+   ·                        ╰── here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
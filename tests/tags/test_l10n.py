@@ -0,0 +1,98 @@
+from decimal import Decimal
+
+import pytest
+from django.template import engines
+from django.template.exceptions import TemplateSyntaxError
+from django.utils import translation
+
+
+def test_localize_off(assert_render, settings):
+    settings.USE_THOUSAND_SEPARATOR = True
+    template = "{% load l10n %}{{ value }} {% localize off %}{{ value }}{% endlocalize %}"
+    context = {"value": 1234567}
+
+    assert_render(template, context, "1,234,567 1234567")
+
+
+def test_localize_on(assert_render, settings):
+    settings.USE_THOUSAND_SEPARATOR = True
+    template = "{% load l10n %}{% localize on %}{{ value }}{% endlocalize %}"
+    context = {"value": Decimal("1234.5")}
+
+    assert_render(template, context, "1,234.5")
+
+
+def test_localize_default_on(assert_render, settings):
+    settings.USE_THOUSAND_SEPARATOR = True
+    template = "{% load l10n %}{% localize off %}{{ value }}{% localize %}{{ value }}{% endlocalize %}{{ value }}{% endlocalize %}"
+    context = {"value": 1000.5}
+
+    assert_render(template, context, "1000.51,000.51000.5")
+
+
+def test_localize_string_unchanged(assert_render, settings):
+    settings.USE_THOUSAND_SEPARATOR = True
+    template = "{% load l10n %}{% localize on %}{{ value }}{% endlocalize %}"
+    context = {"value": "1234567"}
+
+    assert_render(template, context, "1234567")
+
+
+def test_localize_native_numbers(assert_render, settings):
+    settings.USE_THOUSAND_SEPARATOR = True
+    template = "{{ value|add:1 }} {{ 2000 }} {% for x in 'ab' %}{{ forloop.counter }}{% endfor %}"
+    context = {"value": 999}
+
+    assert_render(template, context, "1,000 2,000 12")
+
+
+def test_localize_floats_with_locale(assert_render):
+    template = "{{ value }} {{ 2.5 }} {{ flag }}"
+    context = {"value": 1.5, "flag": True}
+
+    with translation.override("de"):
+        assert_render(template, context, "1,5 2,5 True")
+
+
+def test_localize_invalid_argument():
+    template = "{% load l10n %}{% localize foo %}{{ value }}{% endlocalize %}"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "'localize' argument should be 'on' or 'off'"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × 'localize' argument should be 'on' or 'off'.
+   ╭────
+ 1 │ {% load l10n %}{% localize foo %}{{ value }}{% endlocalize %}
+   ·                            ─┬─
+   ·                             ╰── here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
+
+
+def test_localize_missing_end_tag():
+    template = "{% load l10n %}{% localize %}{{ value }}"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "Unclosed tag on line 1: 'localize'. Looking for one of: endlocalize."
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × Unclosed 'localize' tag. Looking for one of: endlocalize
+   ╭────
+ 1 │ {% load l10n %}{% localize %}{{ value }}
+   ·                ───────┬──────
+   ·                       ╰── started here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
@@ -0,0 +1,123 @@
+from datetime import datetime, timezone
+
+import pytest
+from django.template import engines
+from django.template.exceptions import TemplateSyntaxError
+
+
+NOON_UTC = datetime(2020, 1, 1, 12, tzinfo=timezone.utc)
+
+
+def test_localtime_default(assert_render):
+    template = "{% load tz %}{{ value }}"
+    context = {"value": NOON_UTC}
+
+    assert_render(template, context, "Jan. 1, 2020, 6 a.m.")
+
+
+def test_localtime_off(assert_render):
+    template = "{% load tz %}{% localtime off %}{{ value }}{% endlocaltime %}"
+    context = {"value": NOON_UTC}
+
+    assert_render(template, context, "Jan. 1, 2020, noon")
+
+
+def test_localtime_nested(assert_render):
+    template = "{% load tz %}{% localtime off %}{{ value }} {% localtime %}{{ value }}{% endlocaltime %}{% endlocaltime %}"
+    context = {"value": NOON_UTC}
+
+    assert_render(template, context, "Jan. 1, 2020, noon Jan. 1, 2020, 6 a.m.")
+
+
+def test_timezone(assert_render):
+    template = '{% load tz %}{% timezone "Europe/Paris" %}{{ value }}{% endtimezone %} {{ value }}'
+    context = {"value": NOON_UTC}
+
+    assert_render(template, context, "Jan. 1, 2020, 1 p.m. Jan. 1, 2020, 6 a.m.")
+
+
+def test_timezone_variable(assert_render):
+    template = "{% load tz %}{% timezone tz %}{{ value }}{% endtimezone %}"
+    context = {"value": NOON_UTC, "tz": "Asia/Tokyo"}
+
+    assert_render(template, context, "Jan. 1, 2020, 9 p.m.")
+
+
+def test_timezone_none(assert_render):
+    template = "{% load tz %}{% timezone None %}{{ value }}{% endtimezone %}"
+    context = {"value": NOON_UTC}
+
+    assert_render(template, context, "Jan. 1, 2020, 6 a.m.")
+
+
+def test_get_current_timezone(assert_render):
+    template = '{% load tz %}{% get_current_timezone as TZ %}{{ TZ }} {% timezone "Europe/Paris" %}{% get_current_timezone as TZ %}{{ TZ }}{% endtimezone %}'
+
+    assert_render(template, {}, "America/Chicago Europe/Paris")
+
+
+def test_timezone_missing_argument():
+    template = "{% load tz %}{% timezone %}{% endtimezone %}"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "'timezone' takes one argument (timezone)"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × 'timezone' takes one argument (timezone)
+   ╭────
+ 1 │ {% load tz %}{% timezone %}{% endtimezone %}
+   ·              ───────┬──────
+   ·                     ╰── here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
+
+
+def test_get_current_timezone_missing_as():
+    template = "{% load tz %}{% get_current_timezone %}"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    expected = "'get_current_timezone' requires 'as variable' (got ['get_current_timezone'])"
+    assert str(exc_info.value) == expected
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × 'get_current_timezone' requires 'as variable'
+   ╭────
+ 1 │ {% load tz %}{% get_current_timezone %}
+   ·              ─────────────┬────────────
+   ·                           ╰── here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
+
+
+def test_localtime_invalid_argument():
+    template = "{% load tz %}{% localtime foo %}{% endlocaltime %}"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["django"].from_string(template)
+
+    assert str(exc_info.value) == "'localtime' argument should be 'on' or 'off'"
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        engines["rusty"].from_string(template)
+
+    expected = """\
+  × 'localtime' argument should be 'on' or 'off'.
+   ╭────
+ 1 │ {% load tz %}{% localtime foo %}{% endlocaltime %}
+   ·                           ─┬─
+   ·                            ╰── here
+   ╰────
+"""
+    assert str(exc_info.value) == expected
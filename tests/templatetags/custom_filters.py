@@ -1,4 +1,6 @@
 from django import template
+from django.utils.html import conditional_escape
+from django.utils.safestring import mark_safe
 
 register = template.Library()
 
@@ -22,3 +24,20 @@ def multiply(value, by=3):
 @register.filter
 def divide_by_zero(value, zero=0):
     return value / zero
+
+
+@register.filter(is_safe=True)
+def exclaim(value):
+    return f"{value}!"
+
+
+@register.filter(needs_autoescape=True)
+def bold(value, autoescape=True):
+    if autoescape:
+        value = conditional_escape(value)
+    return mark_safe(f"<b>{value}</b>")
+
+
+@register.filter(expects_localtime=True)
+def hour(value):
+    return value.hour
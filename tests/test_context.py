@@ -0,0 +1,83 @@
+import pytest
+from django.template import engines
+from django.template.context import Context, RequestContext
+from django.test import RequestFactory
+
+from tests.context_processors import greeting
+
+
+factory = RequestFactory()
+
+
+def render(template, context):
+    django_template = engines["django"].from_string(template).template
+    rust_template = engines["rusty"].from_string(template).template
+    return django_template.render(context), rust_template.render(context)
+
+
+def test_context():
+    django, rust = render("{{ foo }}", Context({"foo": 1}))
+    assert django == rust == "1"
+
+
+def test_context_stack():
+    context = Context({"foo": 1, "bar": 2})
+    context.push(foo=3)
+
+    django, rust = render("{{ foo }} {{ bar }}", context)
+    assert django == rust == "3 2"
+
+
+def test_context_autoescape():
+    context = Context({"foo": "<b>"}, autoescape=False)
+
+    django, rust = render("{{ foo }}", context)
+    assert django == rust == "<b>"
+
+
+def test_request_context():
+    request = factory.get("/")
+    context = RequestContext(request, {"name": "Lily", "age": 3}, processors=[greeting])
+    context.push(age=4)
+
+    django, rust = render("{{ greeting }} {{ name }} {{ age }}", context)
+    assert django == rust == "Hello processor 4"
+
+
+def test_request_context_csrf_token():
+    context = RequestContext(factory.get("/"))
+
+    django, rust = render("{% if csrf_token %}token{% endif %}", context)
+    assert django == rust == "token"
+
+
+def test_not_a_dict():
+    template = "{{ foo }}"
+    context = [("foo", 1)]
+    expected = "context must be a dict rather than list."
+
+    with pytest.raises(TypeError) as exc_info:
+        engines["django"].from_string(template).render(context)
+
+    assert str(exc_info.value) == expected
+
+    with pytest.raises(TypeError) as exc_info:
+        engines["rusty"].from_string(template).render(context)
+
+    assert str(exc_info.value) == expected
+
+
+def test_backend_template_context():
+    template = "{{ foo }}"
+    context = Context({"foo": 1})
+    expected = "context must be a dict rather than Context."
+
+    with pytest.raises(TypeError) as exc_info:
+        engines["django"].from_string(template).render(context)
+
+    assert str(exc_info.value) == expected
+
+    with pytest.raises(TypeError) as exc_info:
+        engines["rusty"].from_string(template).render(context)
+
+    assert str(exc_info.value) == expected
@@ -0,0 +1,71 @@
+import pytest
+from django.template.backends.django import DjangoTemplates
+from django.test import RequestFactory
+
+from django_rusty_templates import RustyTemplates
+
+
+factory = RequestFactory()
+
+
+def make_engines(*context_processors):
+    params = {
+        "DIRS": [],
+        "APP_DIRS": False,
+        "OPTIONS": {"context_processors": list(context_processors)},
+    }
+    django_engine = DjangoTemplates({**params, "NAME": "django"})
+    rust_engine = RustyTemplates({**params, "NAME": "rust"})
+    return django_engine, rust_engine
+
+
+def test_context_processors():
+    engines = make_engines(
+        "tests.context_processors.greeting", "tests.context_processors.path"
+    )
+    template = "{{ greeting }} {{ name }} {{ path }}"
+    request = factory.get("/foo/")
+
+    for engine in engines:
+        rendered = engine.from_string(template).render({}, request)
+        assert rendered == "Hello processor /foo/"
+
+
+def test_context_overrides_processors():
+    engines = make_engines("tests.context_processors.greeting")
+    template = "{{ greeting }} {{ name }}"
+    request = factory.get("/")
+
+    for engine in engines:
+        rendered = engine.from_string(template).render({"name": "Lily"}, request)
+        assert rendered == "Hello Lily"
+
+
+def test_no_request():
+    engines = make_engines("tests.context_processors.greeting")
+    template = "{{ greeting }}"
+
+    for engine in engines:
+        assert engine.from_string(template).render({}) == ""
+
+
+def test_csrf_token():
+    engines = make_engines()
+    template = "{% if csrf_token %}token{% endif %}"
+    request = factory.get("/")
+
+    for engine in engines:
+        assert engine.from_string(template).render({}, request) == "token"
+
+
+def test_not_a_dict():
+    engines = make_engines("tests.context_processors.not_a_dict")
+    request = factory.get("/")
+    expected = "Context processor not_a_dict didn't return a dictionary."
+
+    for engine in engines:
+        with pytest.raises(TypeError) as exc_info:
+            engine.from_string("").render({}, request)
+
+        assert str(exc_info.value) == expected
+        assert isinstance(exc_info.value.__cause__, TypeError)
@@ -2,6 +2,9 @@ from pathlib import Path
 
 import pytest
 from django.conf import settings
+from django.core.exceptions import ImproperlyConfigured
+from django.template import TemplateDoesNotExist
+from django.template.base import UNKNOWN_SOURCE
 from django.template.engine import Engine
 from django.template.library import InvalidTemplateLibrary
 
@@ -55,3 +58,231 @@ def test_pathlib_dirs():
 
     template = engine.get_template("basic.txt")
     assert template.render({"user": "Lily"}) == "Hello Lily!\n"
+
+
+def loaders_engine(loaders, dirs=()):
+    return RustyTemplates(
+        {
+            "NAME": "rust",
+            "OPTIONS": {"loaders": loaders},
+            "DIRS": list(dirs),
+            "APP_DIRS": False,
+        }
+    )
+
+
+def test_locmem_loader():
+    loader = ("django.template.loaders.locmem.Loader", {"index.html": "Hi {{ user }}"})
+    engine = loaders_engine([loader])
+
+    template = engine.get_template("index.html")
+    assert template.render({"user": "Lily"}) == "Hi Lily"
+
+
+def test_filesystem_loader():
+    engine = loaders_engine(
+        ["django.template.loaders.filesystem.Loader"],
+        dirs=[Path(settings.BASE_DIR) / "templates"],
+    )
+
+    template = engine.get_template("basic.txt")
+    assert template.render({"user": "Lily"}) == "Hello Lily!\n"
+
+
+def test_filesystem_loader_dirs():
+    loader = (
+        "django.template.loaders.filesystem.Loader",
+        [Path(settings.BASE_DIR) / "templates"],
+    )
+    engine = loaders_engine([loader])
+
+    template = engine.get_template("basic.txt")
+    assert template.render({"user": "Lily"}) == "Hello Lily!\n"
+
+
+def test_app_directories_loader():
+    engine = loaders_engine(["django.template.loaders.app_directories.Loader"])
+
+    template = engine.get_template("basic.txt")
+    assert template.render({"user": "Lily"}) == "Hello Lily!\n"
+
+
+def test_cached_loader():
+    loaders = [
+        (
+            "django.template.loaders.cached.Loader",
+            [
+                ("django.template.loaders.locmem.Loader", {"basic.txt": "locmem"}),
+                "django.template.loaders.app_directories.Loader",
+            ],
+        )
+    ]
+    engine = loaders_engine(loaders)
+
+    assert engine.get_template("basic.txt").render({}) == "locmem"
+    assert engine.get_template("full_example.html")
+
+
+def test_loaders_with_app_dirs():
+    expected = "app_dirs must not be set when loaders is defined."
+
+    with pytest.raises(ImproperlyConfigured) as exc_info:
+        RustyTemplates(
+            {
+                "NAME": "rust",
+                "OPTIONS": {"loaders": []},
+                "DIRS": [],
+                "APP_DIRS": True,
+            }
+        )
+
+    assert str(exc_info.value) == expected
+
+
+def test_invalid_loader():
+    with pytest.raises(ImproperlyConfigured) as exc_info:
+        loaders_engine([1])
+
+    assert str(exc_info.value) == "Invalid value in template loaders configuration: 1"
+
+
+def test_invalid_loader_tuple():
+    expected = "Invalid value in template loaders configuration: 1"
+
+    with pytest.raises(ImproperlyConfigured) as exc_info:
+        Engine(loaders=[(1, "templates")]).get_template("basic.txt")
+
+    assert str(exc_info.value) == expected
+
+    with pytest.raises(ImproperlyConfigured) as exc_info:
+        loaders_engine([(1, "templates")])
+
+    assert str(exc_info.value) == expected
+
+
+def test_loader_missing_argument():
+    expected = "Loader.__init__() missing 1 required positional argument: 'loaders'"
+
+    with pytest.raises(TypeError) as exc_info:
+        loaders_engine(["django.template.loaders.cached.Loader"])
+
+    assert str(exc_info.value) == expected
+
+
+def test_external_loader():
+    loader = ("tests.loaders.DictLoader", {"external.txt": "Hi {{ user }}"})
+    engine = loaders_engine([loader])
+
+    template = engine.get_template("external.txt")
+    assert template.render({"user": "Lily"}) == "Hi Lily"
+
+
+def test_external_loader_engine():
+    loader = ("tests.loaders.DictLoader", {"external.txt": "Hi {{ user }}"})
+    engine = loaders_engine([loader], dirs=["templates"])
+
+    loader_engine = engine.get_template("external.txt").origin.loader.engine
+    assert isinstance(loader_engine, Engine)
+    assert loader_engine.dirs == ["templates"]
+    assert loader_engine.loaders == [loader]
+    assert loader_engine.file_charset == "utf-8"
+
+
+def test_external_loader_missing_template():
+    loaders = [
+        ("tests.loaders.DictLoader", {}),
+        ("django.template.loaders.locmem.Loader", {"basic.txt": "locmem"}),
+    ]
+    engine = loaders_engine(loaders)
+
+    assert engine.get_template("basic.txt").render({}) == "locmem"
+
+    with pytest.raises(TemplateDoesNotExist) as exc_info:
+        engine.get_template("missing.txt")
+
+    assert exc_info.value.tried == [
+        [("dict:missing.txt", "Source does not exist")],
+        [("missing.txt", "Source does not exist")],
+    ]
+
+
+def test_external_loader_error():
+    engine = loaders_engine(["tests.loaders.BrokenLoader"])
+
+    with pytest.raises(ValueError) as exc_info:
+        engine.get_template("external.txt")
+
+    assert str(exc_info.value) == "Storage is unavailable"
+
+
+def test_native_loaders_skip_django_engine():
+    loader = ("django.template.loaders.locmem.Loader", {"index.html": "Hi"})
+    params = {"loaders": [loader], "builtins": ["invalid.path"]}
+    engine = RustyTemplates(
+        {"OPTIONS": params, "NAME": "rust", "DIRS": [], "APP_DIRS": False}
+    )
+
+    assert engine.get_template("index.html").render({}) == "Hi"
+
+
+def test_template_origin():
+    templates_dir = Path(settings.BASE_DIR) / "templates"
+    engine = loaders_engine(
+        ["django.template.loaders.filesystem.Loader"], dirs=[templates_dir]
+    )
+
+    template = engine.get_template("basic.txt")
+    assert template.name == "basic.txt"
+    assert template.source == (templates_dir / "basic.txt").read_text()
+    assert template.origin.name == str(templates_dir / "basic.txt")
+    assert template.origin.template_name == "basic.txt"
+    assert template.origin.loader_name == "django.template.loaders.filesystem.Loader"
+    assert template.origin is template.origin
+
+
+def test_template_origin_default_loaders():
+    engine = RustyTemplates(
+        {
+            "NAME": "rust",
+            "OPTIONS": {},
+            "DIRS": [Path(settings.BASE_DIR) / "templates"],
+            "APP_DIRS": True,
+        }
+    )
+
+    template = engine.get_template("basic.txt")
+    assert template.origin.loader_name == "django.template.loaders.filesystem.Loader"
+    assert engine.get_template("basic.txt").origin is template.origin
+
+
+def test_template_origin_locmem_loader():
+    loader = ("django.template.loaders.locmem.Loader", {"index.html": "Hi"})
+    engine = loaders_engine([loader])
+
+    origin = engine.get_template("index.html").origin
+    assert origin.name == "index.html"
+    assert origin.loader_name == "django.template.loaders.locmem.Loader"
+    assert origin.loader.engine.loaders == [loader]
+
+
+def test_template_origin_external_loader():
+    loader = ("tests.loaders.DictLoader", {"external.txt": "Hi {{ user }}"})
+    engine = loaders_engine([loader])
+
+    template = engine.get_template("external.txt")
+    assert template.name == "external.txt"
+    assert template.source == "Hi {{ user }}"
+    assert template.origin.name == "dict:external.txt"
+    assert template.origin.template_name == "external.txt"
+    assert template.origin.loader_name == "tests.loaders.DictLoader"
+
+
+def test_template_origin_from_string():
+    engine = loaders_engine([])
+
+    template = engine.from_string("Hi {{ user }}")
+    assert template.name is None
+    assert template.source == "Hi {{ user }}"
+    assert template.origin.name == UNKNOWN_SOURCE
+    assert template.origin.template_name is None
+    assert template.origin.loader is None
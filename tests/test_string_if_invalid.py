@@ -0,0 +1,121 @@
+import pytest
+from django.template.backends.django import DjangoTemplates
+from django.template.exceptions import TemplateSyntaxError
+
+from django_rusty_templates import RustyTemplates
+
+
+def invalid_engines(invalid="INVALID %s"):
+    params = {
+        "DIRS": [],
+        "APP_DIRS": False,
+        "OPTIONS": {
+            "string_if_invalid": invalid,
+            "libraries": {"custom_tags": "tests.templatetags.custom_tags"},
+        },
+    }
+    django_engine = DjangoTemplates({**params, "NAME": "django"})
+    rust_engine = RustyTemplates({**params, "NAME": "rust"})
+    return django_engine, rust_engine
+
+
+@pytest.fixture
+def assert_render_invalid():
+    def assert_render_template(template, context, expected, invalid="INVALID %s"):
+        django_engine, rust_engine = invalid_engines(invalid)
+
+        assert django_engine.from_string(template).render(context) == expected
+        assert rust_engine.from_string(template).render(context) == expected
+
+    return assert_render_template
+
+
+def test_missing_variable(assert_render_invalid):
+    assert_render_invalid("{{ foo }} {{ bar }}", {"foo": 1}, "1 INVALID bar")
+
+
+def test_failed_attribute_lookup(assert_render_invalid):
+    template = "{{ user.nmae }} {{ user.name }} {{ user.nmae|upper }}"
+    context = {"user": {"name": "Lily"}}
+    assert_render_invalid(template, context, "INVALID user.nmae Lily INVALID user.nmae")
+
+
+def test_without_placeholder(assert_render_invalid):
+    assert_render_invalid("{{ foo }}", {}, "INVALID", invalid="INVALID")
+
+
+def test_skips_filters(assert_render_invalid):
+    template = "{{ foo|upper }} {{ foo|default:'bar'|lower }}"
+    assert_render_invalid(template, {}, "INVALID foo INVALID foo")
+
+
+def test_present_variable_filters(assert_render_invalid):
+    assert_render_invalid("{{ foo|default:'bar' }}", {"foo": ""}, "bar")
+
+
+def test_autoescape(assert_render_invalid):
+    template = "{{ foo }} {% autoescape off %}{{ foo }}{% endautoescape %}"
+    expected = "&lt;foo&gt; <foo>"
+    assert_render_invalid(template, {}, expected, invalid="<%s>")
+
+
+def test_if(assert_render_invalid):
+    template = "{% if foo %}yes{% else %}no{% endif %}"
+    assert_render_invalid(template, {}, "no")
+
+
+def test_if_filter(assert_render_invalid):
+    template = "{% if foo|default:'bar' == 'bar' %}yes{% else %}no{% endif %}"
+    assert_render_invalid(template, {}, "yes")
+
+
+def test_for(assert_render_invalid):
+    template = "{% for x in foo %}{{ x }}{% empty %}empty{% endfor %}"
+    assert_render_invalid(template, {}, "empty")
+
+
+def test_for_filter(assert_render_invalid):
+    template = "{% for x in foo|default:'ab' %}{{ x }}{% endfor %}"
+    assert_render_invalid(template, {}, "ab")
+
+
+def test_simple_tag_argument(assert_render_invalid):
+    template = "{% load double from custom_tags %}{% double foo %}"
+    assert_render_invalid(template, {}, "INVALID fooINVALID foo")
+
+
+def test_url_argument(assert_render_invalid):
+    template = "{% url 'bio' username=foo %}"
+    assert_render_invalid(template, {}, "/bio/INVALID%20foo/")
+
+
+def test_cache_timeout():
+    template = "{% load cache %}{% cache timeout sidebar %}{% endcache %}"
+    django_engine, rust_engine = invalid_engines()
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        django_engine.from_string(template).render({})
+
+    expected = "\"cache\" tag got a non-integer timeout value: 'INVALID timeout'"
+    assert str(exc_info.value) == expected
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        rust_engine.from_string(template).render({})
+
+    assert expected in str(exc_info.value)
+
+
+def test_cache_name():
+    template = "{% load cache %}{% cache 500 sidebar using=name %}{% endcache %}"
+    django_engine, rust_engine = invalid_engines()
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        django_engine.from_string(template).render({})
+
+    expected = "Invalid cache name specified for cache tag: 'INVALID name'"
+    assert str(exc_info.value) == expected
+
+    with pytest.raises(TemplateSyntaxError) as exc_info:
+        rust_engine.from_string(template).render({})
+
+    assert expected in str(exc_info.value)